@@ -1,3 +1,6 @@
+# Git-Core Protocol - Agent State Tool (PowerShell)
+# Deprecated: prefer the native `gc state read|write|history` command.
+
 param(
     [Parameter(Mandatory=$true, Position=0)]
     [ValidateSet("read", "write")]
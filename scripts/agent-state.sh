@@ -1,6 +1,7 @@
 #!/bin/bash
 
 # Git-Core Protocol v1.4.0 - Agent State Tool (Bash)
+# Deprecated: prefer the native `gc state read|write|history` command.
 # Usage:
 #   ./agent-state.sh read --issue <number>
 #   ./agent-state.sh write --intent "..." --step "..." --progress 50
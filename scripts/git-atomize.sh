@@ -2,6 +2,8 @@
 # git-atomize.sh - Split staged changes into atomic commits
 # Part of Git-Core Protocol
 #
+# Native equivalent: gc atomize [--dry-run] [--strict] [--json]
+#
 # Usage:
 #   git-atomize              # Interactive mode, suggests and waits for confirmation
 #   git-atomize --analyze    # Analyze and show grouping plan
@@ -1,5 +1,6 @@
 #!/bin/bash
 set -e
+# Equivalent to: gc pr merge <PR_NUMBER> --method <MERGE_METHOD> --delete-branch
 
 # Colores
 RED='\033[0;31m'
@@ -157,7 +157,7 @@ fn analyze_commit(commit: CommitInfo, config: &Config) -> CommitAnalysis {
 }
 
 /// Categorize a file path into a concern type
-fn categorize_file(path: &str, config: &Config) -> Concern {
+pub fn categorize_file(path: &str, config: &Config) -> Concern {
     // Check custom rules first
     for rule in &config.custom_rules {
         if let Ok(re) = regex::Regex::new(&rule.pattern) {
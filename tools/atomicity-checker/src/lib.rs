@@ -0,0 +1,12 @@
+//! # Atomicity Checker
+//!
+//! Library half of the checker: configuration, file categorization and commit
+//! analysis. The `atomicity-checker` binary and `gc` both build on it.
+
+pub mod config;
+pub mod git;
+pub mod analyzer;
+pub mod reporter;
+
+pub use analyzer::categorize_file;
+pub use config::{Concern, Config};
@@ -15,10 +15,7 @@ use anyhow::Result;
 use tracing::{info, Level};
 use tracing_subscriber::FmtSubscriber;
 
-mod config;
-mod git;
-mod analyzer;
-mod reporter;
+use atomicity_checker::{analyzer, config, reporter};
 
 #[derive(Parser, Debug)]
 #[command(
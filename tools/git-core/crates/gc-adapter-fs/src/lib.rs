@@ -35,7 +35,12 @@ impl FileSystemPort for TokioFileSystem {
         Ok(())
     }
 
-    async fn list_files(&self, dir: &str, pattern: Option<&str>) -> Result<Vec<String>> {
+    async fn remove_file(&self, path: &str) -> Result<()> {
+        fs::remove_file(path).await.map_err(CoreError::Io)?;
+        Ok(())
+    }
+
+    async fn list_files(&self, dir: &str, pattern: Option<String>) -> Result<Vec<String>> {
         let mut entries = fs::read_dir(dir).await.map_err(CoreError::Io)?;
         let mut files = Vec::new();
 
@@ -43,7 +48,7 @@ impl FileSystemPort for TokioFileSystem {
             let path = entry.path();
             if path.is_file() {
                 let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
-                if let Some(pat) = pattern {
+                if let Some(pat) = pattern.as_deref() {
                     if name.contains(pat) || (pat.starts_with("*.") && name.ends_with(&pat[1..])) {
                         files.push(name);
                     }
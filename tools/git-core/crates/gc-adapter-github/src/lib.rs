@@ -1,9 +1,78 @@
 use async_trait::async_trait;
 use gc_core::ports::{GitHubPort, Result, CoreError};
-use gc_core::{Issue, PullRequest};
+use gc_core::{CheckRun, Comment, Issue, IssuePatch, MergeMethod, NewPullRequest, PullRequest, Release, ReleaseAsset, Review, WorkflowRun};
 use octocrab::Octocrab;
+use octocrab::models::issues::IssueStateReason;
 use octocrab::params::issues::Filter;
 
+fn to_issue(i: octocrab::models::issues::Issue) -> Issue {
+    Issue {
+        number: i.number,
+        title: i.title,
+        body: i.body,
+        state: format!("{:?}", i.state),
+        html_url: i.html_url.to_string(),
+        assignees: i.assignees.into_iter().map(|u| u.login).collect(),
+        labels: i.labels.into_iter().map(|l| l.name).collect(),
+        updated_at: Some(i.updated_at.to_rfc3339()),
+        closed_at: i.closed_at.map(|t| t.to_rfc3339()),
+        milestone: i.milestone.map(|m| m.title),
+        state_reason: i.state_reason.map(|r| match r {
+            IssueStateReason::Completed => "completed".to_string(),
+            IssueStateReason::NotPlanned => "not_planned".to_string(),
+            IssueStateReason::Duplicate => "duplicate".to_string(),
+            other => format!("{:?}", other).to_lowercase(),
+        }),
+    }
+}
+
+fn to_pull_request(pr: octocrab::models::pulls::PullRequest) -> PullRequest {
+    PullRequest {
+        number: pr.number,
+        title: pr.title.unwrap_or_default(),
+        body: pr.body,
+        state: format!("{:?}", pr.state.unwrap_or(octocrab::models::IssueState::Open)),
+        html_url: pr.html_url.map(|u| u.to_string()).unwrap_or_default(),
+        head_ref: pr.head.ref_field,
+        base_ref: pr.base.ref_field,
+        head_owner: pr.head.repo.and_then(|r| r.owner).map(|o| o.login).unwrap_or_default(),
+        draft: pr.draft.unwrap_or(false),
+        author: pr.user.map(|u| u.login).unwrap_or_default(),
+        mergeable: pr.mergeable,
+        merged: pr.merged.unwrap_or(false) || pr.merged_at.is_some(),
+        head_sha: pr.head.sha,
+        labels: pr.labels.unwrap_or_default().into_iter().map(|l| l.name).collect(),
+    }
+}
+
+/// Rate limiting (403 with a rate limit message, or 429) is reported as
+/// [`CoreError::RateLimited`] so callers can back off and retry.
+fn github_error(e: octocrab::Error) -> CoreError {
+    if let octocrab::Error::GitHub { source, .. } = &e {
+        let status = source.status_code.as_u16();
+        if status == 429 || (status == 403 && source.message.to_lowercase().contains("rate limit")) {
+            return CoreError::RateLimited(source.message.clone());
+        }
+    }
+    CoreError::GitHub(e.to_string())
+}
+
+const CLOSING_PR_QUERY: &str = "query($owner: String!, $repo: String!, $number: Int!) {
+  repository(owner: $owner, name: $repo) {
+    issue(number: $number) {
+      timelineItems(itemTypes: [CLOSED_EVENT], last: 1) {
+        nodes { ... on ClosedEvent { closer { ... on PullRequest { number } } } }
+      }
+    }
+  }
+}";
+
+const MARK_READY_MUTATION: &str = "mutation($id: ID!) {
+  markPullRequestReadyForReview(input: { pullRequestId: $id }) {
+    pullRequest { isDraft }
+  }
+}";
+
 pub struct OctocrabGitHub {
     client: Octocrab,
 }
@@ -19,13 +88,47 @@ impl OctocrabGitHub {
         };
         Self { client }
     }
+
+    /// Number of the milestone with this title (open or closed).
+    async fn milestone_number(&self, owner: &str, repo: &str, title: &str) -> Result<u64> {
+        let route = format!("/repos/{}/{}/milestones", owner, repo);
+        let milestones: Vec<octocrab::models::Milestone> = self.client
+            .get(route, Some(&[("state", "all"), ("per_page", "100")]))
+            .await
+            .map_err(github_error)?;
+
+        milestones
+            .into_iter()
+            .find(|m| m.title == title)
+            .map(|m| m.number as u64)
+            .ok_or_else(|| CoreError::GitHub(format!("Milestone '{}' not found in {}/{}", title, owner, repo)))
+    }
+
+    /// Uses an explicit token instead of `GITHUB_TOKEN`.
+    pub fn with_token(token: impl Into<String>) -> Self {
+        let client = Octocrab::builder()
+            .personal_token(token.into())
+            .build()
+            .unwrap_or_else(|_| Octocrab::default());
+        Self { client }
+    }
+
+    /// Talks to another API root (GitHub Enterprise, or a mock server in tests and benches).
+    pub fn with_base_uri(token: impl Into<String>, base_uri: &str) -> Result<Self> {
+        let client = Octocrab::builder()
+            .personal_token(token.into())
+            .base_uri(base_uri)
+            .and_then(|builder| builder.build())
+            .map_err(github_error)?;
+        Ok(Self { client })
+    }
 }
 
 #[async_trait]
 impl GitHubPort for OctocrabGitHub {
     async fn check_auth(&self) -> Result<String> {
         let current = self.client.current();
-        let user = current.user().await.map_err(|e| CoreError::GitHub(e.to_string()))?;
+        let user = current.user().await.map_err(github_error)?;
         Ok(user.login)
     }
 
@@ -38,18 +141,92 @@ impl GitHubPort for OctocrabGitHub {
         Ok(())
     }
 
-    async fn create_issue(&self, owner: &str, repo: &str, title: &str, body: &str, labels: &[String]) -> Result<()> {
-        self.client
+    async fn create_issue(&self, owner: &str, repo: &str, title: &str, body: &str, labels: &[String]) -> Result<Issue> {
+        let issue = self.client
             .issues(owner, repo)
             .create(title)
             .body(body)
             .labels(labels.to_vec())
             .send()
             .await
-            .map_err(|e| CoreError::GitHub(e.to_string()))?;
+            .map_err(github_error)?;
+        Ok(to_issue(issue))
+    }
+
+    async fn update_issue(&self, owner: &str, repo: &str, issue_number: u64, patch: &IssuePatch) -> Result<Issue> {
+        let issues = self.client.issues(owner, repo);
+        let mut builder = issues.update(issue_number);
+        if let Some(title) = &patch.title {
+            builder = builder.title(title);
+        }
+        if let Some(body) = &patch.body {
+            builder = builder.body(body);
+        }
+        if let Some(labels) = &patch.labels {
+            builder = builder.labels(labels);
+        }
+        if let Some(assignees) = &patch.assignees {
+            builder = builder.assignees(assignees);
+        }
+        if let Some(title) = &patch.milestone {
+            builder = builder.milestone(self.milestone_number(owner, repo, title).await?);
+        }
+        if let Some(state) = &patch.state {
+            builder = builder.state(if state.eq_ignore_ascii_case("closed") {
+                octocrab::models::IssueState::Closed
+            } else {
+                octocrab::models::IssueState::Open
+            });
+        }
+        let issue = builder
+            .send()
+            .await
+            .map_err(github_error)?;
+        Ok(to_issue(issue))
+    }
+
+    async fn close_issue(&self, owner: &str, repo: &str, issue_number: u64) -> Result<()> {
+        self.client
+            .issues(owner, repo)
+            .update(issue_number)
+            .state(octocrab::models::IssueState::Closed)
+            .send()
+            .await
+            .map_err(github_error)?;
+        Ok(())
+    }
+
+    async fn add_sub_issue(&self, owner: &str, repo: &str, parent: u64, child: u64) -> Result<()> {
+        // The sub-issues API takes the child's id, not its number
+        let child = self.client
+            .issues(owner, repo)
+            .get(child)
+            .await
+            .map_err(github_error)?;
+        let route = format!("/repos/{}/{}/issues/{}/sub_issues", owner, repo, parent);
+        let _: serde_json::Value = self.client
+            .post(route, Some(&serde_json::json!({ "sub_issue_id": child.id.0, "replace_parent": true })))
+            .await
+            .map_err(github_error)?;
         Ok(())
     }
 
+    async fn closing_pr(&self, owner: &str, repo: &str, issue_number: u64) -> Result<Option<u64>> {
+        // Only the GraphQL timeline knows which pull request closed an issue
+        let payload = serde_json::json!({
+            "query": CLOSING_PR_QUERY,
+            "variables": { "owner": owner, "repo": repo, "number": issue_number },
+        });
+        let response: serde_json::Value = self.client
+            .graphql(&payload)
+            .await
+            .map_err(github_error)?;
+
+        Ok(response
+            .pointer("/data/repository/issue/timelineItems/nodes/0/closer/number")
+            .and_then(serde_json::Value::as_u64))
+    }
+
     async fn create_label(&self, name: &str, color: &str, desc: &str) -> Result<()> {
         Ok(())
     }
@@ -62,7 +239,7 @@ impl GitHubPort for OctocrabGitHub {
             .path(path)
             .send()
             .await
-            .map_err(|e| CoreError::GitHub(e.to_string()))?;
+            .map_err(github_error)?;
 
         if let Some(content) = content_items.items.first() {
              if let Some(encoded) = &content.content {
@@ -86,7 +263,7 @@ impl GitHubPort for OctocrabGitHub {
         let uri = format!("/repos/{}/{}/pulls/{}.diff", owner, repo, pr_number);
         let diff: String = self.client.get(uri, None::<&()>)
             .await
-            .map_err(|e| CoreError::GitHub(e.to_string()))?;
+            .map_err(github_error)?;
 
         Ok(diff)
     }
@@ -95,10 +272,30 @@ impl GitHubPort for OctocrabGitHub {
         self.client.issues(owner, repo)
             .create_comment(issue_number, body)
             .await
-            .map_err(|e| CoreError::GitHub(e.to_string()))?;
+            .map_err(github_error)?;
         Ok(())
     }
 
+    async fn list_comments(&self, owner: &str, repo: &str, issue_number: u64) -> Result<Vec<Comment>> {
+        let page = self.client.issues(owner, repo)
+            .list_comments(issue_number)
+            .per_page(100)
+            .send()
+            .await
+            .map_err(github_error)?;
+
+        let comments = self.client.all_pages(page)
+            .await
+            .map_err(github_error)?;
+
+        Ok(comments.into_iter().map(|c| Comment {
+            id: c.id.into_inner(),
+            author: c.user.login,
+            body: c.body.unwrap_or_default(),
+            created_at: c.created_at.to_rfc3339(),
+        }).collect())
+    }
+
     async fn list_issues(&self, owner: &str, repo: &str, state: Option<String>, assignee: Option<String>) -> Result<Vec<Issue>> {
         let state = match state.as_deref() {
             Some("closed") => octocrab::params::State::Closed,
@@ -116,19 +313,17 @@ impl GitHubPort for OctocrabGitHub {
         }
 
         let page = builder
+            .per_page(100)
             .send()
             .await
-            .map_err(|e| CoreError::GitHub(e.to_string()))?;
+            .map_err(github_error)?;
+
+        let items = self.client.all_pages(page)
+            .await
+            .map_err(github_error)?;
 
-        let issues = page.items.into_iter().map(|i| Issue {
-            number: i.number,
-            title: i.title,
-            body: i.body,
-            state: format!("{:?}", i.state),
-            html_url: i.html_url.to_string(),
-            assignees: i.assignees.into_iter().map(|u| u.login).collect(),
-            labels: i.labels.into_iter().map(|l| l.name).collect(),
-        }).collect();
+        // The issues endpoint also returns pull requests
+        let issues = items.into_iter().filter(|i| i.pull_request.is_none()).map(to_issue).collect();
 
         Ok(issues)
     }
@@ -143,20 +338,183 @@ impl GitHubPort for OctocrabGitHub {
         let page = self.client.pulls(owner, repo)
             .list()
             .state(state)
+            .per_page(100)
+            .send()
+            .await
+            .map_err(github_error)?;
+
+        let items = self.client.all_pages(page)
+            .await
+            .map_err(github_error)?;
+
+        Ok(items.into_iter().map(to_pull_request).collect())
+    }
+
+    async fn list_branch_prs(&self, owner: &str, repo: &str, branch: &str) -> Result<Vec<PullRequest>> {
+        // One page: a branch rarely has more than a handful of PRs, and the latest come first
+        let page = self.client.pulls(owner, repo)
+            .list()
+            .state(octocrab::params::State::All)
+            .head(format!("{}:{}", owner, branch))
+            .sort(octocrab::params::pulls::Sort::Updated)
+            .direction(octocrab::params::Direction::Descending)
+            .per_page(100)
+            .send()
+            .await
+            .map_err(github_error)?;
+
+        Ok(page.items.into_iter().map(to_pull_request).collect())
+    }
+
+    async fn create_pr(&self, owner: &str, repo: &str, pr: &NewPullRequest) -> Result<PullRequest> {
+        let created = self.client.pulls(owner, repo)
+            .create(&pr.title, &pr.head, &pr.base)
+            .body(&pr.body)
+            .draft(pr.draft)
             .send()
             .await
-            .map_err(|e| CoreError::GitHub(e.to_string()))?;
+            .map_err(github_error)?;
+
+        Ok(to_pull_request(created))
+    }
+
+    async fn get_pr(&self, owner: &str, repo: &str, pr_number: u64) -> Result<PullRequest> {
+        let pr = self.client.pulls(owner, repo)
+            .get(pr_number)
+            .await
+            .map_err(github_error)?;
+
+        Ok(to_pull_request(pr))
+    }
+
+    async fn set_pr_base(&self, owner: &str, repo: &str, pr_number: u64, base: &str) -> Result<PullRequest> {
+        let pr = self.client.pulls(owner, repo)
+            .update(pr_number)
+            .base(base)
+            .send()
+            .await
+            .map_err(github_error)?;
+
+        Ok(to_pull_request(pr))
+    }
+
+    async fn list_checks(&self, owner: &str, repo: &str, git_ref: &str) -> Result<Vec<CheckRun>> {
+        let route = format!("/repos/{}/{}/commits/{}/check-runs", owner, repo, git_ref);
+        let response: serde_json::Value = self.client
+            .get(route, Some(&[("per_page", "100")]))
+            .await
+            .map_err(github_error)?;
+
+        let runs = response["check_runs"].as_array().cloned().unwrap_or_default();
+        Ok(runs.iter().map(|run| CheckRun {
+            name: run["name"].as_str().unwrap_or_default().to_string(),
+            status: run["status"].as_str().unwrap_or_default().to_string(),
+            conclusion: run["conclusion"].as_str().map(str::to_string),
+        }).collect())
+    }
+
+    async fn list_reviews(&self, owner: &str, repo: &str, pr_number: u64) -> Result<Vec<Review>> {
+        let route = format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, pr_number);
+        let reviews: Vec<serde_json::Value> = self.client
+            .get(route, Some(&[("per_page", "100")]))
+            .await
+            .map_err(github_error)?;
+
+        Ok(reviews.iter().map(|review| Review {
+            author: review.pointer("/user/login").and_then(serde_json::Value::as_str).unwrap_or_default().to_string(),
+            state: review["state"].as_str().unwrap_or_default().to_string(),
+            submitted_at: review["submitted_at"].as_str().map(str::to_string),
+        }).collect())
+    }
+
+    async fn mark_pr_ready(&self, owner: &str, repo: &str, pr_number: u64) -> Result<()> {
+        // REST cannot undraft a pull request; the GraphQL mutation needs its node id
+        let pr = self.client.pulls(owner, repo)
+            .get(pr_number)
+            .await
+            .map_err(github_error)?;
+        let node_id = pr.node_id
+            .ok_or_else(|| CoreError::GitHub(format!("Pull request #{} has no node id", pr_number)))?;
+
+        let payload = serde_json::json!({
+            "query": MARK_READY_MUTATION,
+            "variables": { "id": node_id },
+        });
+        let response: serde_json::Value = self.client
+            .graphql(&payload)
+            .await
+            .map_err(github_error)?;
+
+        match response.pointer("/errors/0/message").and_then(serde_json::Value::as_str) {
+            Some(message) => Err(CoreError::GitHub(message.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    async fn merge_pr(&self, owner: &str, repo: &str, pr_number: u64, method: MergeMethod) -> Result<String> {
+        let method = match method {
+            MergeMethod::Merge => octocrab::params::pulls::MergeMethod::Merge,
+            MergeMethod::Squash => octocrab::params::pulls::MergeMethod::Squash,
+            MergeMethod::Rebase => octocrab::params::pulls::MergeMethod::Rebase,
+        };
+        let merge = self.client.pulls(owner, repo)
+            .merge(pr_number)
+            .method(method)
+            .send()
+            .await
+            .map_err(github_error)?;
+
+        if !merge.merged {
+            return Err(CoreError::GitHub(merge.message.unwrap_or_else(|| format!("Pull request #{} was not merged", pr_number))));
+        }
+        Ok(merge.sha.unwrap_or_default())
+    }
+
+    async fn delete_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<()> {
+        match self.client.repos(owner, repo)
+            .delete_ref(&octocrab::params::repos::Reference::Branch(branch.to_string()))
+            .await
+        {
+            // GitHub may already have deleted it on merge
+            Err(octocrab::Error::GitHub { source, .. })
+                if source.status_code.as_u16() == 422 && source.message.contains("Reference does not exist") => Ok(()),
+            result => result.map_err(github_error),
+        }
+    }
+
+    async fn list_workflow_runs(&self, owner: &str, repo: &str, limit: u8) -> Result<Vec<WorkflowRun>> {
+        let page = self.client.workflows(owner, repo)
+            .list_all_runs()
+            .per_page(limit)
+            .send()
+            .await
+            .map_err(github_error)?;
+
+        Ok(page.items.into_iter().map(|run| WorkflowRun {
+            name: run.name,
+            head_branch: run.head_branch,
+            status: run.status,
+            conclusion: run.conclusion,
+            html_url: run.html_url.to_string(),
+            created_at: run.created_at.to_rfc3339(),
+        }).collect())
+    }
 
-        let prs = page.items.into_iter().map(|pr| PullRequest {
-            number: pr.number,
-            title: pr.title.unwrap_or_default(),
-            body: pr.body,
-            state: format!("{:?}", pr.state.unwrap_or(octocrab::models::IssueState::Open)),
-            html_url: pr.html_url.map(|u| u.to_string()).unwrap_or_default(),
-            head_ref: pr.head.ref_field,
-            base_ref: pr.base.ref_field,
-        }).collect();
+    async fn get_release(&self, owner: &str, repo: &str, tag: Option<String>) -> Result<Release> {
+        let repos = self.client.repos(owner, repo);
+        let release = match tag {
+            Some(tag) => repos.releases().get_by_tag(&tag).await,
+            None => repos.releases().get_latest().await,
+        }.map_err(github_error)?;
 
-        Ok(prs)
+        Ok(Release {
+            tag_name: release.tag_name,
+            prerelease: release.prerelease,
+            assets: release.assets.into_iter().map(|asset| ReleaseAsset {
+                name: asset.name,
+                download_url: asset.browser_download_url.to_string(),
+                size: asset.size as u64,
+            }).collect(),
+        })
     }
 }
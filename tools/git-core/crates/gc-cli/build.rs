@@ -0,0 +1,4 @@
+fn main() {
+    // `gc self-update` picks the release asset built for the same target
+    println!("cargo:rustc-env=GC_TARGET={}", std::env::var("TARGET").unwrap());
+}
@@ -0,0 +1,318 @@
+use clap::Args;
+use color_eyre::Result;
+use gc_core::ports::SystemPort;
+use atomicity_checker::{categorize_file, Concern, Config};
+use console::style;
+use serde::Serialize;
+use std::io::{self, IsTerminal, Write};
+use crate::commands::finish::{commit_message, current_branch, issue_from_branch, kind_from_branch, CommitGroup};
+use crate::commands::git::{load_atomicity_config, ATOMICITY_CONFIG};
+use crate::commands::pr::git;
+
+/// Order in which groups are committed.
+const CONCERN_ORDER: [Concern; 6] = [Concern::Config, Concern::Source, Concern::Tests, Concern::Docs, Concern::Infra, Concern::Other];
+
+#[derive(Args, Debug)]
+pub struct AtomizeArgs {
+    /// Commit every group without asking (otherwise each commit is confirmed on a terminal)
+    #[arg(long, conflicts_with = "dry_run")]
+    pub auto: bool,
+
+    /// Show the grouping plan without committing
+    #[arg(short = 'n', long, visible_alias = "analyze", visible_short_alias = 'a')]
+    pub dry_run: bool,
+
+    /// Fail when staged changes span more concerns than the config allows (for CI)
+    #[arg(short, long)]
+    pub strict: bool,
+
+    /// Issue number referenced in commits (detected from branch if omitted)
+    #[arg(long)]
+    pub issue: Option<u64>,
+
+    /// Atomicity rules (custom_rules, ignore_files, max_concerns)
+    #[arg(long, default_value = ATOMICITY_CONFIG)]
+    pub config: String,
+
+    /// Output in JSON format
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct Group {
+    concern: Concern,
+    message: String,
+    files: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct AtomizeOutput {
+    total_files: usize,
+    group_count: usize,
+    /// Whether the staged changes already fit in one commit
+    atomic: bool,
+    /// Whether at least one group was committed
+    committed: bool,
+    groups: Vec<Group>,
+}
+
+pub async fn execute(args: AtomizeArgs, system: &impl SystemPort) -> Result<()> {
+    let config = load_atomicity_config(&args.config)?;
+    // -z keeps unusual paths unquoted; no renames so both sides of a move are committed
+    let staged = git(system, &["diff", "--cached", "--name-only", "--no-renames", "-z"]).await?;
+    let files: Vec<String> = staged.split('\0').filter(|f| !f.is_empty()).map(str::to_string).collect();
+
+    if files.is_empty() {
+        if args.json {
+            let output = AtomizeOutput { total_files: 0, group_count: 0, atomic: true, committed: false, groups: Vec::new() };
+            println!("{}", serde_json::to_string(&output).unwrap());
+        } else {
+            println!("{} No staged files found. Stage files first with: git add <files>", style("⚠️").yellow());
+        }
+        return Ok(());
+    }
+
+    let branch = current_branch(system).await?;
+    let issue = args.issue.or_else(|| issue_from_branch(&branch));
+    let groups = plan(&files, &config, &branch, issue);
+    let atomic = !config.enabled || groups.len() <= config.max_concerns;
+
+    let mut output = AtomizeOutput {
+        total_files: files.len(),
+        group_count: groups.len(),
+        atomic,
+        committed: false,
+        groups,
+    };
+
+    if args.strict && !atomic {
+        if args.json {
+            println!("{}", serde_json::to_string(&output).unwrap());
+        } else {
+            print_plan(&output);
+        }
+        color_eyre::eyre::bail!("Staged changes mix {} concerns; split them into atomic commits", output.group_count);
+    }
+
+    if !args.json {
+        print_plan(&output);
+    }
+    if args.dry_run || args.strict {
+        if args.json {
+            println!("{}", serde_json::to_string(&output).unwrap());
+        } else if args.dry_run {
+            println!("{} Dry run complete. No commits were made.", style("ℹ️").cyan());
+        }
+        return Ok(());
+    }
+
+    // Like scripts/git-atomize.sh: ask before each commit, and only commit unattended with --auto
+    let interactive = !args.auto && !args.json && io::stdin().is_terminal();
+    if !args.auto && !interactive {
+        if args.json {
+            println!("{}", serde_json::to_string(&output).unwrap());
+        } else {
+            println!("{} Re-run with --auto to commit these groups.", style("ℹ️").cyan());
+        }
+        return Ok(());
+    }
+
+    // Committing a path takes its working-tree content, so it must match what was staged
+    let unstaged = git(system, &["diff", "--name-only", "-z"]).await?;
+    let partial: Vec<&str> = unstaged.split('\0').filter(|f| files.iter().any(|s| s == f)).collect();
+    if !partial.is_empty() {
+        color_eyre::eyre::bail!("{} also have unstaged changes; stage or stash them first", partial.join(", "));
+    }
+
+    let mut skipped = 0;
+    for (i, group) in output.groups.iter_mut().enumerate() {
+        if interactive {
+            println!("{} [{}/{}] {}", style("📦").blue(), i + 1, output.group_count, group.message);
+            match confirm_group(&group.message) {
+                Some(message) => group.message = message,
+                None => {
+                    println!("   {}", style("Skipped (still staged)").cyan());
+                    skipped += 1;
+                    continue;
+                }
+            }
+        }
+
+        // Passing the paths restricts the commit to this group even if other files are staged
+        let mut commit_args = vec!["commit".to_string(), "-m".to_string(), group.message.clone(), "--".to_string()];
+        commit_args.extend(group.files.iter().cloned());
+        system.run_command_output("git", &commit_args).await?;
+
+        if !args.json {
+            println!("{} [{}/{}] {}", style("✓").green(), i + 1, output.group_count, group.message);
+        }
+    }
+    output.committed = skipped < output.group_count;
+
+    if args.json {
+        println!("{}", serde_json::to_string(&output).unwrap());
+    } else if skipped == 0 {
+        println!("\n{} All groups committed.", style("✅").green());
+    } else {
+        println!("\n{} Committed {} of {} groups.", style("✅").green(), output.group_count - skipped, output.group_count);
+    }
+    Ok(())
+}
+
+/// Asks like `scripts/git-atomize.sh` does: Y commits, n skips, e edits the message.
+/// Returns the message to commit with, `None` to skip the group.
+fn confirm_group(message: &str) -> Option<String> {
+    print!("   Proceed with commit? [Y/n/e(dit message)] ");
+    io::stdout().flush().ok();
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).ok()?;
+    match input.trim().to_lowercase().as_str() {
+        "n" | "no" => None,
+        "e" | "edit" => {
+            print!("   Enter new message: ");
+            io::stdout().flush().ok();
+            let mut edited = String::new();
+            io::stdin().read_line(&mut edited).ok()?;
+            let edited = edited.trim();
+            Some(if edited.is_empty() { message } else { edited }.to_string())
+        }
+        _ => Some(message.to_string()),
+    }
+}
+
+/// Groups staged files by concern, one conventional commit each. Files the config ignores
+/// (lockfiles, ...) ride along with the config group, or the first group if there is none.
+fn plan(files: &[String], config: &Config, branch: &str, issue: Option<u64>) -> Vec<Group> {
+    let mut ignored = Vec::new();
+    let mut by_concern: Vec<(Concern, Vec<String>)> = Vec::new();
+    for file in files {
+        if config.should_ignore_file(file) {
+            ignored.push(file.clone());
+            continue;
+        }
+        let concern = categorize_file(file, config);
+        match by_concern.iter_mut().find(|(c, _)| *c == concern) {
+            Some((_, files)) => files.push(file.clone()),
+            None => by_concern.push((concern, vec![file.clone()])),
+        }
+    }
+    by_concern.sort_by_key(|(c, _)| CONCERN_ORDER.iter().position(|o| o == c));
+
+    if !ignored.is_empty() {
+        let target = by_concern.iter()
+            .position(|(c, _)| *c == Concern::Config)
+            .or((!by_concern.is_empty()).then_some(0));
+        match target {
+            Some(i) => by_concern[i].1.extend(ignored),
+            None => by_concern.push((Concern::Config, ignored)),
+        }
+    }
+
+    by_concern.into_iter().map(|(concern, files)| {
+        let (kind, scope) = match concern {
+            Concern::Source => (kind_from_branch(branch), None),
+            Concern::Tests => ("test", None),
+            Concern::Docs => ("docs", None),
+            Concern::Config => ("chore", Some("config")),
+            Concern::Infra => ("ci", None),
+            Concern::Other => ("chore", None),
+        };
+        let group = CommitGroup { kind, scope, files };
+        Group { message: commit_message(&group, issue), concern, files: group.files }
+    }).collect()
+}
+
+fn print_plan(output: &AtomizeOutput) {
+    println!("{} Analysis of {} staged files:\n", style("📊").cyan(), style(output.total_files).yellow());
+    for (i, group) in output.groups.iter().enumerate() {
+        let plural = if group.files.len() == 1 { "" } else { "s" };
+        println!("{} Group {}: {} ({} file{})", style("📦").blue(), i + 1, style(&group.concern).magenta(), group.files.len(), plural);
+        for file in &group.files {
+            println!("   {}", style(format!("- {}", file)).cyan());
+        }
+        println!("   Commit: {}\n", style(&group.message).yellow());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::MockSystemPort;
+
+    fn files(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(|p| p.to_string()).collect()
+    }
+
+    fn args(auto: bool, strict: bool) -> AtomizeArgs {
+        AtomizeArgs { auto, dry_run: false, strict, issue: None, config: "does/not/exist.yml".into(), json: true }
+    }
+
+    #[test]
+    fn test_plan_groups_by_concern() {
+        let staged = files(&["src/auth.rs", "README.md", "tests/auth_test.rs", "Cargo.lock", "src/session.rs", ".github/workflows/ci.yml"]);
+        let groups = plan(&staged, &Config::default(), "bug/12-login", None);
+
+        let summary: Vec<(&str, usize)> = groups.iter().map(|g| (g.message.as_str(), g.files.len())).collect();
+        assert_eq!(summary, vec![
+            ("fix: update 3 files", 3),
+            ("test: update tests/auth_test.rs", 1),
+            ("docs: update README.md", 1),
+            ("ci: update .github/workflows/ci.yml", 1),
+        ]);
+        assert_eq!(groups[0].files, files(&["src/auth.rs", "src/session.rs", "Cargo.lock"]));
+    }
+
+    #[tokio::test]
+    async fn test_commits_one_group_at_a_time() {
+        let mut system = MockSystemPort::new();
+        let commits = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = commits.clone();
+        system.expect_run_command_output().returning(move |_, args| {
+            Ok(match args.join(" ").as_str() {
+                "diff --cached --name-only --no-renames -z" => "src/lib.rs\0docs/guide.md\0".to_string(),
+                "branch --show-current" => "feat/7-guide".to_string(),
+                "diff --name-only -z" => "notes.txt\0".to_string(),
+                _ => {
+                    log.lock().unwrap().push(args.to_vec());
+                    String::new()
+                }
+            })
+        });
+
+        execute(args(true, false), &system).await.unwrap();
+
+        let commits = commits.lock().unwrap();
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0], files(&["commit", "-m", "feat: update src/lib.rs (#7)", "--", "src/lib.rs"]));
+        assert_eq!(commits[1], files(&["commit", "-m", "docs: update docs/guide.md (#7)", "--", "docs/guide.md"]));
+    }
+
+    #[tokio::test]
+    async fn test_strict_fails_on_mixed_concerns() {
+        let mut system = MockSystemPort::new();
+        system.expect_run_command_output().returning(|_, args| {
+            Ok(match args.join(" ").as_str() {
+                "diff --cached --name-only --no-renames -z" => "src/lib.rs\0docs/guide.md\0".to_string(),
+                "branch --show-current" => "main".to_string(),
+                other => panic!("unexpected git {}", other),
+            })
+        });
+
+        assert!(execute(args(false, true), &system).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_without_auto_only_plans() {
+        let mut system = MockSystemPort::new();
+        system.expect_run_command_output().returning(|_, args| {
+            Ok(match args.join(" ").as_str() {
+                "diff --cached --name-only --no-renames -z" => "src/lib.rs\0docs/guide.md\0".to_string(),
+                "branch --show-current" => "main".to_string(),
+                other => panic!("unexpected git {}", other),
+            })
+        });
+
+        execute(args(false, false), &system).await.unwrap();
+    }
+}
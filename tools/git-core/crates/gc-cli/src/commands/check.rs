@@ -1,136 +1,661 @@
+use async_trait::async_trait;
 use clap::Args;
 use color_eyre::Result;
 use gc_core::ports::{SystemPort, FileSystemPort, GitHubPort};
+use atomicity_checker::Config;
 use console::style;
 use serde::Serialize;
+use std::cmp::Ordering;
+use std::time::Duration;
+use crate::commands::git::ATOMICITY_CONFIG;
+use crate::commands::update::compare_versions;
+
+const VERSION_FILE: &str = ".git-core-protocol-version";
+const REQUIRED_FILES: [&str; 2] = ["AGENTS.md", ".ai-core/ARCHITECTURE.md"];
+/// Scopes `gh` needs to push branches and the protocol's workflow files
+const REQUIRED_SCOPES: [&str; 2] = ["repo", "workflow"];
+const AGENT_CLIS: [&str; 5] = ["claude", "codex", "gemini", "copilot", "jules"];
+/// Marker the protocol's pre-commit hook carries (see scripts/hooks/install-hooks.sh)
+const HOOK_MARKER: &str = "git-core-protocol";
+const HOOK_WRAPPER: &str = r#"#!/bin/bash
+# Git-Core Protocol pre-commit hook (git-core-protocol)
+# This hook validates atomic commits based on .git-atomize.yml configuration
+# Bypass with: git commit --no-verify
+
+# Get repository root
+REPO_ROOT="$(git rev-parse --show-toplevel)"
+ATOMIZE_HOOK="$REPO_ROOT/scripts/hooks/pre-commit"
+
+# Run the hook script if it exists
+if [ -f "$ATOMIZE_HOOK" ] && [ -x "$ATOMIZE_HOOK" ]; then
+    exec "$ATOMIZE_HOOK"
+elif [ -f "$ATOMIZE_HOOK" ]; then
+    exec bash "$ATOMIZE_HOOK"
+else
+    # Hook script not found, skip validation
+    echo "Note: scripts/hooks/pre-commit not found, skipping atomicity check"
+    exit 0
+fi
+"#;
+const REMOTE_TIMEOUT: Duration = Duration::from_secs(10);
 
 #[derive(Args, Debug)]
+#[command(after_help = "\
+Exit codes (the first failing check decides; warnings only count with --strict):
+  0   healthy
+  10  git            git is not installed
+  11  repo           not inside a git repository
+  12  required-files AGENTS.md or .ai-core/ARCHITECTURE.md missing
+  13  config         a protocol config file does not parse
+  20  gh-cli         GitHub CLI not installed
+  21  gh-auth        gh is not logged in
+  22  token-scopes   gh token lacks the repo/workflow scopes
+  23  github-token   GITHUB_TOKEN missing or rejected
+  24  hooks          pre-commit hook not installed
+  25  agent-clis     no agent CLI (claude, codex, gemini, copilot, jules) found
+  26  remote         origin is missing or unreachable
+  27  protocol-version  protocol not installed or an update is available")]
 pub struct CheckArgs {
     /// Output in JSON format
     #[arg(long)]
     pub json: bool,
+
+    /// Apply the automatic fix of each failing check that has one
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Exit non-zero on warnings too, not only on errors
+    #[arg(long)]
+    pub strict: bool,
+}
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Severity {
+    /// gc cannot work until this is fixed
+    Error,
+    /// gc works, but some commands or agents will not
+    Warning,
+}
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Status {
+    Pass,
+    Fail,
+    /// The check could not tell either way (offline, missing prerequisite)
+    Skip,
+}
+
+#[derive(Debug)]
+pub(crate) struct Outcome {
+    status: Status,
+    detail: String,
+}
+
+impl Outcome {
+    fn pass(detail: impl Into<String>) -> Self {
+        Self { status: Status::Pass, detail: detail.into() }
+    }
+
+    fn fail(detail: impl Into<String>) -> Self {
+        Self { status: Status::Fail, detail: detail.into() }
+    }
+
+    fn skip(detail: impl Into<String>) -> Self {
+        Self { status: Status::Skip, detail: detail.into() }
+    }
+}
+
+pub(crate) struct CheckInfo {
+    id: &'static str,
+    title: &'static str,
+    severity: Severity,
+    /// Process exit code when this is the first failing check
+    exit_code: i32,
+    remediation: &'static str,
+}
+
+/// Ports handed to every check.
+pub(crate) struct Env<'a> {
+    fs: &'a dyn FileSystemPort,
+    system: &'a dyn SystemPort,
+    github: &'a dyn GitHubPort,
+}
+
+/// One diagnostic of `gc check`. New checks implement this and are added to [`checks`].
+#[async_trait]
+pub(crate) trait Check: Send + Sync {
+    fn info(&self) -> CheckInfo;
+
+    async fn run(&self, env: &Env<'_>) -> Outcome;
+
+    /// Repairs a failure for `--fix`; `None` when it has to be fixed by hand.
+    async fn fix(&self, _env: &Env<'_>) -> Option<Result<()>> {
+        None
+    }
+}
+
+/// All checks, errors first so they decide the exit code.
+pub(crate) fn checks() -> Vec<Box<dyn Check>> {
+    vec![
+        Box::new(GitInstalled),
+        Box::new(InsideRepo),
+        Box::new(RequiredFiles),
+        Box::new(ConfigValid),
+        Box::new(GhInstalled),
+        Box::new(GhAuth),
+        Box::new(TokenScopes),
+        Box::new(GithubToken),
+        Box::new(HooksInstalled),
+        Box::new(AgentClis),
+        Box::new(RemoteReachable),
+        Box::new(ProtocolVersion),
+    ]
+}
+
+#[derive(Serialize)]
+struct CheckReport {
+    id: &'static str,
+    title: &'static str,
+    severity: Severity,
+    status: Status,
+    detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remediation: Option<&'static str>,
+    exit_code: i32,
+    fixed: bool,
 }
 
 #[derive(Serialize)]
 struct CheckOutput {
-    git_installed: bool,
-    gh_cli_installed: bool,
-    in_git_repo: bool,
-    has_gh_token: bool,
-    protocol_version: String,
-    latest_protocol_version: String,
-    update_available: bool,
-    all_passed: bool,
+    checks: Vec<CheckReport>,
+    /// No check failed (skipped checks are not counted as failures)
+    healthy: bool,
+    exit_code: i32,
 }
 
+/// Runs every check and returns the process exit code (see `gc check --help`).
 pub async fn execute(
     args: CheckArgs,
     fs: &impl FileSystemPort,
     system: &impl SystemPort,
     github: &impl GitHubPort
-) -> Result<()> {
+) -> Result<i32> {
+    let env = Env { fs, system, github };
     if !args.json {
-        println!("{} Checking environment health...", style("hz").cyan()); // Heartbeat/Health icon
+        println!("{} Checking environment health...", style("🩺").cyan());
     }
 
-    // 1. Check Git
-    let git_version = system.run_command_output("git", &["--version".to_string()]).await;
-    let git_installed = git_version.is_ok();
+    let mut reports = Vec::new();
+    for check in checks() {
+        let info = check.info();
+        let mut outcome = check.run(&env).await;
+        let mut fixed = false;
 
-    if !args.json {
-        print_status("Git Installed", git_installed);
+        if args.fix && outcome.status == Status::Fail {
+            match check.fix(&env).await {
+                Some(Ok(())) => {
+                    outcome = check.run(&env).await;
+                    fixed = outcome.status == Status::Pass;
+                }
+                Some(Err(e)) => outcome.detail = format!("{} (fix failed: {})", outcome.detail, e),
+                None => {}
+            }
+        }
+
+        let report = CheckReport {
+            id: info.id,
+            title: info.title,
+            severity: info.severity,
+            status: outcome.status,
+            detail: outcome.detail,
+            remediation: (outcome.status == Status::Fail).then_some(info.remediation),
+            exit_code: info.exit_code,
+            fixed,
+        };
+        if !args.json {
+            print_report(&report);
+        }
+        reports.push(report);
     }
 
-    // 2. Check Inside Git Repo
-    let git_repo = system.run_command_output("git", &["rev-parse".to_string(), "--is-inside-work-tree".to_string()]).await;
-    let in_git_repo = git_repo.is_ok();
+    let exit_code = exit_code(&reports, args.strict);
+    let failed = |severity| reports.iter().filter(|r| r.status == Status::Fail && r.severity == severity).count();
+    let (errors, warnings) = (failed(Severity::Error), failed(Severity::Warning));
+    let skipped = reports.iter().filter(|r| r.status == Status::Skip).count();
 
-    if !args.json {
-        print_status("Inside Git Repo", in_git_repo);
+    if args.json {
+        let output = CheckOutput { healthy: errors + warnings == 0, exit_code, checks: reports };
+        println!("{}", serde_json::to_string(&output)?);
+        return Ok(exit_code);
     }
 
-    // 3. Check GitHub CLI (gh) - Optional but recommended
-    let gh_version = system.run_command_output("gh", &["--version".to_string()]).await;
-    let gh_cli_installed = gh_version.is_ok();
+    println!();
+    if errors > 0 {
+        println!("{} Environment has {} problem(s) and {} warning(s).", style("❌").red(), errors, warnings);
+    } else if warnings > 0 {
+        println!("{} Environment is usable with {} warning(s).", style("⚠️").yellow(), warnings);
+    } else {
+        println!("{} Environment looks healthy!", style("✅").green());
+    }
+    if skipped > 0 {
+        println!("   {} check(s) could not be verified.", skipped);
+    }
+    Ok(exit_code)
+}
 
-    if !args.json {
-        print_status("GitHub CLI (gh)", gh_cli_installed);
+/// Exit code of the first failing check that counts: errors always, warnings with `strict`.
+fn exit_code(reports: &[CheckReport], strict: bool) -> i32 {
+    reports.iter()
+        .find(|r| r.status == Status::Fail && (strict || r.severity == Severity::Error))
+        .map_or(0, |r| r.exit_code)
+}
+
+fn print_report(report: &CheckReport) {
+    let icon = match (report.status, report.severity) {
+        (Status::Pass, _) => style("✓").green(),
+        (Status::Skip, _) => style("?").yellow(),
+        (Status::Fail, Severity::Warning) => style("!").yellow(),
+        (Status::Fail, Severity::Error) => style("✗").red(),
+    };
+    if report.detail.is_empty() {
+        println!("   {} {}", icon, report.title);
+    } else {
+        println!("   {} {}: {}", icon, report.title, report.detail);
     }
+    if report.fixed {
+        println!("      {}", style("fixed").green());
+    } else if let Some(remediation) = report.remediation {
+        println!("      {} {} [{}]", style("→").dim(), remediation, report.id);
+    }
+}
 
-    // 4. Check Environment Variables (simulate token check)
-    // In a real scenario, we might check specifically for GITHUB_TOKEN or similar
-    // For now, we assume if `gh` works, we might have auth, but let's check basic var
-    let has_gh_token = std::env::var("GITHUB_TOKEN").is_ok() || std::env::var("GH_TOKEN").is_ok();
+/// Adapter errors can carry a backtrace; the first line is the message.
+fn first_line(error: &impl std::fmt::Display) -> String {
+    error.to_string().lines().next().unwrap_or_default().to_string()
+}
 
-    if !args.json {
-        print_status("GITHUB_TOKEN Set", has_gh_token);
-        if !has_gh_token && gh_cli_installed {
-             println!("      (Reliant on 'gh' auth status if token is missing)");
+/// Trimmed stdout of a command, `None` when it cannot be run or fails.
+async fn output(system: &dyn SystemPort, cmd: &str, args: &[&str]) -> Option<String> {
+    let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+    system.run_command_output(cmd, &args).await.ok().map(|out| out.trim().to_string())
+}
+
+struct GitInstalled;
+
+#[async_trait]
+impl Check for GitInstalled {
+    fn info(&self) -> CheckInfo {
+        CheckInfo { id: "git", title: "Git Installed", severity: Severity::Error, exit_code: 10, remediation: "Install git from https://git-scm.com/downloads" }
+    }
+
+    async fn run(&self, env: &Env<'_>) -> Outcome {
+        match output(env.system, "git", &["--version"]).await {
+            Some(version) => Outcome::pass(version),
+            None => Outcome::fail("git not found on PATH"),
         }
     }
+}
 
-    // 5. Check Protocol Version
-    let version_file = ".git-core-protocol-version";
-    let protocol_version = if fs.exists(version_file).await.unwrap_or(false) {
-        fs.read_file(version_file).await.unwrap_or_else(|_| "0.0.0".to_string()).trim().to_string()
-    } else {
-        "0.0.0".to_string()
-    };
+struct InsideRepo;
 
-    if !args.json {
-        println!("   {} Protocol Version: {}", style("ℹ").blue(), protocol_version);
+#[async_trait]
+impl Check for InsideRepo {
+    fn info(&self) -> CheckInfo {
+        CheckInfo { id: "repo", title: "Inside Git Repo", severity: Severity::Error, exit_code: 11, remediation: "cd into your project, or run 'git init' (--fix does this)" }
+    }
+
+    async fn run(&self, env: &Env<'_>) -> Outcome {
+        match output(env.system, "git", &["rev-parse", "--is-inside-work-tree"]).await.as_deref() {
+            Some("true") => Outcome::pass(""),
+            _ => Outcome::fail("not a git work tree"),
+        }
+    }
+
+    async fn fix(&self, env: &Env<'_>) -> Option<Result<()>> {
+        Some(env.system.run_command("git", &["init".to_string(), "-q".to_string()]).await.map_err(Into::into))
     }
+}
 
-    // 6. Check Latest Version (Remote)
-    let latest_protocol_version = github.get_file_content(
-        "iberi22",
-        "Git-Core-Protocol",
-        "main",
-        ".git-core-protocol-version"
-    ).await.unwrap_or_else(|_| "unknown".to_string()).trim().to_string();
+struct RequiredFiles;
 
-    let update_available = latest_protocol_version != "unknown" && protocol_version != latest_protocol_version;
+#[async_trait]
+impl Check for RequiredFiles {
+    fn info(&self) -> CheckInfo {
+        CheckInfo { id: "required-files", title: "Protocol Files", severity: Severity::Error, exit_code: 12, remediation: "Run 'gc init' or 'gc update' to install the protocol files" }
+    }
 
-    if !args.json {
-        if latest_protocol_version == "unknown" {
-            println!("   {} Could not fetch latest version (check internet/token)", style("!").yellow());
-        } else if update_available {
-            println!("   {} Update Available: {}", style("!").yellow(), latest_protocol_version);
-            println!("      (Run 'gc update' to upgrade)");
+    async fn run(&self, env: &Env<'_>) -> Outcome {
+        let mut missing = Vec::new();
+        for file in REQUIRED_FILES {
+            if !env.fs.exists(file).await.unwrap_or(false) {
+                missing.push(file);
+            }
+        }
+        if missing.is_empty() {
+            Outcome::pass("")
         } else {
-            println!("   {} Protocol is up to date", style("✓").green());
+            Outcome::fail(format!("missing {}", missing.join(", ")))
         }
     }
+}
 
-    let all_passed = git_installed && in_git_repo && !update_available; // Requirement includes being up to date
+struct ConfigValid;
 
-    if args.json {
-        let output = CheckOutput {
-            git_installed,
-            gh_cli_installed,
-            in_git_repo,
-            has_gh_token,
-            protocol_version,
-            latest_protocol_version,
-            update_available,
-            all_passed,
+#[async_trait]
+impl Check for ConfigValid {
+    fn info(&self) -> CheckInfo {
+        CheckInfo { id: "config", title: "Config Files", severity: Severity::Error, exit_code: 13, remediation: "Fix the syntax error, or delete the file to fall back to defaults" }
+    }
+
+    async fn run(&self, env: &Env<'_>) -> Outcome {
+        let mut checked = 0;
+        for file in [ATOMICITY_CONFIG, ".git-atomize.yml", ".ai-core/features.json"] {
+            if !env.fs.exists(file).await.unwrap_or(false) {
+                continue;
+            }
+            let content = match env.fs.read_file(file).await {
+                Ok(content) => content,
+                Err(e) => return Outcome::fail(format!("{}: {}", file, e)),
+            };
+            let parsed = if file == ATOMICITY_CONFIG {
+                serde_yaml::from_str::<Config>(&content).map(drop).map_err(|e| e.to_string())
+            } else if file.ends_with(".json") {
+                serde_json::from_str::<serde_json::Value>(&content).map(drop).map_err(|e| e.to_string())
+            } else {
+                serde_yaml::from_str::<serde_yaml::Value>(&content).map(drop).map_err(|e| e.to_string())
+            };
+            if let Err(e) = parsed {
+                return Outcome::fail(format!("{}: {}", file, e));
+            }
+            checked += 1;
+        }
+        if checked == 0 {
+            Outcome::pass("none present, using defaults")
+        } else {
+            Outcome::pass(format!("{} file(s) valid", checked))
+        }
+    }
+}
+
+struct GhInstalled;
+
+#[async_trait]
+impl Check for GhInstalled {
+    fn info(&self) -> CheckInfo {
+        CheckInfo { id: "gh-cli", title: "GitHub CLI (gh)", severity: Severity::Warning, exit_code: 20, remediation: "Install the GitHub CLI from https://cli.github.com" }
+    }
+
+    async fn run(&self, env: &Env<'_>) -> Outcome {
+        match output(env.system, "gh", &["--version"]).await {
+            Some(version) => Outcome::pass(version.lines().next().unwrap_or_default().to_string()),
+            None => Outcome::fail("gh not found on PATH"),
+        }
+    }
+}
+
+struct GhAuth;
+
+#[async_trait]
+impl Check for GhAuth {
+    fn info(&self) -> CheckInfo {
+        CheckInfo { id: "gh-auth", title: "gh Authenticated", severity: Severity::Warning, exit_code: 21, remediation: "Run 'gh auth login'" }
+    }
+
+    async fn run(&self, env: &Env<'_>) -> Outcome {
+        if output(env.system, "gh", &["--version"]).await.is_none() {
+            return Outcome::skip("gh not installed");
+        }
+        match output(env.system, "gh", &["auth", "status"]).await {
+            Some(_) => Outcome::pass(""),
+            None => Outcome::fail("not logged in to github.com"),
+        }
+    }
+}
+
+struct TokenScopes;
+
+#[async_trait]
+impl Check for TokenScopes {
+    fn info(&self) -> CheckInfo {
+        CheckInfo { id: "token-scopes", title: "Token Scopes", severity: Severity::Warning, exit_code: 22, remediation: "Run 'gh auth refresh -s repo,workflow'" }
+    }
+
+    async fn run(&self, env: &Env<'_>) -> Outcome {
+        // -i prints the response headers; classic tokens list their scopes there
+        let Some(response) = output(env.system, "gh", &["api", "-i", "user"]).await else {
+            return Outcome::skip("gh not installed or not logged in");
         };
-        println!("{}", serde_json::to_string(&output)?);
-    } else {
-        if all_passed {
-            println!("\n{} Environment looks healthy!", style("✅").green());
+        let Some(scopes) = response.lines().find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.eq_ignore_ascii_case("x-oauth-scopes").then(|| value.trim().to_string())
+        }) else {
+            return Outcome::skip("fine-grained token; scopes are not listed");
+        };
+
+        let granted: Vec<&str> = scopes.split(',').map(str::trim).collect();
+        let missing: Vec<&str> = REQUIRED_SCOPES.into_iter().filter(|s| !granted.contains(s)).collect();
+        if missing.is_empty() {
+            Outcome::pass(scopes)
         } else {
-            println!("\n{} Environment has issues.", style("⚠️").yellow());
+            Outcome::fail(format!("missing {}", missing.join(", ")))
         }
     }
+}
+
+struct GithubToken;
+
+#[async_trait]
+impl Check for GithubToken {
+    fn info(&self) -> CheckInfo {
+        CheckInfo { id: "github-token", title: "GITHUB_TOKEN Set", severity: Severity::Warning, exit_code: 23, remediation: "export GITHUB_TOKEN=$(gh auth token) for gc's API commands" }
+    }
 
-    Ok(())
+    async fn run(&self, env: &Env<'_>) -> Outcome {
+        if std::env::var("GITHUB_TOKEN").map_or(true, |t| t.is_empty()) {
+            return Outcome::fail("not set");
+        }
+        match env.github.check_auth().await {
+            Ok(user) => Outcome::pass(format!("authenticated as {}", user)),
+            Err(e) => Outcome::fail(format!("rejected by GitHub: {}", first_line(&e))),
+        }
+    }
 }
 
-fn print_status(name: &str, passed: bool) {
-    if passed {
-        println!("   {} {}", style("✓").green(), name);
-    } else {
-        println!("   {} {}", style("✗").red(), name);
+struct HooksInstalled;
+
+impl HooksInstalled {
+    async fn hook_path(env: &Env<'_>) -> Option<String> {
+        // Honors core.hooksPath and worktrees
+        output(env.system, "git", &["rev-parse", "--git-path", "hooks/pre-commit"]).await
+    }
+}
+
+#[async_trait]
+impl Check for HooksInstalled {
+    fn info(&self) -> CheckInfo {
+        CheckInfo { id: "hooks", title: "Pre-commit Hook", severity: Severity::Warning, exit_code: 24, remediation: "Run scripts/hooks/install-hooks.sh (--fix does this)" }
+    }
+
+    async fn run(&self, env: &Env<'_>) -> Outcome {
+        let Some(path) = Self::hook_path(env).await else {
+            return Outcome::skip("not in a git repository");
+        };
+        match env.fs.read_file(&path).await {
+            Ok(hook) if hook.contains(HOOK_MARKER) => Outcome::pass(path),
+            Ok(_) => Outcome::fail(format!("{} is not the protocol hook", path)),
+            Err(_) => Outcome::fail("not installed"),
+        }
+    }
+
+    async fn fix(&self, env: &Env<'_>) -> Option<Result<()>> {
+        let path = Self::hook_path(env).await?;
+        Some(async {
+            if env.fs.exists(&path).await? {
+                let backup = format!("{}.backup.{}", path, chrono::Utc::now().timestamp());
+                env.fs.move_file(&path, &backup).await?;
+            }
+            if let Some((dir, _)) = path.rsplit_once('/') {
+                env.fs.create_dir(dir).await?;
+            }
+            env.fs.write_file(&path, HOOK_WRAPPER).await?;
+            env.system.run_command("chmod", &["+x".to_string(), path.clone()]).await?;
+            Ok::<_, color_eyre::Report>(())
+        }.await)
+    }
+}
+
+struct AgentClis;
+
+#[async_trait]
+impl Check for AgentClis {
+    fn info(&self) -> CheckInfo {
+        CheckInfo { id: "agent-clis", title: "Agent CLIs", severity: Severity::Warning, exit_code: 25, remediation: "Install at least one agent CLI (claude, codex, gemini, copilot or jules)" }
+    }
+
+    async fn run(&self, env: &Env<'_>) -> Outcome {
+        let mut found = Vec::new();
+        for cli in AGENT_CLIS {
+            if env.system.check_command(cli).await.unwrap_or(false) {
+                found.push(cli);
+            }
+        }
+        if found.is_empty() {
+            Outcome::fail("none found")
+        } else {
+            Outcome::pass(found.join(", "))
+        }
+    }
+}
+
+struct RemoteReachable;
+
+#[async_trait]
+impl Check for RemoteReachable {
+    fn info(&self) -> CheckInfo {
+        CheckInfo { id: "remote", title: "Remote Reachable", severity: Severity::Warning, exit_code: 26, remediation: "Add a remote with 'git remote add origin <url>' and check your network and credentials" }
+    }
+
+    async fn run(&self, env: &Env<'_>) -> Outcome {
+        let Some(url) = output(env.system, "git", &["remote", "get-url", "origin"]).await else {
+            return Outcome::fail("no origin remote");
+        };
+        let ls_remote = output(env.system, "git", &["ls-remote", "--exit-code", "origin", "HEAD"]);
+        match tokio::time::timeout(REMOTE_TIMEOUT, ls_remote).await {
+            Ok(Some(_)) => Outcome::pass(url),
+            Ok(None) => Outcome::fail(format!("{} did not answer", url)),
+            Err(_) => Outcome::fail(format!("{} timed out after {}s", url, REMOTE_TIMEOUT.as_secs())),
+        }
+    }
+}
+
+struct ProtocolVersion;
+
+#[async_trait]
+impl Check for ProtocolVersion {
+    fn info(&self) -> CheckInfo {
+        CheckInfo { id: "protocol-version", title: "Protocol Version", severity: Severity::Warning, exit_code: 27, remediation: "Run 'gc update' to install the latest protocol" }
+    }
+
+    async fn run(&self, env: &Env<'_>) -> Outcome {
+        let installed = match env.fs.read_file(VERSION_FILE).await {
+            Ok(version) if !version.trim().is_empty() => version.trim().to_string(),
+            _ => return Outcome::fail(format!("not installed ({} missing)", VERSION_FILE)),
+        };
+
+        let latest = env.github
+            .get_file_content("iberi22", "Git-Core-Protocol", "main", VERSION_FILE)
+            .await
+            .map(|v| v.trim().to_string());
+        match latest {
+            Ok(latest) if latest.is_empty() => Outcome::skip(format!("{} (latest unknown)", installed)),
+            Ok(latest) if compare_versions(Some(&installed), &latest) == Ordering::Less => Outcome::fail(format!("{} (update available: {})", installed, latest)),
+            Ok(_) => Outcome::pass(format!("{} (up to date)", installed)),
+            Err(e) => Outcome::skip(format!("{} (latest unknown: {})", installed, first_line(&e))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::{MockFileSystemPort, MockGitHubPort, MockSystemPort};
+    use gc_core::ports::CoreError;
+
+    fn report(id: &'static str, severity: Severity, status: Status, exit_code: i32) -> CheckReport {
+        CheckReport { id, title: id, severity, status, detail: String::new(), remediation: None, exit_code, fixed: false }
+    }
+
+    fn version_fs(version: &'static str) -> MockFileSystemPort {
+        let mut fs = MockFileSystemPort::new();
+        fs.expect_read_file().returning(move |_| Ok(version.to_string()));
+        fs
+    }
+
+    #[tokio::test]
+    async fn test_update_available_is_a_warning_and_unknown_latest_is_not_a_pass() {
+        let system = MockSystemPort::new();
+        let fs = version_fs("3.0.0\n");
+
+        let mut github = MockGitHubPort::new();
+        github.expect_get_file_content().returning(|_, _, _, _| Ok("3.1.0\n".to_string()));
+        let outcome = ProtocolVersion.run(&Env { fs: &fs, system: &system, github: &github }).await;
+        assert_eq!(outcome.status, Status::Fail);
+        assert_eq!(outcome.detail, "3.0.0 (update available: 3.1.0)");
+        assert_eq!(ProtocolVersion.info().severity, Severity::Warning);
+
+        let mut offline = MockGitHubPort::new();
+        offline.expect_get_file_content().returning(|_, _, _, _| Err(CoreError::GitHub("offline".into())));
+        let outcome = ProtocolVersion.run(&Env { fs: &fs, system: &system, github: &offline }).await;
+        assert_eq!(outcome.status, Status::Skip);
+    }
+
+    #[test]
+    fn test_exit_code_comes_from_first_counted_failure() {
+        let reports = vec![
+            report("git", Severity::Error, Status::Pass, 10),
+            report("gh-cli", Severity::Warning, Status::Fail, 20),
+            report("remote", Severity::Warning, Status::Fail, 26),
+        ];
+        assert_eq!(exit_code(&reports, false), 0);
+        assert_eq!(exit_code(&reports, true), 20);
+
+        let reports = vec![
+            report("config", Severity::Error, Status::Fail, 13),
+            report("gh-cli", Severity::Warning, Status::Fail, 20),
+        ];
+        assert_eq!(exit_code(&reports, false), 13);
+    }
+
+    #[tokio::test]
+    async fn test_fix_installs_hook_and_backs_up_foreign_one() {
+        let mut system = MockSystemPort::new();
+        system.expect_run_command_output().returning(|_, args| match args.join(" ").as_str() {
+            "rev-parse --git-path hooks/pre-commit" => Ok(".git/hooks/pre-commit\n".to_string()),
+            other => panic!("unexpected command {}", other),
+        });
+        system.expect_run_command()
+            .withf(|cmd, args| cmd == "chmod" && args == ["+x", ".git/hooks/pre-commit"])
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut fs = MockFileSystemPort::new();
+        fs.expect_read_file().returning(|_| Ok("#!/bin/sh\nlint\n".to_string()));
+        fs.expect_exists().returning(|_| Ok(true));
+        fs.expect_move_file()
+            .withf(|from, to| from == ".git/hooks/pre-commit" && to.starts_with(".git/hooks/pre-commit.backup."))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        fs.expect_create_dir().returning(|_| Ok(()));
+        fs.expect_write_file()
+            .withf(|path, content| path == ".git/hooks/pre-commit" && content.contains(HOOK_MARKER))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let github = MockGitHubPort::new();
+        let env = Env { fs: &fs, system: &system, github: &github };
+
+        assert_eq!(HooksInstalled.run(&env).await.status, Status::Fail);
+        assert!(HooksInstalled.fix(&env).await.unwrap().is_ok());
     }
 }
@@ -94,7 +94,7 @@ pub async fn execute(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::commands::mocks::{MockFileSystemPort, MockGitHubPort};
+    use gc_core::ports::{MockFileSystemPort, MockGitHubPort};
     use mockall::predicate::*;
 
     #[tokio::test]
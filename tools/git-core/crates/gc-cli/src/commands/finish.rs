@@ -16,6 +16,14 @@ pub struct FinishArgs {
     #[arg(long)]
     pub skip_report: bool,
 
+    /// Group uncommitted changes into conventional commits before pushing
+    #[arg(long)]
+    pub commit: bool,
+
+    /// Issue number referenced in generated commits (detected from branch if omitted)
+    #[arg(long)]
+    pub issue: Option<u64>,
+
     /// Output in JSON format
     #[arg(long)]
     pub json: bool,
@@ -28,6 +36,15 @@ struct FinishOutput {
     validation_passed: bool,
     pushed: bool,
     report_generated: bool,
+    commits: Vec<String>,
+}
+
+/// A set of pending changes that share a conventional-commit type and scope.
+#[derive(Debug, PartialEq)]
+pub(crate) struct CommitGroup {
+    pub(crate) kind: &'static str,
+    pub(crate) scope: Option<&'static str>,
+    pub(crate) files: Vec<String>,
 }
 
 pub async fn execute(
@@ -67,35 +84,53 @@ pub async fn execute(
 
     // 2. Git Status Check
     // Ensure we have commits to push
-    let status_args = vec!["status".to_string(), "--porcelain".to_string()];
+    // -z keeps unusual paths unquoted; no renames so both sides of a move are committed
+    let status_args = vec!["status".to_string(), "--porcelain".to_string(), "-z".to_string(), "--no-renames".to_string()];
     let status = system.run_command_output("git", &status_args).await?;
+    let mut commits = Vec::new();
     if !status.trim().is_empty() {
-        if args.json {
-            // For JSON parsing, we might want to return a specific error structure,
-            // but for now returning result error is standard.
-            // Ideally we output JSON error, but color_eyre handles main loop.
-            // We'll let it bubble up, but maybe agents check stderr.
-        } else {
-            println!("\n{} Warning: You have uncommitted changes.", style("⚠️").yellow());
-            println!("   Please commit your changes before finishing.");
+        if !args.commit {
+            if !args.json {
+                println!("\n{} Warning: You have uncommitted changes.", style("⚠️").yellow());
+                println!("   Commit them manually or re-run with `gc finish --commit`.");
+            }
+            color_eyre::eyre::bail!("Uncommitted changes present; commit them or use --commit");
+        }
+
+        if !args.json {
+            println!("\n{} Committing pending changes", style("📦").cyan());
         }
-        // We could offer to auto-commit here in the future
-        return Ok(());
+        commits = auto_commit(&status, args.issue, args.json, system).await?;
     }
 
     // 3. Push
-    println!("\n{} Step 2: Push to Remote", style("⬆️").blue());
-    let branch_args = vec!["branch".to_string(), "--show-current".to_string()];
-    let branch = system.run_command_output("git", &branch_args).await?;
-    let branch = branch.trim();
+    if !args.json {
+        println!("\n{} Step 2: Push to Remote", style("⬆️").blue());
+    }
+    let branch = current_branch(system).await?;
 
     if branch.is_empty() {
-        eprintln!("   Error: Detached HEAD or no branch.");
-        return Ok(());
+        color_eyre::eyre::bail!("Detached HEAD or no branch; cannot push");
     }
 
-    println!("   Pushing {}...", branch);
-    let push_args = vec!["push".to_string(), "origin".to_string(), branch.to_string()];
+    // New branches have no upstream yet, so push with --set-upstream instead of failing
+    let upstream_args = vec!["rev-parse".to_string(), "--abbrev-ref".to_string(), "--symbolic-full-name".to_string(), "@{u}".to_string()];
+    let has_upstream = system.run_command_output("git", &upstream_args).await.is_ok();
+
+    let mut push_args = vec!["push".to_string()];
+    if !has_upstream {
+        push_args.push("--set-upstream".to_string());
+    }
+    push_args.push("origin".to_string());
+    push_args.push(branch.clone());
+
+    if !args.json {
+        if has_upstream {
+            println!("   Pushing {}...", branch);
+        } else {
+            println!("   Pushing {} (setting upstream to origin/{})...", branch, branch);
+        }
+    }
     match system.run_command("git", &push_args).await {
         Ok(_) => {
             if !args.json {
@@ -104,9 +139,7 @@ pub async fn execute(
         },
         Err(e) => {
             eprintln!("   {} Push failed: {}", style("❌").red(), e);
-            // Hint: maybe upstream is missing
-             println!("   Tip: Try `git push --set-upstream origin {}` manually if this is a new branch.", branch);
-             return Err(e.into());
+            return Err(e.into());
         }
     }
 
@@ -126,10 +159,11 @@ pub async fn execute(
     if args.json {
         let output = FinishOutput {
             success: true,
-            branch: branch.to_string(),
+            branch,
             validation_passed: !args.skip_validate,
             pushed: true,
             report_generated: !args.skip_report,
+            commits,
         };
         println!("{}", serde_json::to_string(&output).unwrap()); // Safe unwrap for basic struct
     } else {
@@ -137,3 +171,270 @@ pub async fn execute(
     }
     Ok(())
 }
+
+pub(crate) async fn current_branch(system: &impl SystemPort) -> Result<String> {
+    let branch_args = vec!["branch".to_string(), "--show-current".to_string()];
+    let branch = system.run_command_output("git", &branch_args).await?;
+    Ok(branch.trim().to_string())
+}
+
+/// Commits every pending change, one conventional commit per concern.
+/// Returns the commit messages that were created.
+async fn auto_commit(status: &str, issue: Option<u64>, json: bool, system: &impl SystemPort) -> Result<Vec<String>> {
+    let branch = current_branch(system).await?;
+    let issue = issue.or_else(|| issue_from_branch(&branch));
+    let groups = group_changes(&parse_porcelain(status), &branch);
+
+    let mut messages = Vec::new();
+    for group in groups {
+        let message = commit_message(&group, issue);
+
+        let mut add_args = vec!["add".to_string(), "-A".to_string(), "--".to_string()];
+        add_args.extend(group.files.iter().cloned());
+        system.run_command_output("git", &add_args).await?;
+
+        // Passing the paths restricts the commit to this group even if other files are staged
+        let mut commit_args = vec!["commit".to_string(), "-m".to_string(), message.clone(), "--".to_string()];
+        commit_args.extend(group.files.iter().cloned());
+        system.run_command_output("git", &commit_args).await?;
+
+        if !json {
+            println!("   {} {}", style("✓").green(), message);
+        }
+        messages.push(message);
+    }
+
+    Ok(messages)
+}
+
+/// Extracts file paths from `git status --porcelain -z --no-renames` output.
+fn parse_porcelain(status: &str) -> Vec<String> {
+    status
+        .split('\0')
+        .filter(|entry| entry.len() > 3)
+        .map(|entry| entry[3..].to_string())
+        .collect()
+}
+
+/// Detects an issue number in branch names such as `feat/123-login` or `auto-42`.
+pub(crate) fn issue_from_branch(branch: &str) -> Option<u64> {
+    let re = regex::Regex::new(r"(?:^|[/_-])#?(\d+)(?:[/_-]|$)").ok()?;
+    re.captures(branch).and_then(|c| c[1].parse().ok())
+}
+
+/// Maps the branch prefix created by `gc task` to a conventional-commit type.
+pub(crate) fn kind_from_branch(branch: &str) -> &'static str {
+    match branch.split('/').next().unwrap_or("").to_lowercase().as_str() {
+        "bug" | "fix" | "hotfix" => "fix",
+        "docs" => "docs",
+        "refactor" => "refactor",
+        "test" => "test",
+        "chore" | "task" => "chore",
+        "perf" => "perf",
+        _ => "feat",
+    }
+}
+
+fn classify(path: &str, branch_kind: &'static str) -> (&'static str, Option<&'static str>) {
+    let lower = path.to_lowercase();
+    let file_name = lower.rsplit('/').next().unwrap_or(&lower);
+
+    if lower.starts_with(".github/workflows/") || lower.starts_with(".github/actions/") {
+        ("ci", None)
+    } else if lower.starts_with(".github/issues/") {
+        ("chore", Some("issues"))
+    } else if lower.starts_with("tests/") || lower.contains("/tests/") || file_name.contains("_test.")
+        || file_name.contains(".test.") || file_name.contains(".spec.") {
+        ("test", None)
+    } else if lower.starts_with("docs/") || lower.ends_with(".md") {
+        ("docs", None)
+    } else if matches!(file_name, "cargo.toml" | "cargo.lock" | "package.json" | "package-lock.json")
+        || lower.ends_with(".toml") || lower.ends_with(".yml") || lower.ends_with(".yaml") {
+        ("chore", Some("config"))
+    } else if lower.starts_with("scripts/") {
+        ("chore", Some("scripts"))
+    } else {
+        (branch_kind, None)
+    }
+}
+
+/// Groups changed files by concern, preserving the order in which concerns first appear.
+fn group_changes(files: &[String], branch: &str) -> Vec<CommitGroup> {
+    let branch_kind = kind_from_branch(branch);
+    let mut groups: Vec<CommitGroup> = Vec::new();
+
+    for file in files {
+        let (kind, scope) = classify(file, branch_kind);
+        match groups.iter_mut().find(|g| g.kind == kind && g.scope == scope) {
+            Some(group) => group.files.push(file.clone()),
+            None => groups.push(CommitGroup { kind, scope, files: vec![file.clone()] }),
+        }
+    }
+
+    groups
+}
+
+pub(crate) fn commit_message(group: &CommitGroup, issue: Option<u64>) -> String {
+    let prefix = match group.scope {
+        Some(scope) => format!("{}({})", group.kind, scope),
+        None => group.kind.to_string(),
+    };
+    let subject = if group.files.len() == 1 {
+        format!("update {}", group.files[0])
+    } else {
+        format!("update {} files", group.files.len())
+    };
+
+    match issue {
+        Some(n) => format!("{}: {} (#{})", prefix, subject, n),
+        None => format!("{}: {}", prefix, subject),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::{MockSystemPort, MockGitHubPort};
+    use mockall::predicate::*;
+
+    fn args(commit: bool) -> FinishArgs {
+        FinishArgs { skip_validate: true, skip_report: true, commit, issue: None, json: true }
+    }
+
+    fn status_args() -> Vec<String> {
+        ["status", "--porcelain", "-z", "--no-renames"].iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_porcelain_keeps_both_sides_of_renames() {
+        let files = parse_porcelain(" M src/lib.rs\0D  old.md\0A  docs/new.md\0?? with space.txt\0?? caf\u{e9}.md\0");
+        assert_eq!(files, vec!["src/lib.rs", "old.md", "docs/new.md", "with space.txt", "caf\u{e9}.md"]);
+    }
+
+    #[tokio::test]
+    async fn test_finish_commit_includes_rename_source() {
+        let mut mock_system = MockSystemPort::new();
+        let mock_github = MockGitHubPort::new();
+
+        mock_system.expect_run_command_output()
+            .with(eq("git"), eq(status_args()))
+            .returning(|_, _| Ok("D  docs/old.md\0A  docs/new.md\0".to_string()));
+        mock_system.expect_run_command_output()
+            .with(eq("git"), eq(vec!["branch".to_string(), "--show-current".to_string()]))
+            .returning(|_, _| Ok("docs/rename\n".to_string()));
+        mock_system.expect_run_command_output()
+            .with(eq("git"), function(|a: &[String]| a.first().map(String::as_str) == Some("add")))
+            .returning(|_, _| Ok(String::new()));
+        mock_system.expect_run_command_output()
+            .with(eq("git"), eq(vec!["commit".to_string(), "-m".to_string(), "docs: update 2 files".to_string(), "--".to_string(), "docs/old.md".to_string(), "docs/new.md".to_string()]))
+            .times(1)
+            .returning(|_, _| Ok(String::new()));
+        mock_system.expect_run_command_output()
+            .with(eq("git"), function(|a: &[String]| a.first().map(String::as_str) == Some("rev-parse")))
+            .returning(|_, _| Ok("origin/docs/rename\n".to_string()));
+        mock_system.expect_run_command()
+            .returning(|_, _| Ok(()));
+
+        let res = execute(args(true), &mock_system, &mock_github).await;
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_issue_from_branch() {
+        assert_eq!(issue_from_branch("feat/123-login"), Some(123));
+        assert_eq!(issue_from_branch("auto-42"), Some(42));
+        assert_eq!(issue_from_branch("feat/add-oauth2"), None);
+    }
+
+    #[test]
+    fn test_group_changes_by_concern() {
+        let files = vec![
+            "src/auth.rs".to_string(),
+            "docs/auth.md".to_string(),
+            "src/session.rs".to_string(),
+            "tests/auth_test.rs".to_string(),
+            ".github/workflows/ci.yml".to_string(),
+        ];
+        let groups = group_changes(&files, "bug/login-crash");
+
+        assert_eq!(groups.len(), 4);
+        assert_eq!(groups[0], CommitGroup { kind: "fix", scope: None, files: vec!["src/auth.rs".into(), "src/session.rs".into()] });
+        assert_eq!(groups[1].kind, "docs");
+        assert_eq!(groups[2].kind, "test");
+        assert_eq!(groups[3].kind, "ci");
+        assert_eq!(commit_message(&groups[0], Some(7)), "fix: update 2 files (#7)");
+        assert_eq!(commit_message(&groups[1], None), "docs: update docs/auth.md");
+    }
+
+    #[tokio::test]
+    async fn test_finish_fails_on_uncommitted_changes() {
+        let mut mock_system = MockSystemPort::new();
+        let mock_github = MockGitHubPort::new();
+
+        mock_system.expect_run_command_output()
+            .with(eq("git"), eq(status_args()))
+            .returning(|_, _| Ok(" M src/main.rs\0".to_string()));
+
+        let res = execute(args(false), &mock_system, &mock_github).await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_finish_sets_upstream_for_new_branch() {
+        let mut mock_system = MockSystemPort::new();
+        let mock_github = MockGitHubPort::new();
+
+        mock_system.expect_run_command_output()
+            .with(eq("git"), eq(status_args()))
+            .returning(|_, _| Ok(String::new()));
+        mock_system.expect_run_command_output()
+            .with(eq("git"), eq(vec!["branch".to_string(), "--show-current".to_string()]))
+            .returning(|_, _| Ok("feat/new-thing\n".to_string()));
+        mock_system.expect_run_command_output()
+            .with(eq("git"), function(|a: &[String]| a.first().map(String::as_str) == Some("rev-parse")))
+            .returning(|_, _| Err(gc_core::ports::CoreError::System("no upstream".into())));
+        mock_system.expect_run_command()
+            .with(eq("git"), eq(vec!["push".to_string(), "--set-upstream".to_string(), "origin".to_string(), "feat/new-thing".to_string()]))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let res = execute(args(false), &mock_system, &mock_github).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_finish_commit_groups_changes() {
+        let mut mock_system = MockSystemPort::new();
+        let mock_github = MockGitHubPort::new();
+
+        mock_system.expect_run_command_output()
+            .with(eq("git"), eq(status_args()))
+            .times(1)
+            .returning(|_, _| Ok(" M src/main.rs\0 M README.md\0".to_string()));
+        mock_system.expect_run_command_output()
+            .with(eq("git"), eq(vec!["branch".to_string(), "--show-current".to_string()]))
+            .returning(|_, _| Ok("feat/12-widgets\n".to_string()));
+        mock_system.expect_run_command_output()
+            .with(eq("git"), function(|a: &[String]| a.first().map(String::as_str) == Some("add")))
+            .times(2)
+            .returning(|_, _| Ok(String::new()));
+        mock_system.expect_run_command_output()
+            .with(eq("git"), eq(vec!["commit".to_string(), "-m".to_string(), "feat: update src/main.rs (#12)".to_string(), "--".to_string(), "src/main.rs".to_string()]))
+            .times(1)
+            .returning(|_, _| Ok(String::new()));
+        mock_system.expect_run_command_output()
+            .with(eq("git"), eq(vec!["commit".to_string(), "-m".to_string(), "docs: update README.md (#12)".to_string(), "--".to_string(), "README.md".to_string()]))
+            .times(1)
+            .returning(|_, _| Ok(String::new()));
+        mock_system.expect_run_command_output()
+            .with(eq("git"), function(|a: &[String]| a.first().map(String::as_str) == Some("rev-parse")))
+            .returning(|_, _| Ok("origin/feat/12-widgets".to_string()));
+        mock_system.expect_run_command()
+            .with(eq("git"), eq(vec!["push".to_string(), "origin".to_string(), "feat/12-widgets".to_string()]))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let res = execute(args(true), &mock_system, &mock_github).await;
+        assert!(res.is_ok());
+    }
+}
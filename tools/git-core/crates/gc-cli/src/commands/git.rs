@@ -1,6 +1,15 @@
 use clap::{Args, Subcommand};
-use gc_core::ports::SystemPort;
+use gc_core::ports::{GitHubPort, SystemPort};
+use gc_core::PullRequest;
+use atomicity_checker::{categorize_file, Concern, Config};
 use console::style;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use crate::commands::finish::{current_branch, issue_from_branch};
+use crate::commands::pr::{default_branch, git, is_contained_in};
+
+/// Same file the atomicity-checker workflow reads.
+pub(crate) const ATOMICITY_CONFIG: &str = ".github/atomicity-config.yml";
 
 #[derive(Args, Debug)]
 pub struct GitArgs {
@@ -10,28 +19,495 @@ pub struct GitArgs {
 
 #[derive(Subcommand, Debug)]
 pub enum GitCommands {
-    /// Show git status with context
-    Status,
+    /// Show changes grouped by concern (source, tests, docs, config, infra)
+    Status {
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
     /// Show git log
     Log {
         #[arg(short, long, default_value = "5")]
         limit: usize,
     },
+    /// Show the diff, or per-file stats with --summary
+    Diff {
+        /// Commit or range to diff against (working tree vs index if omitted)
+        rev: Option<String>,
+
+        /// Diff staged changes
+        #[arg(long)]
+        staged: bool,
+
+        /// Lines added/removed per file, grouped by concern
+        #[arg(long)]
+        summary: bool,
+
+        /// Output the summary in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// List local branches with their linked issue and PR
+    Branches {
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Delete local branches whose PRs were merged
+    Cleanup {
+        /// Only list the branches that would be deleted
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct StatusEntry {
+    path: String,
+    /// Two-letter porcelain code (`M `, ` M`, `??`, ...)
+    code: String,
+    concern: Concern,
+}
+
+#[derive(Serialize)]
+struct StatusOutput {
+    branch: String,
+    tracking: Option<String>,
+    concerns: BTreeMap<String, Vec<StatusEntry>>,
+    /// Files excluded from atomicity by `ignore_files` (lockfiles, ...)
+    ignored: Vec<StatusEntry>,
+    atomic: bool,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct FileStat {
+    path: String,
+    /// `None` for binary files
+    added: Option<u64>,
+    removed: Option<u64>,
+    concern: Concern,
+}
+
+#[derive(Serialize)]
+struct DiffSummary {
+    files: Vec<FileStat>,
+    added: u64,
+    removed: u64,
+}
+
+#[derive(Serialize)]
+struct BranchInfo {
+    name: String,
+    current: bool,
+    /// e.g. `ahead 2, behind 1` or `gone`
+    tracking: String,
+    last_commit: String,
+    issue: Option<u64>,
+    pr: Option<u64>,
+    pr_state: Option<String>,
+    /// Head commit of the PR, to tell whether the local branch moved on since
+    #[serde(skip)]
+    pr_head: Option<String>,
+}
+
+#[derive(Serialize)]
+struct CleanupOutput {
+    deleted: Vec<String>,
+    /// Merged branches kept because they point somewhere the PR never did
+    kept: Vec<String>,
+    dry_run: bool,
 }
 
 pub async fn execute(
     args: GitArgs,
+    github: &impl GitHubPort,
     system: &impl SystemPort,
 ) -> color_eyre::Result<()> {
     match args.command {
-        GitCommands::Status => {
-            println!("{}", style("📊 Git Status").bold());
-            system.run_command("git", &["status".to_string()]).await?;
+        GitCommands::Status { json } => {
+            let config = load_atomicity_config(ATOMICITY_CONFIG)?;
+            let output = git(system, &["status", "--porcelain", "--branch"]).await?;
+            let status = group_status(&output, &config);
+
+            if json {
+                println!("{}", serde_json::to_string(&status).unwrap());
+            } else {
+                print_status(&status);
+            }
         }
         GitCommands::Log { limit } => {
             println!("{}", style("📜 Git Log").bold());
             system.run_command("git", &["log".to_string(), "--oneline".to_string(), format!("-{}", limit)]).await?;
         }
+        GitCommands::Diff { rev, staged, summary, json } => {
+            let mut diff_args = vec!["diff".to_string()];
+            if staged {
+                diff_args.push("--cached".to_string());
+            }
+            diff_args.extend(rev);
+
+            if !summary && !json {
+                system.run_command("git", &diff_args).await?;
+                return Ok(());
+            }
+
+            diff_args.insert(1, "--numstat".to_string());
+            let output = system.run_command_output("git", &diff_args).await?;
+            let summary = summarize_diff(&output, &load_atomicity_config(ATOMICITY_CONFIG)?);
+
+            if json {
+                println!("{}", serde_json::to_string(&summary).unwrap());
+            } else {
+                print_diff_summary(&summary);
+            }
+        }
+        GitCommands::Branches { json } => {
+            let branches = branches(github, system).await?;
+            if json {
+                println!("{}", serde_json::to_string(&branches).unwrap());
+                return Ok(());
+            }
+
+            println!("{}", style("🌿 Branches").bold());
+            for branch in &branches {
+                let marker = if branch.current { style("*").green() } else { style(" ") };
+                let issue = branch.issue.map(|n| format!("issue #{}", n)).unwrap_or_default();
+                let pr = match (branch.pr, &branch.pr_state) {
+                    (Some(n), Some(state)) => format!("PR #{} {}", n, state),
+                    _ => String::new(),
+                };
+                println!("{} {:<30} {:<12} {:<14} {}  {}",
+                    marker,
+                    style(&branch.name).cyan(),
+                    style(&branch.tracking).yellow(),
+                    issue,
+                    pr,
+                    style(&branch.last_commit).dim()
+                );
+            }
+        }
+        GitCommands::Cleanup { dry_run, json } => {
+            let branches = branches(github, system).await?;
+            let trunk = default_branch(system).await;
+            let mut merged = Vec::new();
+            let mut kept = Vec::new();
+            for branch in branches {
+                if branch.current || branch.name == trunk || branch.pr_state.as_deref() != Some("merged") {
+                    continue;
+                }
+                // Commits added after the merge, or a fork/older PR that reused the name
+                if is_merged_tip(system, &branch).await {
+                    merged.push(branch.name);
+                } else {
+                    kept.push(branch.name);
+                }
+            }
+
+            if !dry_run {
+                // -D: squash merges leave the branch unmerged as far as git can tell
+                for branch in &merged {
+                    git(system, &["branch", "-D", branch]).await?;
+                }
+                git(system, &["fetch", "--prune", "origin"]).await?;
+            }
+
+            if json {
+                println!("{}", serde_json::to_string(&CleanupOutput { deleted: merged, kept, dry_run }).unwrap());
+                return Ok(());
+            }
+            if merged.is_empty() {
+                println!("No merged branches to clean up.");
+            } else {
+                let verb = if dry_run { "Would delete" } else { "Deleted" };
+                for branch in &merged {
+                    println!("{} {} {}", style("🧹").cyan(), verb, branch);
+                }
+            }
+            for branch in &kept {
+                println!("{} Kept {}: it has commits that are not in its merged PR", style("⚠️").yellow(), branch);
+            }
+        }
     }
     Ok(())
 }
+
+/// Loads the atomicity rules shared with the atomicity-checker workflow (defaults if absent).
+pub(crate) fn load_atomicity_config(path: &str) -> color_eyre::Result<Config> {
+    Config::load(path).map_err(|e| color_eyre::eyre::eyre!("{:#}", e))
+}
+
+/// Parses `git status --porcelain --branch` into changes grouped by concern.
+fn group_status(output: &str, config: &Config) -> StatusOutput {
+    let mut status = StatusOutput {
+        branch: String::new(),
+        tracking: None,
+        concerns: BTreeMap::new(),
+        ignored: Vec::new(),
+        atomic: true,
+    };
+
+    for line in output.lines() {
+        if let Some(header) = line.strip_prefix("## ") {
+            // "main...origin/main [ahead 1]"
+            let (branch, tracking) = match header.split_once(' ') {
+                Some((branch, tracking)) => (branch, Some(tracking.trim_matches(|c| c == '[' || c == ']').to_string())),
+                None => (header, None),
+            };
+            status.branch = branch.split("...").next().unwrap_or(branch).to_string();
+            status.tracking = tracking;
+            continue;
+        }
+        if line.len() <= 3 {
+            continue;
+        }
+
+        let path = &line[3..];
+        // Renames are reported as "old -> new"
+        let path = path.rsplit(" -> ").next().unwrap_or(path).trim_matches('"').to_string();
+        let entry = StatusEntry { concern: categorize_file(&path, config), code: line[..2].to_string(), path };
+        if config.should_ignore_file(&entry.path) {
+            status.ignored.push(entry);
+        } else {
+            status.concerns.entry(entry.concern.to_string()).or_default().push(entry);
+        }
+    }
+
+    status.atomic = status.concerns.len() <= config.max_concerns;
+    status
+}
+
+/// Parses `git diff --numstat` output.
+fn summarize_diff(output: &str, config: &Config) -> DiffSummary {
+    let mut files = Vec::new();
+    for line in output.lines() {
+        let mut parts = line.splitn(3, '\t');
+        let (Some(added), Some(removed), Some(path)) = (parts.next(), parts.next(), parts.next()) else {
+            continue;
+        };
+        // Renames: "src/{old.rs => new.rs}" or "old => new"
+        let path = match (path.find('{'), path.find('}')) {
+            (Some(open), Some(close)) if open < close => {
+                let inner = &path[open + 1..close];
+                let new = inner.rsplit(" => ").next().unwrap_or(inner);
+                format!("{}{}{}", &path[..open], new, &path[close + 1..]).replace("//", "/")
+            }
+            _ => path.rsplit(" => ").next().unwrap_or(path).to_string(),
+        };
+        files.push(FileStat {
+            concern: categorize_file(&path, config),
+            added: added.parse().ok(),
+            removed: removed.parse().ok(),
+            path,
+        });
+    }
+
+    DiffSummary {
+        added: files.iter().filter_map(|f| f.added).sum(),
+        removed: files.iter().filter_map(|f| f.removed).sum(),
+        files,
+    }
+}
+
+/// Local branches with tracking state, linked issue (from the name) and PR (by head).
+async fn branches(github: &impl GitHubPort, system: &impl SystemPort) -> color_eyre::Result<Vec<BranchInfo>> {
+    let output = system.run_command_output("git", &["remote", "get-url", "origin"].map(|s| s.to_string())).await?;
+    let (owner, repo) = crate::commands::issue::parse_repo_from_url(&output)?;
+
+    let current = current_branch(system).await?;
+    let refs = git(system, &["for-each-ref", "--format=%(refname:short)\t%(upstream:track,nobracket)\t%(committerdate:relative)", "refs/heads"]).await?;
+
+    let mut branches = Vec::new();
+    for line in refs.lines() {
+        let mut parts = line.split('\t');
+        let Some(name) = parts.next().filter(|n| !n.is_empty()).map(str::to_string) else { continue };
+        let tracking = parts.next().unwrap_or_default().to_string();
+        let last_commit = parts.next().unwrap_or_default().to_string();
+        // Per branch rather than every closed PR of the repository, which can be thousands
+        let prs = github.list_branch_prs(&owner, &repo, &name).await?;
+        let pr: Option<&PullRequest> = prs.iter().find(|pr| pr.state.eq_ignore_ascii_case("open")).or(prs.first());
+        branches.push(BranchInfo {
+            current: name == current,
+            issue: issue_from_branch(&name),
+            pr: pr.map(|pr| pr.number),
+            pr_state: pr.map(|pr| if pr.merged { "merged".to_string() } else { pr.state.to_lowercase() }),
+            pr_head: pr.map(|pr| pr.head_sha.clone()).filter(|sha| !sha.is_empty()),
+            name,
+            tracking,
+            last_commit,
+        });
+    }
+    Ok(branches)
+}
+
+/// True if the local branch holds nothing beyond its PR's head commit.
+async fn is_merged_tip(system: &impl SystemPort, branch: &BranchInfo) -> bool {
+    let Some(head) = branch.pr_head.as_deref() else { return false };
+    let Ok(tip) = git(system, &["rev-parse", &format!("refs/heads/{}", branch.name)]).await else { return false };
+    is_contained_in(system, &tip, head).await
+}
+
+fn print_status(status: &StatusOutput) {
+    let tracking = status.tracking.as_deref().map(|t| format!(" [{}]", t)).unwrap_or_default();
+    println!("{} {}{}", style("📊 Git Status").bold(), style(&status.branch).cyan(), style(tracking).yellow());
+
+    if status.concerns.is_empty() && status.ignored.is_empty() {
+        println!("   Working tree clean");
+        return;
+    }
+    for (concern, entries) in &status.concerns {
+        println!("\n{} ({})", style(concern).bold(), entries.len());
+        for entry in entries {
+            println!("   {} {}", style(&entry.code).green(), entry.path);
+        }
+    }
+    if !status.ignored.is_empty() {
+        println!("\n{} ({})", style("ignored").dim(), status.ignored.len());
+        for entry in &status.ignored {
+            println!("   {} {}", style(&entry.code).dim(), style(&entry.path).dim());
+        }
+    }
+    if !status.atomic {
+        println!("\n{} Changes span {} concerns; commit them separately to keep commits atomic.",
+            style("⚠️").yellow(), status.concerns.len());
+    }
+}
+
+fn print_diff_summary(summary: &DiffSummary) {
+    println!("{}", style("📝 Diff Summary").bold());
+    for file in &summary.files {
+        let stats = match (file.added, file.removed) {
+            (Some(added), Some(removed)) => format!("{} {}", style(format!("+{}", added)).green(), style(format!("-{}", removed)).red()),
+            _ => style("binary".to_string()).dim().to_string(),
+        };
+        println!("   {:<8} {}  {}", style(file.concern.to_string()).dim(), file.path, stats);
+    }
+    println!("\n   {} files, {} {}",
+        summary.files.len(),
+        style(format!("+{}", summary.added)).green(),
+        style(format!("-{}", summary.removed)).red()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::{MockGitHubPort, MockSystemPort};
+    use mockall::predicate::*;
+
+    fn pr(number: u64, head: &str, merged: bool) -> PullRequest {
+        PullRequest {
+            number,
+            title: head.into(),
+            body: None,
+            state: if merged { "Closed".into() } else { "Open".into() },
+            html_url: String::new(),
+            head_ref: head.into(),
+            base_ref: "main".into(),
+            head_owner: "acme".into(),
+            draft: false,
+            author: String::new(),
+            mergeable: None,
+            merged,
+            head_sha: format!("sha-{}", number),
+            labels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_group_status_by_concern() {
+        let output = "## feat/12-login...origin/feat/12-login [ahead 2]\n M src/auth.rs\n?? tests/auth_test.rs\nR  old.md -> docs/login.md\n M Cargo.lock\n";
+        let status = group_status(output, &Config::default());
+
+        assert_eq!(status.branch, "feat/12-login");
+        assert_eq!(status.tracking.as_deref(), Some("ahead 2"));
+        assert_eq!(status.concerns.keys().collect::<Vec<_>>(), vec!["docs", "source", "tests"]);
+        assert_eq!(status.concerns["docs"][0], StatusEntry { path: "docs/login.md".into(), code: "R ".into(), concern: Concern::Docs });
+        assert_eq!(status.ignored.len(), 1);
+        assert!(!status.atomic);
+    }
+
+    #[test]
+    fn test_summarize_diff_numstat() {
+        let output = "10\t2\tsrc/lib.rs\n-\t-\tlogo.png\n3\t0\tsrc/{old.rs => new.rs}\n";
+        let summary = summarize_diff(output, &Config::default());
+
+        assert_eq!(summary.files[1].added, None);
+        assert_eq!(summary.files[2].path, "src/new.rs");
+        assert_eq!((summary.added, summary.removed), (13, 2));
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_deletes_merged_branches_only() {
+        let mut system = MockSystemPort::new();
+        let mut github = MockGitHubPort::new();
+
+        let answers: [(&str, &str); 3] = [
+            ("remote get-url origin", "git@github.com:acme/app.git"),
+            ("branch --show-current", "feat/3-wip"),
+            ("symbolic-ref --short refs/remotes/origin/HEAD", "origin/main"),
+        ];
+        system.expect_run_command_output()
+            .withf(|_, args| args[0] == "for-each-ref")
+            .returning(|_, _| Ok("main\t\t2 days ago\nfeat/1-done\tgone\t3 days ago\nfeat/2-open\t\t1 hour ago\nfeat/3-wip\t\tnow\n".into()));
+        system.expect_run_command_output()
+            .withf(|_, args| args.join(" ") == "rev-parse refs/heads/feat/1-done")
+            .returning(|_, _| Ok("sha-1\n".into()));
+        system.expect_run_command_output()
+            .withf(|_, args| args.join(" ") == "branch -D feat/1-done")
+            .times(1)
+            .returning(|_, _| Ok(String::new()));
+        system.expect_run_command_output()
+            .withf(|_, args| args.join(" ") == "fetch --prune origin")
+            .times(1)
+            .returning(|_, _| Ok(String::new()));
+        system.expect_run_command_output()
+            .returning(move |_, args| Ok(answers.iter().find(|(c, _)| *c == args.join(" ")).map(|(_, a)| a.to_string()).unwrap_or_default()));
+
+        github.expect_list_branch_prs().returning(|_, _, branch| Ok(match branch {
+            "feat/1-done" => vec![pr(1, branch, true)],
+            "feat/2-open" => vec![pr(2, branch, false)],
+            "feat/3-wip" => vec![pr(3, branch, true)],
+            _ => vec![],
+        }));
+
+        let args = GitArgs { command: GitCommands::Cleanup { dry_run: false, json: true } };
+        execute(args, &github, &system).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_keeps_branch_that_moved_past_its_pr() {
+        let mut system = MockSystemPort::new();
+        let mut github = MockGitHubPort::new();
+
+        system.expect_run_command_output()
+            .withf(|_, args| args[0] == "for-each-ref")
+            .returning(|_, _| Ok("main\t\t2 days ago\nfeat/1-done\t\t1 hour ago\n".into()));
+        system.expect_run_command_output()
+            .withf(|_, args| args.join(" ") == "rev-parse refs/heads/feat/1-done")
+            .returning(|_, _| Ok("sha-local\n".into()));
+        system.expect_run_command_output()
+            .withf(|_, args| args.join(" ") == "merge-base --is-ancestor sha-local sha-1")
+            .returning(|_, _| Err(gc_core::ports::CoreError::System("not an ancestor".into())));
+        system.expect_run_command_output()
+            .withf(|_, args| args[0] == "branch" && args[1] == "-D")
+            .never();
+        system.expect_run_command_output()
+            .returning(|_, args| Ok(match args.join(" ").as_str() {
+                "remote get-url origin" => "git@github.com:acme/app.git".into(),
+                "branch --show-current" => "main".into(),
+                _ => String::new(),
+            }));
+
+        github.expect_list_branch_prs().returning(|_, _, branch| Ok(match branch {
+            "feat/1-done" => vec![pr(1, branch, true)],
+            _ => vec![],
+        }));
+
+        let args = GitArgs { command: GitCommands::Cleanup { dry_run: false, json: true } };
+        execute(args, &github, &system).await.unwrap();
+    }
+}
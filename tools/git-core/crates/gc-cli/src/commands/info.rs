@@ -1,25 +1,448 @@
 use clap::Args;
-use gc_core::ports::SystemPort;
+use gc_core::ports::{FileSystemPort, GitHubPort, SystemPort};
+use gc_core::WorkflowRun;
+use atomicity_checker::categorize_file;
 use console::style;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use crate::commands::git::{load_atomicity_config, ATOMICITY_CONFIG};
+use crate::commands::pr::git;
+
+const VERSION_FILE: &str = ".git-core-protocol-version";
+const CONTEXT_FILE: &str = ".ai-core/CURRENT_CONTEXT.md";
+/// Commits inspected for the atomic-commit ratio.
+const ATOMICITY_SAMPLE: usize = 50;
+const WORKFLOW_SAMPLE: u8 = 20;
 
 #[derive(Args, Debug)]
-pub struct InfoArgs {}
+pub struct InfoArgs {
+    /// Output format
+    #[arg(short, long, default_value = "terminal", value_parser = ["terminal", "markdown", "json"])]
+    pub format: String,
+
+    /// Days of history that count as recent activity
+    #[arg(long, default_value = "30")]
+    pub days: u32,
+}
+
+#[derive(Serialize, Debug, Default)]
+struct Dashboard {
+    protocol_version: Option<String>,
+    /// Role equipped with `gc context equip`
+    agent_role: Option<String>,
+    /// `Solo` or `Team`
+    development_type: &'static str,
+    languages: Vec<CodeStats>,
+    directories: Vec<CodeStats>,
+    contributors: Vec<Contributor>,
+    issues: Option<LabelCounts>,
+    pull_requests: Option<LabelCounts>,
+    workflows: Option<WorkflowHealth>,
+    atomicity: AtomicityStats,
+    /// Sections that could not be collected
+    warnings: Vec<String>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct CodeStats {
+    name: String,
+    files: usize,
+    lines: usize,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct Contributor {
+    name: String,
+    commits: usize,
+    recent_commits: usize,
+}
+
+#[derive(Serialize, Debug, Default, PartialEq)]
+struct LabelCounts {
+    total: usize,
+    by_label: BTreeMap<String, usize>,
+}
+
+#[derive(Serialize, Debug, Default, PartialEq)]
+struct WorkflowHealth {
+    passed: usize,
+    failed: usize,
+    in_progress: usize,
+    /// Most recent first
+    runs: Vec<WorkflowRun>,
+}
+
+#[derive(Serialize, Debug, Default, PartialEq)]
+struct AtomicityStats {
+    commits: usize,
+    atomic: usize,
+    /// `None` when there is no history yet
+    ratio: Option<f64>,
+}
 
 pub async fn execute(
-    _args: InfoArgs,
+    args: InfoArgs,
+    fs: &impl FileSystemPort,
+    github: &impl GitHubPort,
     system: &impl SystemPort,
 ) -> color_eyre::Result<()> {
+    let dashboard = collect(args.days, fs, github, system).await?;
+
+    match args.format.as_str() {
+        "json" => println!("{}", serde_json::to_string(&dashboard).unwrap()),
+        "markdown" => print!("{}", render_markdown(&dashboard)),
+        _ => print_terminal(&dashboard),
+    }
+    Ok(())
+}
+
+async fn collect(
+    days: u32,
+    fs: &impl FileSystemPort,
+    github: &impl GitHubPort,
+    system: &impl SystemPort,
+) -> color_eyre::Result<Dashboard> {
+    let mut dashboard = Dashboard::default();
+
+    if fs.exists(VERSION_FILE).await? {
+        dashboard.protocol_version = Some(fs.read_file(VERSION_FILE).await?.trim().to_string());
+    }
+    if fs.exists(CONTEXT_FILE).await? {
+        dashboard.agent_role = agent_role(&fs.read_file(CONTEXT_FILE).await?);
+    }
+
+    // An empty repository has no HEAD yet
+    let totals = git(system, &["shortlog", "-s", "-n", "HEAD"]).await.unwrap_or_default();
+    let recent = git(system, &["shortlog", "-s", "-n", &format!("--since={}.days", days), "HEAD"]).await.unwrap_or_default();
+    dashboard.contributors = contributors(&totals, &recent);
+    dashboard.development_type = if dashboard.contributors.len() > 1 { "Team" } else { "Solo" };
+
+    let files = git(system, &["ls-files", "-z"]).await?;
+    let mut languages: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    let mut directories: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    for path in files.split('\0').filter(|p| !p.is_empty()) {
+        // Binary or unreadable files have no lines to count
+        let Ok(content) = fs.read_file(path).await else { continue };
+        let lines = content.lines().count();
+        if let Some(language) = language_of(path) {
+            let entry = languages.entry(language).or_default();
+            entry.0 += 1;
+            entry.1 += lines;
+        }
+        let directory = path.split_once('/').map(|(dir, _)| dir.to_string()).unwrap_or_else(|| ".".to_string());
+        let entry = directories.entry(directory).or_default();
+        entry.0 += 1;
+        entry.1 += lines;
+    }
+    dashboard.languages = by_lines(languages.into_iter().map(|(name, stats)| (name.to_string(), stats)));
+    dashboard.directories = by_lines(directories.into_iter());
+
+    let config = load_atomicity_config(ATOMICITY_CONFIG)?;
+    let log = git(system, &["log", "-n", &ATOMICITY_SAMPLE.to_string(), "--no-merges", "--format=%x1e%an", "--name-only"]).await.unwrap_or_default();
+    dashboard.atomicity = atomicity(&log, &config);
+
+    let remote = match git(system, &["remote", "get-url", "origin"]).await {
+        Ok(url) => crate::commands::issue::parse_repo_from_url(&url)
+            .map_err(|_| format!("`origin` ({}) is not a GitHub repository; GitHub sections skipped", url.trim())),
+        Err(_) => Err("No `origin` remote; GitHub sections skipped".to_string()),
+    };
+    match remote {
+        Ok((owner, repo)) => {
+            match github.list_issues(&owner, &repo, Some("open".to_string()), None).await {
+                Ok(issues) => dashboard.issues = Some(label_counts(issues.iter().map(|i| &i.labels))),
+                Err(e) => dashboard.warnings.push(format!("Issues unavailable: {}", e)),
+            }
+            match github.list_prs(&owner, &repo, Some("open".to_string())).await {
+                Ok(prs) => dashboard.pull_requests = Some(label_counts(prs.iter().map(|pr| &pr.labels))),
+                Err(e) => dashboard.warnings.push(format!("Pull requests unavailable: {}", e)),
+            }
+            match github.list_workflow_runs(&owner, &repo, WORKFLOW_SAMPLE).await {
+                Ok(runs) => dashboard.workflows = Some(workflow_health(runs)),
+                Err(e) => dashboard.warnings.push(format!("Workflow runs unavailable: {}", e)),
+            }
+        }
+        Err(warning) => dashboard.warnings.push(warning),
+    }
+
+    Ok(dashboard)
+}
+
+/// Reads the role from the header `gc context equip` writes.
+fn agent_role(context: &str) -> Option<String> {
+    context.lines()
+        .find_map(|line| line.split_once("ACTIVE AGENT PERSONA:"))
+        .map(|(_, role)| role.trim().to_string())
+        .filter(|role| !role.is_empty())
+}
+
+/// Merges `git shortlog -s -n` output for all history and for the recent window.
+fn contributors(totals: &str, recent: &str) -> Vec<Contributor> {
+    let parse = |output: &str| -> Vec<(String, usize)> {
+        output.lines()
+            .filter_map(|line| {
+                let (count, name) = line.trim().split_once('\t')?;
+                Some((name.trim().to_string(), count.trim().parse().ok()?))
+            })
+            .collect()
+    };
+    let recent = parse(recent);
+
+    parse(totals).into_iter().map(|(name, commits)| {
+        let recent_commits = recent.iter().find(|(n, _)| *n == name).map(|(_, c)| *c).unwrap_or(0);
+        Contributor { name, commits, recent_commits }
+    }).collect()
+}
+
+fn by_lines(stats: impl Iterator<Item = (String, (usize, usize))>) -> Vec<CodeStats> {
+    let mut stats: Vec<CodeStats> = stats.map(|(name, (files, lines))| CodeStats { name, files, lines }).collect();
+    stats.sort_by(|a, b| b.lines.cmp(&a.lines).then_with(|| a.name.cmp(&b.name)));
+    stats
+}
+
+fn language_of(path: &str) -> Option<&'static str> {
+    let extension = path.rsplit_once('.')?.1.to_lowercase();
+    Some(match extension.as_str() {
+        "rs" => "Rust",
+        "py" => "Python",
+        "ts" | "tsx" => "TypeScript",
+        "js" | "jsx" | "mjs" | "cjs" => "JavaScript",
+        "go" => "Go",
+        "java" => "Java",
+        "kt" | "kts" => "Kotlin",
+        "swift" => "Swift",
+        "c" | "h" => "C",
+        "cpp" | "cc" | "hpp" => "C++",
+        "cs" => "C#",
+        "rb" => "Ruby",
+        "php" => "PHP",
+        "sh" | "bash" => "Shell",
+        "ps1" | "psm1" => "PowerShell",
+        "md" => "Markdown",
+        "yml" | "yaml" => "YAML",
+        "toml" => "TOML",
+        "json" => "JSON",
+        "html" => "HTML",
+        "css" | "scss" => "CSS",
+        "sql" => "SQL",
+        _ => return None,
+    })
+}
+
+/// Share of recent commits touching no more concerns than the atomicity config allows.
+/// `log` is `git log --format=%x1e%an --name-only`; bot commits are left out.
+fn atomicity(log: &str, config: &atomicity_checker::Config) -> AtomicityStats {
+    let mut stats = AtomicityStats::default();
+    for commit in log.split('\x1e').filter(|c| !c.trim().is_empty()) {
+        let mut lines = commit.lines();
+        let author = lines.next().unwrap_or_default();
+        if config.is_bot_author(author) {
+            continue;
+        }
+        let concerns: HashSet<String> = lines
+            .map(str::trim)
+            .filter(|f| !f.is_empty() && !config.should_ignore_file(f))
+            .map(|f| categorize_file(f, config).to_string())
+            .collect();
+        stats.commits += 1;
+        if concerns.len() <= config.max_concerns {
+            stats.atomic += 1;
+        }
+    }
+    stats.ratio = (stats.commits > 0).then(|| stats.atomic as f64 / stats.commits as f64);
+    stats
+}
+
+fn label_counts<'a>(labels: impl Iterator<Item = &'a Vec<String>>) -> LabelCounts {
+    let mut counts = LabelCounts::default();
+    for labels in labels {
+        counts.total += 1;
+        for label in labels {
+            *counts.by_label.entry(label.clone()).or_default() += 1;
+        }
+    }
+    counts
+}
+
+fn workflow_health(runs: Vec<WorkflowRun>) -> WorkflowHealth {
+    let mut health = WorkflowHealth::default();
+    for run in &runs {
+        match run.conclusion.as_deref() {
+            _ if run.status != "completed" => health.in_progress += 1,
+            Some("success") | Some("skipped") | Some("neutral") => health.passed += 1,
+            _ => health.failed += 1,
+        }
+    }
+    health.runs = runs;
+    health
+}
+
+fn percent(ratio: Option<f64>) -> String {
+    ratio.map(|r| format!("{:.0}%", r * 100.0)).unwrap_or_else(|| "n/a".to_string())
+}
+
+fn print_terminal(d: &Dashboard) {
     println!("{}", style("ℹ️ Project Info").bold());
+    println!("Protocol Version: {}", style(d.protocol_version.as_deref().unwrap_or("not installed")).cyan());
+    println!("Agent Role: {}", style(d.agent_role.as_deref().unwrap_or("none equipped")).cyan());
+    println!("Development Type: {}", style(d.development_type).cyan());
+    println!("Atomic Commits: {} ({} of last {})",
+        style(percent(d.atomicity.ratio)).yellow(), d.atomicity.atomic, d.atomicity.commits);
 
-    // Detect if solo or team
-    // Simple heuristic: check number of contributors in git log
-    let output = system.run_command_output("git", &["shortlog", "-s", "-n", "HEAD"].map(|s| s.to_string())).await?;
-    let contributors = output.lines().count();
+    println!("\n{}", style("📚 Languages").bold());
+    for stats in d.languages.iter().take(8) {
+        println!("   {:<12} {:>8} lines  {:>5} files", stats.name, stats.lines, stats.files);
+    }
+    println!("\n{}", style("📁 Directories").bold());
+    for stats in d.directories.iter().take(8) {
+        println!("   {:<24} {:>8} lines  {:>5} files", stats.name, stats.lines, stats.files);
+    }
 
-    let dev_type = if contributors > 1 { "Team" } else { "Solo" };
+    println!("\n{} ({})", style("👥 Contributors").bold(), d.contributors.len());
+    for contributor in d.contributors.iter().take(10) {
+        println!("   {:<24} {:>5} commits  {}",
+            contributor.name, contributor.commits, style(format!("{} recent", contributor.recent_commits)).dim());
+    }
 
-    println!("Development Type: {}", style(dev_type).cyan());
-    println!("Contributors: {}", style(contributors).yellow());
+    for (title, counts) in [("🐛 Open Issues", &d.issues), ("🔀 Open PRs", &d.pull_requests)] {
+        if let Some(counts) = counts {
+            println!("\n{} ({})", style(title).bold(), counts.total);
+            for (label, count) in &counts.by_label {
+                println!("   {:<24} {}", label, count);
+            }
+        }
+    }
 
-    Ok(())
+    if let Some(workflows) = &d.workflows {
+        println!("\n{} {} passed, {} failed, {} running",
+            style("⚙️ Workflow Runs").bold(),
+            style(workflows.passed).green(),
+            style(workflows.failed).red(),
+            style(workflows.in_progress).yellow()
+        );
+        for run in workflows.runs.iter().filter(|r| r.status == "completed" && !matches!(r.conclusion.as_deref(), Some("success") | Some("skipped") | Some("neutral"))).take(5) {
+            println!("   {} {} ({})", style("✗").red(), run.name, run.head_branch);
+        }
+    }
+
+    for warning in &d.warnings {
+        println!("\n{} {}", style("⚠️").yellow(), warning);
+    }
+}
+
+fn render_markdown(d: &Dashboard) -> String {
+    let mut out = String::from("# Project Dashboard\n\n");
+    out.push_str(&format!("- **Protocol version:** {}\n", d.protocol_version.as_deref().unwrap_or("not installed")));
+    out.push_str(&format!("- **Agent role:** {}\n", d.agent_role.as_deref().unwrap_or("none equipped")));
+    out.push_str(&format!("- **Development type:** {}\n", d.development_type));
+    out.push_str(&format!("- **Atomic commits:** {} ({} of last {})\n", percent(d.atomicity.ratio), d.atomicity.atomic, d.atomicity.commits));
+
+    for (title, stats) in [("Languages", &d.languages), ("Directories", &d.directories)] {
+        out.push_str(&format!("\n## {}\n\n| Name | Files | Lines |\n|------|------:|------:|\n", title));
+        for s in stats {
+            out.push_str(&format!("| {} | {} | {} |\n", s.name, s.files, s.lines));
+        }
+    }
+
+    out.push_str("\n## Contributors\n\n| Name | Commits | Recent |\n|------|--------:|-------:|\n");
+    for c in &d.contributors {
+        out.push_str(&format!("| {} | {} | {} |\n", c.name, c.commits, c.recent_commits));
+    }
+
+    for (title, counts) in [("Open Issues", &d.issues), ("Open Pull Requests", &d.pull_requests)] {
+        if let Some(counts) = counts {
+            out.push_str(&format!("\n## {} ({})\n\n| Label | Count |\n|-------|------:|\n", title, counts.total));
+            for (label, count) in &counts.by_label {
+                out.push_str(&format!("| {} | {} |\n", label, count));
+            }
+        }
+    }
+
+    if let Some(w) = &d.workflows {
+        out.push_str(&format!("\n## Workflow Runs\n\n{} passed, {} failed, {} running\n", w.passed, w.failed, w.in_progress));
+    }
+
+    if !d.warnings.is_empty() {
+        out.push('\n');
+        for warning in &d.warnings {
+            out.push_str(&format!("> ⚠️ {}\n", warning));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::{MockFileSystemPort, MockGitHubPort, MockSystemPort};
+    use gc_core::ports::CoreError;
+
+    #[test]
+    fn test_atomicity_ratio_skips_bots_and_ignored_files() {
+        let log = "\x1eana\nsrc/lib.rs\nCargo.lock\n\n\x1edependabot[bot]\nsrc/a.rs\ndocs/a.md\n\x1ebob\nsrc/a.rs\nREADME.md\n";
+        let stats = atomicity(log, &atomicity_checker::Config::default());
+        assert_eq!(stats, AtomicityStats { commits: 2, atomic: 1, ratio: Some(0.5) });
+    }
+
+    #[test]
+    fn test_contributors_merge_recent_activity() {
+        let contributors = contributors("    12\tAna\n     3\tBob\n", "     2\tBob\n");
+        assert_eq!(contributors, vec![
+            Contributor { name: "Ana".into(), commits: 12, recent_commits: 0 },
+            Contributor { name: "Bob".into(), commits: 3, recent_commits: 2 },
+        ]);
+        assert_eq!(agent_role("# 🎭 ACTIVE AGENT PERSONA: Architect\n> GENERATED"), Some("Architect".into()));
+    }
+
+    #[tokio::test]
+    async fn test_collect_degrades_without_github() {
+        let mut fs = MockFileSystemPort::new();
+        let mut system = MockSystemPort::new();
+        let mut github = MockGitHubPort::new();
+
+        fs.expect_exists().returning(|path| Ok(path == VERSION_FILE));
+        fs.expect_read_file().returning(|path| match path {
+            VERSION_FILE => Ok("3.5.0\n".into()),
+            "src/main.rs" => Ok("fn main() {\n}\n".into()),
+            "README.md" => Ok("# App\n".into()),
+            _ => Err(CoreError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, "binary"))),
+        });
+        system.expect_run_command_output().returning(|_, args| match args.join(" ").as_str() {
+            "ls-files -z" => Ok("src/main.rs\0README.md\0logo.png\0".into()),
+            "shortlog -s -n HEAD" => Ok("     4\tAna\n".into()),
+            "remote get-url origin" => Ok("https://github.com/acme/app.git".into()),
+            _ => Ok(String::new()),
+        });
+        github.expect_list_issues().returning(|_, _, _, _| Err(CoreError::GitHub("Bad credentials".into())));
+        github.expect_list_prs().returning(|_, _, _| Ok(Vec::new()));
+        github.expect_list_workflow_runs().returning(|_, _, _| Ok(Vec::new()));
+
+        let dashboard = collect(30, &fs, &github, &system).await.unwrap();
+
+        assert_eq!(dashboard.protocol_version.as_deref(), Some("3.5.0"));
+        assert_eq!(dashboard.development_type, "Solo");
+        assert_eq!(dashboard.languages[0], CodeStats { name: "Rust".into(), files: 1, lines: 2 });
+        assert_eq!(dashboard.directories.len(), 2);
+        assert!(dashboard.issues.is_none());
+        assert_eq!(dashboard.pull_requests, Some(LabelCounts::default()));
+        assert_eq!(dashboard.warnings.len(), 1);
+        assert!(render_markdown(&dashboard).contains("| Rust | 1 | 2 |"));
+    }
+
+    #[tokio::test]
+    async fn test_collect_skips_github_for_other_hosts() {
+        let mut fs = MockFileSystemPort::new();
+        let mut system = MockSystemPort::new();
+        let github = MockGitHubPort::new();
+
+        fs.expect_exists().returning(|_| Ok(false));
+        system.expect_run_command_output().returning(|_, args| match args.join(" ").as_str() {
+            "remote get-url origin" => Ok("https://gitlab.com/acme/app.git\n".into()),
+            _ => Ok(String::new()),
+        });
+
+        let dashboard = collect(30, &fs, &github, &system).await.unwrap();
+
+        assert!(dashboard.issues.is_none() && dashboard.pull_requests.is_none() && dashboard.workflows.is_none());
+        assert_eq!(dashboard.warnings, vec!["`origin` (https://gitlab.com/acme/app.git) is not a GitHub repository; GitHub sections skipped"]);
+    }
 }
@@ -1,7 +1,13 @@
 use clap::{Args, Subcommand};
-use gc_core::ports::{GitHubPort, SystemPort, FileSystemPort};
-use serde::Deserialize;
+use gc_core::ports::{GitHubPort, SystemPort};
 use console::style;
+use issue_syncer::syncer::{
+    ChangeKind, ClosedPolicy, ConflictStrategy, DirectoryMapping, IssueSyncer, SyncReport, DEFAULT_CONCURRENCY,
+};
+use std::path::PathBuf;
+
+const ISSUES_DIR: &str = ".github/issues";
+const MAPPING_FILE: &str = ".issue-mapping.json";
 
 #[derive(Args, Debug)]
 pub struct IssueArgs {
@@ -42,27 +48,86 @@ pub enum IssueCommands {
         #[arg(short, long)]
         labels: Option<String>,
     },
-    /// Sync local issue files (.github/issues/*.md) to GitHub
+    /// Sync local issue files (.github/issues/**/*.md) with GitHub (push + pull)
+    ///
+    /// New files create issues, edited files update them, deleted files close them,
+    /// issues created or edited on GitHub are written as TYPE_slug.md files and
+    /// files whose issue was closed are archived. The file → issue mapping (with the
+    /// content hash and remote timestamp of the last sync) is kept in
+    /// .github/issues/.issue-mapping.json.
     Sync {
-        /// Dry run (show what would be synced)
+        /// Dry run (show a diff of what would change)
         #[arg(long)]
         dry_run: bool,
+
+        /// When a file and its issue both changed: local-wins, remote-wins, fail, conflict-file
+        #[arg(long, default_value = "conflict-file", value_parser = ["local-wins", "remote-wins", "fail", "conflict-file"])]
+        on_conflict: String,
+
+        /// What to do with files whose issue was closed: delete, archive (to _archive/YYYY-MM/), keep-and-mark
+        #[arg(long, default_value = "archive", value_parser = ["delete", "archive", "keep-and-mark"])]
+        on_close: String,
+
+        /// Only write new files for issues with this label (repeatable, all must match)
+        #[arg(short, long = "label")]
+        labels: Vec<String>,
+
+        /// What directory names of nested files become: none, labels, milestone (top-level directory)
+        #[arg(long, default_value = "none", value_parser = ["none", "labels", "milestone"])]
+        dirs_as: String,
+
+        /// Maximum number of issues created or updated at the same time
+        #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+        concurrency: usize,
     },
-}
+    /// Push local issue files to GitHub (create, update, close deleted)
+    Push {
+        /// Dry run (show a diff of what would change)
+        #[arg(long)]
+        dry_run: bool,
+
+        /// When a file and its issue both changed: local-wins, remote-wins, fail, conflict-file
+        #[arg(long, default_value = "conflict-file", value_parser = ["local-wins", "remote-wins", "fail", "conflict-file"])]
+        on_conflict: String,
+
+        /// What directory names of nested files become: none, labels, milestone (top-level directory)
+        #[arg(long, default_value = "none", value_parser = ["none", "labels", "milestone"])]
+        dirs_as: String,
+
+        /// Maximum number of issues created or updated at the same time
+        #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+        concurrency: usize,
+    },
+    /// Write files for new GitHub issues, apply GitHub edits and archive files of closed issues
+    Pull {
+        /// Dry run (show what would change)
+        #[arg(long)]
+        dry_run: bool,
+
+        /// When a file and its issue both changed: local-wins, remote-wins, fail, conflict-file
+        #[arg(long, default_value = "conflict-file", value_parser = ["local-wins", "remote-wins", "fail", "conflict-file"])]
+        on_conflict: String,
+
+        /// What to do with files whose issue was closed: delete, archive (to _archive/YYYY-MM/), keep-and-mark
+        #[arg(long, default_value = "archive", value_parser = ["delete", "archive", "keep-and-mark"])]
+        on_close: String,
+
+        /// Only write new files for issues with this label (repeatable, all must match)
+        #[arg(short, long = "label")]
+        labels: Vec<String>,
 
-#[derive(Debug, Deserialize)]
-struct IssueFrontmatter {
-    title: String,
-    labels: Option<Vec<String>>,
-    #[allow(dead_code)]
-    assignees: Option<Vec<String>>,
+        /// What directory names of nested files become: none, labels, milestone (top-level directory)
+        #[arg(long, default_value = "none", value_parser = ["none", "labels", "milestone"])]
+        dirs_as: String,
+    },
+    /// Show which local issue files are mapped to GitHub issues
+    Status,
 }
 
 pub async fn execute(
     args: IssueArgs,
     github: &impl GitHubPort,
     system: &impl SystemPort,
-    fs: &impl FileSystemPort,
 ) -> color_eyre::Result<()> {
     match args.command {
         IssueCommands::List { state, assignee, assigned_to_me, limit } => {
@@ -106,68 +171,61 @@ pub async fn execute(
                 .unwrap_or_else(Vec::new);
 
             println!("🚀 Creating issue: {}...", style(&title).cyan());
-            github.create_issue(&owner, &repo, &title, body.as_deref().unwrap_or(""), &labels_vec).await?;
-            println!("✅ Issue created successfully!");
+            let issue = github.create_issue(&owner, &repo, &title, body.as_deref().unwrap_or(""), &labels_vec).await?;
+            println!("✅ Issue #{} created successfully!", issue.number);
         }
-        IssueCommands::Sync { dry_run } => {
-            let output = system.run_command_output("git", &["remote", "get-url", "origin"].map(|s| s.to_string())).await?;
-            let (owner, repo) = parse_repo_from_url(&output)?;
-
-            let repo_root = system.run_command_output("git", &["rev-parse".into(), "--show-toplevel".into()]).await?.trim().to_string();
-            println!("{}", style(format!("🔍 Scanning for local issue files in {}/.github/issues/...", repo_root)).dim());
-
-            let files_output = if cfg!(windows) {
-                system.run_command_output("powershell", &[
-                    "-NoProfile".into(),
-                    "-ExecutionPolicy".into(), "Bypass".into(),
-                    "-Command".into(), format!("Get-ChildItem '{}/.github/issues/*.md' -Name", repo_root)
-                ]).await?
-            } else {
-                system.run_command_output("find", &[format!("{}/.github/issues", repo_root), "-name".into(), "*.md".into()]).await?
-            };
+        IssueCommands::Sync { dry_run, on_conflict, on_close, labels, dirs_as, concurrency } => {
+            let mut syncer = open_syncer(github, system, dry_run).await?
+                .with_concurrency(concurrency)
+                .with_conflict_strategy(on_conflict.parse::<ConflictStrategy>().map_err(to_eyre)?)
+                .with_closed_policy(on_close.parse::<ClosedPolicy>().map_err(to_eyre)?)
+                .with_label_filter(labels)
+                .with_directory_mapping(dirs_as.parse::<DirectoryMapping>().map_err(to_eyre)?);
+            let report = syncer.sync_all().await.map_err(to_eyre)?;
+            print_report(&report, dry_run);
+        }
+        IssueCommands::Push { dry_run, on_conflict, dirs_as, concurrency } => {
+            let mut syncer = open_syncer(github, system, dry_run).await?
+                .with_concurrency(concurrency)
+                .with_conflict_strategy(on_conflict.parse::<ConflictStrategy>().map_err(to_eyre)?)
+                .with_directory_mapping(dirs_as.parse::<DirectoryMapping>().map_err(to_eyre)?);
+            let report = syncer.push().await.map_err(to_eyre)?;
+            print_report(&report, dry_run);
+        }
+        IssueCommands::Pull { dry_run, on_conflict, on_close, labels, dirs_as } => {
+            let mut syncer = open_syncer(github, system, dry_run).await?
+                .with_conflict_strategy(on_conflict.parse::<ConflictStrategy>().map_err(to_eyre)?)
+                .with_closed_policy(on_close.parse::<ClosedPolicy>().map_err(to_eyre)?)
+                .with_label_filter(labels)
+                .with_directory_mapping(dirs_as.parse::<DirectoryMapping>().map_err(to_eyre)?);
+            let report = syncer.pull().await.map_err(to_eyre)?;
+            print_report(&report, dry_run);
+        }
+        IssueCommands::Status => {
+            let syncer = open_syncer(github, system, true).await?;
+            let status = syncer.status().map_err(to_eyre)?;
 
-            for file in files_output.lines() {
-                if file.starts_with('_') || file.starts_with('.') {
-                    continue;
-                }
-                let path = if cfg!(windows) {
-                    format!("{}/.github/issues/{}", repo_root, file)
-                } else {
-                    format!("{}/.github/issues/{}", repo_root, file)
-                };
-                let content = fs.read_file(&path).await?;
-
-                // Simple frontmatter parser
-                if content.starts_with("---") {
-                    let parts: Vec<&str> = content.split("---").collect();
-                    if parts.len() >= 3 {
-                        let yaml = parts[1];
-                        let body = parts[2..].join("---");
-                        let frontmatter: IssueFrontmatter = serde_yaml::from_str(yaml)?;
-
-                        if dry_run {
-                            println!("Test Sync: {} -> {}", style(file).yellow(), style(&frontmatter.title).cyan());
-                        } else {
-                            println!("Syncing: {} -> {}...", style(file).yellow(), style(&frontmatter.title).cyan());
-                            github.create_issue(
-                                &owner,
-                                &repo,
-                                &frontmatter.title,
-                                body.trim(),
-                                frontmatter.labels.as_ref().unwrap_or(&vec![]),
-                            ).await?;
-                            println!("✅ Synced!");
-                            // TODO: Move file to a 'synced' folder or add 'synced: true' to frontmatter to avoid duplicates
-                        }
-                    }
-                }
+            println!("{}", style("📊 Issue File Mapping").bold());
+            for (file, number) in &status.mapped {
+                println!("   {} {} → #{}", style("✓").green(), file, number);
             }
+            for file in &status.unmapped {
+                println!("   {} {} (not yet pushed)", style("+").green(), file);
+            }
+            for (file, number) in &status.missing {
+                println!("   {} {} → #{} (file deleted, will be closed)", style("-").red(), file, number);
+            }
+            print_invalid(&status.invalid);
+            for file in &status.conflicts {
+                println!("   {} {} (conflict: resolve it, then delete the .conflict.md file)", style("!").red().bold(), file);
+            }
+            println!("\n   {} mapped, {} new, {} deleted", status.mapped.len(), status.unmapped.len(), status.missing.len());
         }
     }
     Ok(())
 }
 
-fn parse_repo_from_url(url: &str) -> color_eyre::Result<(String, String)> {
+pub(crate) fn parse_repo_from_url(url: &str) -> color_eyre::Result<(String, String)> {
     let url = url.trim();
     // Supports:
     // https://github.com/owner/repo.git
@@ -188,3 +246,102 @@ fn parse_repo_from_url(url: &str) -> color_eyre::Result<(String, String)> {
 
     Ok((owner, repo))
 }
+
+/// Keeps the whole context chain of an issue-syncer error in the report.
+fn to_eyre(e: anyhow::Error) -> color_eyre::Report {
+    color_eyre::eyre::eyre!("{:#}", e)
+}
+
+/// Opens the shared sync engine on `<repo root>/.github/issues`.
+async fn open_syncer<'a, G: GitHubPort>(
+    github: &'a G,
+    system: &impl SystemPort,
+    dry_run: bool,
+) -> color_eyre::Result<IssueSyncer<'a, G>> {
+    let output = system.run_command_output("git", &["remote", "get-url", "origin"].map(|s| s.to_string())).await?;
+    let (owner, repo) = parse_repo_from_url(&output)?;
+
+    let repo_root = system.run_command_output("git", &["rev-parse".into(), "--show-toplevel".into()]).await?;
+    let issues_dir = PathBuf::from(repo_root.trim()).join(ISSUES_DIR);
+    println!("{}", style(format!("🔍 Syncing {} with {}/{}...", issues_dir.display(), owner, repo)).dim());
+
+    let mapping_file = issues_dir.join(MAPPING_FILE);
+    let syncer = IssueSyncer::new(github, owner, repo, issues_dir, mapping_file)
+        .map_err(to_eyre)?
+        .with_dry_run(dry_run);
+    Ok(syncer)
+}
+
+fn print_report(report: &SyncReport, dry_run: bool) {
+    print_invalid(&report.invalid);
+    for change in &report.changes {
+        let issue = change.issue.map(|n| format!("#{}", n)).unwrap_or_else(|| "new issue".to_string());
+        match change.kind {
+            ChangeKind::Create => println!("{} {} → {} {}", style("+").green().bold(), style(&change.file).yellow(), issue, style(&change.title).cyan()),
+            ChangeKind::Update => println!("{} {} → {}", style("~").yellow().bold(), style(&change.file).yellow(), issue),
+            ChangeKind::Close => println!("{} {} deleted → closing {}", style("-").red().bold(), style(&change.file).yellow(), issue),
+            ChangeKind::Delete => println!("{} {} closed on GitHub → removing {}", style("↓").blue().bold(), issue, style(&change.file).yellow()),
+            ChangeKind::Archive => println!("{} {} closed on GitHub → archiving {}", style("↓").blue().bold(), issue, style(&change.file).yellow()),
+            ChangeKind::Mark => println!("{} {} closed on GitHub → marking {}", style("↓").blue().bold(), issue, style(&change.file).yellow()),
+            ChangeKind::Materialize => println!("{} {} new on GitHub → writing {} {}", style("↓").blue().bold(), issue, style(&change.file).yellow(), style(&change.title).cyan()),
+            ChangeKind::Pull => println!("{} {} edited on GitHub → updating {}", style("↓").blue().bold(), issue, style(&change.file).yellow()),
+            ChangeKind::Conflict => println!("{} {} and {} both changed", style("!").red().bold(), style(&change.file).yellow(), issue),
+        }
+        if dry_run {
+            for line in &change.diff {
+                let styled = if line.starts_with('-') { style(line.as_str()).red() } else { style(line.as_str()).green() };
+                println!("    {}", styled);
+            }
+        }
+    }
+
+    println!(
+        "\n{} {}: {} created, {} updated, {} closed, {} removed, {} archived, {} pulled, {} unchanged{}{}",
+        if dry_run { "🔎" } else { "✅" },
+        if dry_run { "Dry run" } else { "Sync complete" },
+        report.created, report.updated, report.closed, report.deleted, report.archived, report.pulled, report.unchanged,
+        if report.conflicts > 0 { format!(", {} conflicts", report.conflicts) } else { String::new() },
+        if report.errors > 0 { format!(", {} errors", report.errors) } else { String::new() }
+    );
+}
+
+/// One line per file whose frontmatter failed to parse or validate
+fn print_invalid(invalid: &[(String, String)]) {
+    for (file, reason) in invalid {
+        println!("{} {}: {}", style("✗").red().bold(), style(file).yellow(), reason);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::{MockGitHubPort, MockSystemPort};
+    use mockall::predicate::*;
+
+    #[tokio::test]
+    async fn test_push_dry_run_uses_repo_issues_dir() {
+        let temp = assert_fs::TempDir::new().unwrap();
+        let issues_dir = temp.path().join(ISSUES_DIR);
+        std::fs::create_dir_all(&issues_dir).unwrap();
+        std::fs::write(issues_dir.join("FEAT_a.md"), "---\ntitle: A\n---\nBody").unwrap();
+
+        let root = temp.path().to_string_lossy().to_string();
+        let mut mock_system = MockSystemPort::new();
+        mock_system.expect_run_command_output()
+            .with(eq("git"), eq(vec!["remote".to_string(), "get-url".to_string(), "origin".to_string()]))
+            .returning(|_, _| Ok("git@github.com:owner/repo.git\n".to_string()));
+        mock_system.expect_run_command_output()
+            .with(eq("git"), eq(vec!["rev-parse".to_string(), "--show-toplevel".to_string()]))
+            .returning(move |_, _| Ok(format!("{}\n", root)));
+
+        let mut mock_github = MockGitHubPort::new();
+        mock_github.expect_list_issues()
+            .with(eq("owner"), eq("repo"), eq(Some("all".to_string())), eq(None))
+            .returning(|_, _, _, _| Ok(vec![]));
+        mock_github.expect_create_issue().never();
+
+        let args = IssueArgs { command: IssueCommands::Push { dry_run: true, on_conflict: "conflict-file".into(), dirs_as: "none".into(), concurrency: DEFAULT_CONCURRENCY } };
+        assert!(execute(args, &mock_github, &mock_system).await.is_ok());
+        assert!(!issues_dir.join(MAPPING_FILE).exists());
+    }
+}
@@ -8,14 +8,19 @@ pub mod task;
 pub mod finish;
 pub mod issue;
 pub mod pr;
+pub mod stack;
 pub mod git;
 pub mod info;
 pub mod check;
 pub mod next;
 pub mod update;
+pub mod self_update;
 pub mod workflow;
 pub mod dispatch;
 pub mod analyze;
+pub mod plan;
+pub mod state;
+pub mod atomize;
 
 pub use init::InitArgs;
 pub use context::ContextCmd;
@@ -32,9 +37,11 @@ pub use info::InfoArgs;
 pub use check::CheckArgs;
 pub use next::NextArgs;
 pub use update::UpdateArgs;
+pub use self_update::SelfUpdateArgs;
 pub use workflow::WorkflowArgs;
 pub use dispatch::DispatchArgs;
 pub use analyze::AnalyzeArgs;
+pub use plan::PlanArgs;
+pub use state::StateArgs;
+pub use atomize::AtomizeArgs;
 
-#[cfg(test)]
-pub mod mocks;
@@ -1,8 +1,12 @@
 use clap::Args;
 use color_eyre::Result;
 use gc_core::ports::{SystemPort, GitHubPort, FileSystemPort};
+use gc_core::agent_state::AgentState;
+use gc_core::features::{FeaturesManifest, FEATURES_PATH};
+use gc_core::graph::IssueGraph;
+use gc_core::priority::{self, Candidate, ScoreBreakdown, ScoringContext, ScoringWeights, CLAIM_LABEL};
 use console::style;
-use serde::Serialize;
+use serde::{Serialize, Deserialize};
 use crate::commands::task::{TaskArgs, self};
 
 #[derive(Args, Debug)]
@@ -15,6 +19,26 @@ pub struct NextArgs {
     #[arg(long)]
     pub agent: Option<String>,
 
+    /// Show the score breakdown behind the selection
+    #[arg(long)]
+    pub explain: bool,
+
+    /// Preview the N best candidates without starting work
+    #[arg(long, value_name = "N")]
+    pub top: Option<usize>,
+
+    /// Hours after which an idle `in-progress` claim returns to the pool
+    #[arg(long, default_value = "24")]
+    pub stale_hours: i64,
+
+    /// Release a claimed issue back to the pool instead of selecting one
+    #[arg(long, value_name = "ISSUE")]
+    pub release: Option<u64>,
+
+    /// Maximum number of open issues to consider
+    #[arg(long, default_value = "100")]
+    pub limit: usize,
+
     /// Output in JSON format
     #[arg(long)]
     pub json: bool,
@@ -27,6 +51,64 @@ struct NextOutput {
     agent_assigned: String,
     branch_created: String,
     jules_triggered: bool,
+    claimed: bool,
+    score: ScoreBreakdown,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhIssue {
+    number: u64,
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    labels: Vec<GhLabel>,
+    #[serde(default)]
+    assignees: Vec<GhUser>,
+    #[serde(default)]
+    milestone: Option<GhMilestone>,
+    #[serde(default, rename = "createdAt")]
+    created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, rename = "updatedAt")]
+    updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhUser {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhMilestone {
+    title: String,
+    #[serde(default, rename = "dueOn")]
+    due_on: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl GhIssue {
+    fn into_candidate(self, features: Option<&FeaturesManifest>) -> Candidate {
+        let now = chrono::Utc::now();
+        let labels: Vec<String> = self.labels.into_iter().map(|l| l.name).collect();
+        let phase = features.and_then(|f| f.phase_for(&self.title, &labels));
+
+        Candidate {
+            number: self.number,
+            body: self.body.unwrap_or_default(),
+            assignees: self.assignees.into_iter().map(|u| u.login).collect(),
+            milestone_due_in_days: self.milestone.as_ref().and_then(|m| m.due_on).map(|d| (d - now).num_days()),
+            milestone: self.milestone.map(|m| m.title),
+            age_days: self.created_at.map(|c| (now - c).num_days()).unwrap_or(0),
+            idle_hours: self.updated_at.map(|u| (now - u).num_hours()).unwrap_or(0),
+            title: self.title,
+            labels,
+            phase,
+        }
+    }
 }
 
 pub async fn execute(
@@ -35,26 +117,28 @@ pub async fn execute(
     system: &impl SystemPort,
     github: &impl GitHubPort,
 ) -> Result<()> {
+    if let Some(number) = args.release {
+        release_issue(number, system).await?;
+        if !args.json {
+            println!("{} Released #{} back to the pool.", style("🔓").green(), number);
+        }
+        return Ok(());
+    }
+
     if !args.json {
         println!("{} Scanning for next priority task...", style("🔍").cyan());
     }
 
-    // 1. Fetch Issues via GitHub Adapter
-    // Note: In MVP, `GitHubPort` might abstract `gh` cli calls or `octocrab`.
-    // Assuming we don't have full `list_issues` in `GitHubPort` yet tailored for this,
-    // we might fallback to `system.run_command_output("gh", ...)` if the port is limited.
-    // For this implementation, let's use `system` to call `gh CLI` as it's the most reliable way
-    // to get "my assigned issues" or "repo issues" without complex auth setup in Rust code itself yet.
-
+    // 1. Fetch open issues via `gh`, which reuses the user's existing CLI auth
     let gh_args = vec![
         "issue".to_string(), "list".to_string(),
-        "--json".to_string(), "number,title,labels,body".to_string(),
+        "--json".to_string(), "number,title,labels,body,assignees,milestone,createdAt,updatedAt".to_string(),
         "--state".to_string(), "open".to_string(),
-        "--limit".to_string(), "10".to_string(),
+        "--limit".to_string(), args.limit.to_string(),
     ];
 
     let output = system.run_command_output("gh", &gh_args).await?;
-    let issues: Vec<serde_json::Value> = serde_json::from_str(&output)?;
+    let issues: Vec<GhIssue> = serde_json::from_str(&output)?;
 
     if issues.is_empty() {
         if !args.json {
@@ -63,25 +147,90 @@ pub async fn execute(
         return Ok(());
     }
 
-    // 2. Prioritize
-    // Bug > Urgent > AI-Plan > Feature
-    let selected = issues.iter().min_by_key(|i| {
-        let labels = i["labels"].as_array().unwrap();
-        let is_bug = labels.iter().any(|l| l["name"] == "bug");
-        let is_urgent = labels.iter().any(|l| l["name"] == "urgent" || l["name"] == "high priority");
+    // 2. Score
+    let features = load_features(fs).await;
+    let current_user = system
+        .run_command_output("gh", &["api".to_string(), "user".to_string(), "--jq".to_string(), ".login".to_string()])
+        .await
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+
+    let candidates: Vec<Candidate> = issues.into_iter().map(|i| i.into_candidate(features.as_ref())).collect();
+    let graph = IssueGraph::build(candidates.iter().map(|c| (c.number, c.title.as_str(), c.body.as_str(), true)));
+    if let Some(cycle) = graph.find_cycle() {
+        if !args.json {
+            let path: Vec<String> = cycle.iter().map(|n| format!("#{}", n)).collect();
+            println!("{} Dependency cycle detected: {} (run `gc plan`)", style("!").yellow(), path.join(" -> "));
+        }
+    }
+
+    let ctx = ScoringContext {
+        current_user,
+        current_phase: features.as_ref().and_then(|f| f.current_phase()),
+        graph,
+        claim_timeout_hours: Some(args.stale_hours),
+    };
+    let ranked = priority::rank(&candidates, &ctx, &ScoringWeights::default());
+
+    if let Some(n) = args.top {
+        let top: Vec<&ScoreBreakdown> = ranked.iter().take(n).collect();
+        if args.json {
+            println!("{}", serde_json::to_string(&top)?);
+        } else {
+            println!("{} Top {} candidates:", style("📋").cyan(), top.len());
+            for (i, s) in top.iter().enumerate() {
+                print_candidate(i + 1, s, args.explain);
+            }
+        }
+        return Ok(());
+    }
+
+    if ranked.iter().all(|s| s.is_blocked()) {
+        if !args.json {
+            println!("{} All {} open issues are blocked.", style("⛔").red(), ranked.len());
+            println!("   Run `gc next --top 5 --explain` to see why.");
+        }
+        return Ok(());
+    }
 
-        if is_bug { 0 }
-        else if is_urgent { 1 }
-        else { 2 }
-    }).unwrap();
+    // Claim before doing any local work; if a concurrent run wins the issue, move on to the next one
+    let mut claimed = None;
+    for score in ranked.iter().filter(|s| !s.is_blocked()) {
+        let Some(candidate) = candidates.iter().find(|c| c.number == score.number) else {
+            color_eyre::eyre::bail!("Selected issue #{} missing from candidates", score.number);
+        };
+        if !args.json {
+            println!("{} Selected: #{} - {} (score {})", style("🎯").yellow(), candidate.number, candidate.title, score.total);
+            if args.explain {
+                print_breakdown(score);
+            }
+        }
 
-    let number = selected["number"].as_u64().unwrap();
-    let title = selected["title"].as_str().unwrap().to_string();
-    let body = selected["body"].as_str().unwrap_or("");
-    let labels_array = selected["labels"].as_array().unwrap();
+        match claim_issue(candidate, ctx.current_user.as_deref(), args.stale_hours, system).await? {
+            Claim::Won => {
+                claimed = Some((score, candidate));
+                break;
+            }
+            Claim::Lost(winner) => {
+                if !args.json {
+                    println!("   {} #{} was claimed by {} first; trying the next issue", style("↷").yellow(), candidate.number, winner);
+                }
+            }
+        }
+    }
+    let Some((selected, candidate)) = claimed else {
+        if !args.json {
+            println!("{} Every unblocked issue was claimed by another run.", style("⛔").red());
+        }
+        return Ok(());
+    };
 
+    let number = candidate.number;
+    let title = candidate.title.clone();
+    let body = candidate.body.as_str();
     if !args.json {
-        println!("{} Selected: #{} - {}", style("🎯").yellow(), number, title);
+        println!("{} Claimed #{} (assigned to you, labelled '{}')", style("🔒").blue(), number, CLAIM_LABEL);
     }
 
     // 3. Init Workspace (Reuse gc task)
@@ -102,7 +251,7 @@ pub async fn execute(
 
     // 4. Agent Dispatch Strategy
     let is_complex = body.len() > 500 || title.to_lowercase().contains("implement");
-    let has_jules_label = labels_array.iter().any(|l| l["name"] == "jules");
+    let has_jules_label = candidate.labels.iter().any(|l| l == "jules");
 
     let agent = if let Some(a) = &args.agent {
         a.clone()
@@ -149,6 +298,8 @@ pub async fn execute(
             agent_assigned: agent,
             branch_created: "unknown_in_json_mode".to_string(), // Limitation of composition without capturing stdout
             jules_triggered,
+            claimed: true,
+            score: selected.clone(),
         };
         // Print nothing here? Or double JSON?
         // `task::execute` already printed JSON if args.json is true.
@@ -161,3 +312,296 @@ pub async fn execute(
 
     Ok(())
 }
+
+fn claim_comment(number: u64, step: &str, next_action: &str) -> String {
+    let mut state = AgentState {
+        intent: Some(format!("Work on #{}", number)),
+        step: Some(step.to_string()),
+        progress: Some(0),
+        next_action: Some(next_action.to_string()),
+        ..Default::default()
+    };
+    state.set_metric("generated_at", chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string());
+    state.to_xml()
+}
+
+/// Outcome of [`claim_issue`].
+#[derive(Debug, PartialEq)]
+enum Claim {
+    Won,
+    /// Another claimant got there first (their login)
+    Lost(String),
+}
+
+#[derive(Debug, Deserialize)]
+struct GhClaimView {
+    #[serde(default)]
+    assignees: Vec<GhUser>,
+    #[serde(default)]
+    comments: Vec<GhComment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhComment {
+    #[serde(default)]
+    author: Option<GhUser>,
+    #[serde(default)]
+    body: String,
+    #[serde(rename = "createdAt")]
+    created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    url: String,
+}
+
+impl GhComment {
+    fn login(&self) -> &str {
+        self.author.as_ref().map(|a| a.login.as_str()).unwrap_or("")
+    }
+
+    fn step(&self) -> Option<String> {
+        AgentState::parse(&self.body).ok().and_then(|s| s.step)
+    }
+}
+
+/// Marks the issue as taken: assigns the current user, adds the claim label and records an agent-state comment.
+///
+/// Two runs can both get this far, so the claim is then read back: the earliest claim comment
+/// since the last release (and younger than `stale_hours`) keeps the issue, later claimants back off.
+async fn claim_issue(candidate: &Candidate, me: Option<&str>, stale_hours: i64, system: &impl SystemPort) -> Result<Claim> {
+    let number = candidate.number;
+    let is_me = |login: &str| me.is_some_and(|m| m.eq_ignore_ascii_case(login));
+
+    // Taking over a stale claim: the previous claimant no longer owns the issue
+    let previous: Vec<&str> = if candidate.labels.iter().any(|l| l == CLAIM_LABEL) {
+        candidate.assignees.iter().map(String::as_str).filter(|a| !is_me(a)).collect()
+    } else {
+        Vec::new()
+    };
+    let mut edit_args = vec![
+        "issue".to_string(), "edit".to_string(), number.to_string(),
+        "--add-assignee".to_string(), "@me".to_string(),
+        "--add-label".to_string(), CLAIM_LABEL.to_string(),
+    ];
+    if !previous.is_empty() {
+        edit_args.push("--remove-assignee".to_string());
+        edit_args.push(previous.join(","));
+    }
+    system.run_command_output("gh", &edit_args).await?;
+    // gh prints the URL of the new comment, which identifies our claim below
+    let my_url = system.run_command_output("gh", &[
+        "issue".to_string(), "comment".to_string(), number.to_string(),
+        "--body".to_string(), claim_comment(number, "claimed", "implement"),
+    ]).await?.trim().to_string();
+
+    let view = system.run_command_output("gh", &[
+        "issue".to_string(), "view".to_string(), number.to_string(),
+        "--json".to_string(), "assignees,comments".to_string(),
+    ]).await?;
+    let view: GhClaimView = serde_json::from_str(&view)?;
+
+    let since_release = view.comments.iter().rposition(|c| c.step().as_deref() == Some("released")).map_or(0, |i| i + 1);
+    let cutoff = chrono::Utc::now() - chrono::Duration::hours(stale_hours);
+    let claims: Vec<&GhComment> = view.comments[since_release..]
+        .iter()
+        .filter(|c| c.created_at >= cutoff && c.step().as_deref() == Some("claimed"))
+        .collect();
+    let mine = |c: &GhComment| if my_url.is_empty() { is_me(c.login()) } else { c.url == my_url };
+
+    let winner = match claims.first() {
+        Some(first) if !mine(first) => Some(first.login().to_string()),
+        // Assigned meanwhile by someone who did not go through `gc next`
+        _ => view.assignees.iter()
+            .map(|a| a.login.as_str())
+            .find(|a| !is_me(a) && !previous.contains(a) && !claims.iter().any(|c| c.login() == *a))
+            .map(str::to_string),
+    };
+    let Some(winner) = winner else {
+        return Ok(Claim::Won);
+    };
+
+    // Back off without undoing the winner's claim: keep the label, and the assignee if it is also us
+    if !is_me(&winner) {
+        system.run_command_output("gh", &[
+            "issue".to_string(), "edit".to_string(), number.to_string(),
+            "--remove-assignee".to_string(), "@me".to_string(),
+        ]).await?;
+    }
+    if let Some((_, id)) = my_url.rsplit_once("#issuecomment-") {
+        system.run_command_output("gh", &[
+            "api".to_string(), "-X".to_string(), "DELETE".to_string(),
+            format!("repos/{{owner}}/{{repo}}/issues/comments/{}", id),
+        ]).await?;
+    }
+    Ok(Claim::Lost(winner))
+}
+
+/// Undoes [`claim_issue`] so the issue can be picked up again.
+async fn release_issue(number: u64, system: &impl SystemPort) -> Result<()> {
+    system.run_command_output("gh", &[
+        "issue".to_string(), "edit".to_string(), number.to_string(),
+        "--remove-assignee".to_string(), "@me".to_string(),
+        "--remove-label".to_string(), CLAIM_LABEL.to_string(),
+    ]).await?;
+    system.run_command_output("gh", &[
+        "issue".to_string(), "comment".to_string(), number.to_string(),
+        "--body".to_string(), claim_comment(number, "released", "available for pickup"),
+    ]).await?;
+    Ok(())
+}
+
+async fn load_features(fs: &impl FileSystemPort) -> Option<FeaturesManifest> {
+    if !fs.exists(FEATURES_PATH).await.unwrap_or(false) {
+        return None;
+    }
+    let content = fs.read_file(FEATURES_PATH).await.ok()?;
+    match serde_json::from_str(&content) {
+        Ok(manifest) => Some(manifest),
+        Err(e) => {
+            eprintln!("   {} Ignoring invalid {}: {}", style("!").yellow(), FEATURES_PATH, e);
+            None
+        }
+    }
+}
+
+fn print_candidate(rank: usize, score: &ScoreBreakdown, explain: bool) {
+    let status = if score.is_blocked() { style("blocked".to_string()).red() } else { style(format!("{:>4}", score.total)).green() };
+    println!("   {}. #{} {} [{}]", rank, style(score.number).bold(), score.title, status);
+    if explain {
+        print_breakdown(score);
+    }
+}
+
+fn print_breakdown(score: &ScoreBreakdown) {
+    for f in &score.factors {
+        println!("      {:>+5}  {}", f.points, style(&f.factor).dim());
+    }
+    for reason in &score.blocked_by {
+        println!("      {}  {}", style("block").red(), reason);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::{MockFileSystemPort, MockSystemPort, MockGitHubPort};
+    use mockall::predicate::*;
+
+    fn preview_args() -> NextArgs {
+        NextArgs { auto: true, agent: None, explain: false, top: Some(3), stale_hours: 24, release: None, limit: 100, json: true }
+    }
+
+    #[tokio::test]
+    async fn test_top_preview_does_not_claim() {
+        let mut mock_fs = MockFileSystemPort::new();
+        let mut mock_system = MockSystemPort::new();
+        let mock_github = MockGitHubPort::new();
+
+        mock_fs.expect_exists().returning(|_| Ok(false));
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), function(|a: &[String]| a[0] == "issue" && a[1] == "list"))
+            .returning(|_, _| Ok(r#"[
+                {"number": 1, "title": "Docs", "body": null, "labels": [], "assignees": [], "milestone": null, "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2024-01-01T00:00:00Z"},
+                {"number": 2, "title": "Crash", "body": "", "labels": [{"name": "bug"}, {"name": "in-progress"}], "assignees": [{"login": "bob"}], "milestone": null, "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2999-01-01T00:00:00Z"}
+            ]"#.to_string()));
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), function(|a: &[String]| a[0] == "api"))
+            .returning(|_, _| Ok("alice\n".to_string()));
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), function(|a: &[String]| a[0] == "issue" && a[1] != "list"))
+            .never();
+
+        let res = execute(preview_args(), &mock_fs, &mock_system, &mock_github).await;
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_release_removes_claim() {
+        let mock_fs = MockFileSystemPort::new();
+        let mut mock_system = MockSystemPort::new();
+        let mock_github = MockGitHubPort::new();
+
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), eq(vec![
+                "issue".to_string(), "edit".to_string(), "7".to_string(),
+                "--remove-assignee".to_string(), "@me".to_string(),
+                "--remove-label".to_string(), CLAIM_LABEL.to_string(),
+            ]))
+            .times(1)
+            .returning(|_, _| Ok(String::new()));
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), function(|a: &[String]| a[1] == "comment" && a[4].contains("<step>released</step>")))
+            .times(1)
+            .returning(|_, _| Ok(String::new()));
+
+        let args = NextArgs { release: Some(7), top: None, ..preview_args() };
+        let res = execute(args, &mock_fs, &mock_system, &mock_github).await;
+        assert!(res.is_ok());
+    }
+
+    fn claim_view(comments: &[(&str, &str, &str, i64)]) -> String {
+        let comments: Vec<serde_json::Value> = comments.iter().map(|(login, step, id, hours_ago)| serde_json::json!({
+            "author": { "login": login },
+            "body": claim_comment(9, step, "implement"),
+            "createdAt": (chrono::Utc::now() - chrono::Duration::hours(*hours_ago)).to_rfc3339(),
+            "url": format!("https://github.com/o/r/issues/9#issuecomment-{}", id),
+        })).collect();
+        serde_json::json!({ "assignees": [{ "login": "alice" }, { "login": "bob" }], "comments": comments }).to_string()
+    }
+
+    #[tokio::test]
+    async fn test_claim_backs_off_when_another_run_claimed_first() {
+        let mut mock_system = MockSystemPort::new();
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), function(|a: &[String]| a[1] == "edit" && a.contains(&"--add-assignee".to_string())))
+            .times(1)
+            .returning(|_, _| Ok(String::new()));
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), function(|a: &[String]| a[1] == "comment"))
+            .returning(|_, _| Ok("https://github.com/o/r/issues/9#issuecomment-200\n".to_string()));
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), function(|a: &[String]| a[1] == "view"))
+            .returning(|_, _| Ok(claim_view(&[("bob", "claimed", "199", 0), ("alice", "claimed", "200", 0)])));
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), eq(vec!["issue".to_string(), "edit".to_string(), "9".to_string(), "--remove-assignee".to_string(), "@me".to_string()]))
+            .times(1)
+            .returning(|_, _| Ok(String::new()));
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), eq(vec!["api".to_string(), "-X".to_string(), "DELETE".to_string(), "repos/{owner}/{repo}/issues/comments/200".to_string()]))
+            .times(1)
+            .returning(|_, _| Ok(String::new()));
+
+        let candidate = Candidate { number: 9, ..Default::default() };
+        let claim = claim_issue(&candidate, Some("alice"), 24, &mock_system).await.unwrap();
+        assert_eq!(claim, Claim::Lost("bob".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_stale_reclaim_removes_previous_assignee() {
+        let mut mock_system = MockSystemPort::new();
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), eq(vec![
+                "issue".to_string(), "edit".to_string(), "9".to_string(),
+                "--add-assignee".to_string(), "@me".to_string(),
+                "--add-label".to_string(), CLAIM_LABEL.to_string(),
+                "--remove-assignee".to_string(), "bob".to_string(),
+            ]))
+            .times(1)
+            .returning(|_, _| Ok(String::new()));
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), function(|a: &[String]| a[1] == "comment"))
+            .returning(|_, _| Ok("https://github.com/o/r/issues/9#issuecomment-200\n".to_string()));
+        // bob's abandoned claim is older than the timeout and no longer counts
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), function(|a: &[String]| a[1] == "view"))
+            .returning(|_, _| Ok(claim_view(&[("bob", "claimed", "100", 48), ("alice", "claimed", "200", 0)])));
+
+        let candidate = Candidate {
+            number: 9,
+            labels: vec![CLAIM_LABEL.to_string()],
+            assignees: vec!["bob".to_string()],
+            ..Default::default()
+        };
+        let claim = claim_issue(&candidate, Some("alice"), 24, &mock_system).await.unwrap();
+        assert_eq!(claim, Claim::Won);
+    }
+}
@@ -0,0 +1,155 @@
+use clap::Args;
+use color_eyre::Result;
+use gc_core::graph::{EdgeKind, IssueGraph};
+use gc_core::ports::{FileSystemPort, SystemPort};
+use console::style;
+use serde::{Deserialize, Serialize};
+
+#[derive(Args, Debug)]
+pub struct PlanArgs {
+    /// Output format
+    #[arg(short, long, default_value = "text", value_parser = ["text", "mermaid", "dot", "json"])]
+    pub format: String,
+
+    /// Write the output to a file instead of stdout
+    #[arg(short, long)]
+    pub output: Option<String>,
+
+    /// Issues to include (open, all). Closed issues satisfy dependencies either way.
+    #[arg(short, long, default_value = "all")]
+    pub state: String,
+
+    /// Maximum number of issues to fetch
+    #[arg(long, default_value = "200")]
+    pub limit: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhIssue {
+    number: u64,
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+    state: String,
+}
+
+#[derive(Serialize)]
+struct PlanOutput<'a> {
+    order: Vec<u64>,
+    layers: Vec<Vec<u64>>,
+    graph: &'a IssueGraph,
+}
+
+pub async fn execute(
+    args: PlanArgs,
+    fs: &impl FileSystemPort,
+    system: &impl SystemPort,
+) -> Result<()> {
+    let gh_args = vec![
+        "issue".to_string(), "list".to_string(),
+        "--json".to_string(), "number,title,body,state".to_string(),
+        "--state".to_string(), args.state.clone(),
+        "--limit".to_string(), args.limit.to_string(),
+    ];
+    let output = system.run_command_output("gh", &gh_args).await?;
+    let issues: Vec<GhIssue> = serde_json::from_str(&output)?;
+
+    let graph = IssueGraph::build(issues.iter().map(|i| {
+        (i.number, i.title.as_str(), i.body.as_deref().unwrap_or(""), i.state.eq_ignore_ascii_case("open"))
+    }));
+
+    let rendered = match args.format.as_str() {
+        "mermaid" => graph.to_mermaid(),
+        "dot" => graph.to_dot(),
+        "json" => {
+            let layers = graph.layers()?;
+            let out = PlanOutput { order: layers.iter().flatten().copied().collect(), layers, graph: &graph };
+            serde_json::to_string_pretty(&out)?
+        }
+        _ => render_text(&graph)?,
+    };
+
+    match args.output {
+        Some(path) => {
+            fs.write_file(&path, &rendered).await?;
+            println!("{} Plan written to {}", style("✓").green(), path);
+        }
+        None => println!("{}", rendered),
+    }
+
+    Ok(())
+}
+
+fn render_text(graph: &IssueGraph) -> Result<String> {
+    let layers = graph.layers()?;
+    let titles: std::collections::HashMap<u64, &str> = graph.nodes().map(|n| (n.number, n.title.as_str())).collect();
+
+    let mut out = format!("{}\n", style("🗺️  Work Order").bold());
+    if layers.is_empty() {
+        out.push_str("   No open issues.\n");
+        return Ok(out);
+    }
+
+    let mut step = 1;
+    for (i, layer) in layers.iter().enumerate() {
+        out.push_str(&format!("\n{}\n", style(format!("Stage {} ({} parallelizable)", i + 1, layer.len())).cyan()));
+        for number in layer {
+            out.push_str(&format!("   {:>3}. #{} {}\n", step, style(number).green().bold(), titles.get(number).unwrap_or(&"")));
+            for edge in graph.open_blockers(*number) {
+                let relation = match edge.kind {
+                    EdgeKind::DependsOn => "after",
+                    EdgeKind::Subtask => "sub-issue",
+                };
+                out.push_str(&format!("         {} #{}\n", style(relation).dim(), edge.to));
+            }
+            step += 1;
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::{MockFileSystemPort, MockSystemPort};
+    use mockall::predicate::*;
+
+    const ISSUES: &str = r#"[
+        {"number": 1, "title": "Epic", "body": "- [ ] #2", "state": "OPEN"},
+        {"number": 2, "title": "API", "body": "depends on #3", "state": "OPEN"},
+        {"number": 3, "title": "Schema", "body": null, "state": "CLOSED"}
+    ]"#;
+
+    #[tokio::test]
+    async fn test_plan_writes_mermaid_export() {
+        let mut mock_fs = MockFileSystemPort::new();
+        let mut mock_system = MockSystemPort::new();
+
+        mock_system.expect_run_command_output()
+            .with(eq("gh"), always())
+            .returning(|_, _| Ok(ISSUES.to_string()));
+        mock_fs.expect_write_file()
+            .with(eq("plan.mmd"), function(|c: &str| c.starts_with("graph TD") && c.contains("I2 -.-> I1")))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let args = PlanArgs { format: "mermaid".into(), output: Some("plan.mmd".into()), state: "all".into(), limit: 200 };
+        assert!(execute(args, &mock_fs, &mock_system).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_plan_fails_on_cycle() {
+        let mock_fs = MockFileSystemPort::new();
+        let mut mock_system = MockSystemPort::new();
+
+        mock_system.expect_run_command_output()
+            .returning(|_, _| Ok(r#"[
+                {"number": 1, "title": "A", "body": "blocked by #2", "state": "OPEN"},
+                {"number": 2, "title": "B", "body": "blocked by #1", "state": "OPEN"}
+            ]"#.to_string()));
+
+        let args = PlanArgs { format: "text".into(), output: None, state: "all".into(), limit: 200 };
+        let err = execute(args, &mock_fs, &mock_system).await.unwrap_err();
+        assert!(err.to_string().contains("#1 -> #2 -> #1"));
+    }
+}
@@ -1,6 +1,11 @@
 use clap::{Args, Subcommand};
 use gc_core::ports::{GitHubPort, SystemPort};
+use gc_core::{CheckRun, MergeMethod, NewPullRequest, PullRequest, Review};
 use console::style;
+use serde::Serialize;
+use crate::commands::finish::{current_branch, issue_from_branch};
+use crate::commands::issue::parse_repo_from_url;
+use crate::commands::stack::{self, StackArgs};
 
 #[derive(Args, Debug)]
 pub struct PrArgs {
@@ -18,30 +23,169 @@ pub enum PrCommands {
 
         #[arg(short, long, default_value = "10")]
         limit: usize,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Push the current branch and open a pull request for it
+    Create {
+        /// Title (defaults to the last commit subject)
+        #[arg(short, long)]
+        title: Option<String>,
+
+        /// Body (defaults to `Closes #N` when the branch names an issue)
+        #[arg(short, long)]
+        body: Option<String>,
+
+        /// Branch to merge into (defaults to the remote's default branch)
+        #[arg(long)]
+        base: Option<String>,
+
+        /// Branch with the changes (defaults to the current branch)
+        #[arg(long)]
+        head: Option<String>,
+
+        /// Open as a draft
+        #[arg(short, long)]
+        draft: bool,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show a pull request with its checks and reviews
+    View {
+        /// PR number (defaults to the PR of the current branch)
+        number: Option<u64>,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check out the branch of a pull request locally
+    Checkout {
+        number: u64,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Mark a draft pull request as ready for review
+    Ready {
+        /// PR number (defaults to the PR of the current branch)
+        number: Option<u64>,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Merge a pull request, marking it ready first if it is a draft
+    Merge {
+        /// PR number (defaults to the PR of the current branch)
+        number: Option<u64>,
+
+        #[arg(short, long, default_value = "squash", value_parser = ["squash", "rebase", "merge"])]
+        method: String,
+
+        /// Delete the head branch, remotely and locally, once merged
+        #[arg(short, long)]
+        delete_branch: bool,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show, submit or sync a stack of dependent branches
+    Stack(StackArgs),
+    /// Comment on a pull request
+    Comment {
+        /// PR number (defaults to the PR of the current branch)
+        number: Option<u64>,
+
+        #[arg(short, long)]
+        body: String,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
     },
 }
 
+#[derive(Serialize, Debug, Default, PartialEq)]
+struct ChecksSummary {
+    passed: usize,
+    failed: usize,
+    pending: usize,
+}
+
+#[derive(Serialize)]
+struct ViewOutput {
+    #[serde(flatten)]
+    pr: PullRequest,
+    checks_summary: ChecksSummary,
+    checks: Vec<CheckRun>,
+    /// Latest review of each reviewer
+    reviews: Vec<Review>,
+}
+
+#[derive(Serialize)]
+struct CheckoutOutput {
+    number: u64,
+    branch: String,
+}
+
+#[derive(Serialize)]
+struct ReadyOutput {
+    number: u64,
+    /// False when the PR was already ready
+    changed: bool,
+}
+
+#[derive(Serialize)]
+struct MergeOutput {
+    number: u64,
+    method: MergeMethod,
+    sha: String,
+    marked_ready: bool,
+    branch_deleted: bool,
+}
+
+#[derive(Serialize)]
+struct CommentOutput {
+    number: u64,
+    commented: bool,
+}
+
 pub async fn execute(
     args: PrArgs,
     github: &impl GitHubPort,
     system: &impl SystemPort,
 ) -> color_eyre::Result<()> {
-    match args.command {
-        PrCommands::List { state, limit } => {
-            // Detect repo
-            let output = system.run_command_output("git", &["remote", "get-url", "origin"].map(|s| s.to_string())).await?;
-            let (owner, repo) = parse_repo_from_url(&output)?;
+    // Detect repo
+    let output = system.run_command_output("git", &["remote", "get-url", "origin"].map(|s| s.to_string())).await?;
+    let (owner, repo) = parse_repo_from_url(&output)?;
 
-            println!("{}", style(format!("Fetching PRs for {}/{}...", owner, repo)).dim());
+    match args.command {
+        PrCommands::List { state, limit, json } => {
+            if !json {
+                println!("{}", style(format!("Fetching PRs for {}/{}...", owner, repo)).dim());
+            }
 
             let prs = github.list_prs(&owner, &repo, Some(state.clone())).await?;
+            let prs: Vec<PullRequest> = prs.into_iter().take(limit).collect();
+
+            if json {
+                println!("{}", serde_json::to_string(&prs).unwrap());
+                return Ok(());
+            }
 
             if prs.is_empty() {
                 println!("No PRs found.");
                 return Ok(());
             }
 
-            for pr in prs.iter().take(limit) {
+            for pr in &prs {
                 println!("#{} {} [{}] ({})",
                     style(pr.number).green().bold(),
                     pr.title,
@@ -50,24 +194,496 @@ pub async fn execute(
                 );
             }
         }
+        PrCommands::Create { title, body, base, head, draft, json } => {
+            let head = match head {
+                Some(head) => head,
+                None => current_branch(system).await?,
+            };
+            if head.is_empty() {
+                color_eyre::eyre::bail!("Detached HEAD or no branch; cannot open a PR");
+            }
+            let base = match base {
+                Some(base) => base,
+                None => default_branch(system).await,
+            };
+            if head == base {
+                color_eyre::eyre::bail!("Head and base are both '{}'; switch to a feature branch first", head);
+            }
+
+            let title = match title {
+                Some(title) => title,
+                None => git(system, &["log", "-1", "--format=%s"]).await?,
+            };
+            let body = body.unwrap_or_else(|| {
+                issue_from_branch(&head).map(|n| format!("Closes #{}", n)).unwrap_or_default()
+            });
+
+            if !json {
+                println!("{} Pushing {}...", style("⬆️").blue(), head);
+            }
+            git(system, &["push", "--set-upstream", "origin", &head]).await?;
+
+            let new_pr = NewPullRequest { title, body, head, base, draft };
+            let pr = github.create_pr(&owner, &repo, &new_pr).await?;
+
+            if json {
+                println!("{}", serde_json::to_string(&pr).unwrap());
+            } else {
+                println!("{} Opened {}PR #{}: {}", style("✓").green(), if pr.draft { "draft " } else { "" }, pr.number, pr.title);
+                println!("   {}", style(&pr.html_url).underlined());
+            }
+        }
+        PrCommands::View { number, json } => {
+            let number = resolve_number(number, github, system, &owner, &repo).await?;
+            let pr = github.get_pr(&owner, &repo, number).await?;
+            let checks = github.list_checks(&owner, &repo, &pr.head_sha).await?;
+            let reviews = latest_reviews(github.list_reviews(&owner, &repo, number).await?);
+            let view = ViewOutput { checks_summary: summarize_checks(&checks), pr, checks, reviews };
+
+            if json {
+                println!("{}", serde_json::to_string(&view).unwrap());
+            } else {
+                print_view(&view);
+            }
+        }
+        PrCommands::Checkout { number, json } => {
+            let pr = github.get_pr(&owner, &repo, number).await?;
+            let branch = pr.head_ref;
+            let source = format!("pull/{}/head", number);
+
+            let exists = git(system, &["rev-parse", "--verify", "--quiet", &format!("refs/heads/{}", branch)]).await.is_ok();
+            if exists {
+                git(system, &["checkout", &branch]).await?;
+                git(system, &["pull", "--ff-only", "origin", &source]).await?;
+            } else {
+                git(system, &["fetch", "origin", &format!("{}:{}", source, branch)]).await?;
+                git(system, &["checkout", &branch]).await?;
+            }
+
+            if json {
+                println!("{}", serde_json::to_string(&CheckoutOutput { number, branch }).unwrap());
+            } else {
+                println!("{} Switched to {} (PR #{})", style("✓").green(), style(&branch).cyan(), number);
+            }
+        }
+        PrCommands::Ready { number, json } => {
+            let number = resolve_number(number, github, system, &owner, &repo).await?;
+            let pr = github.get_pr(&owner, &repo, number).await?;
+            if pr.draft {
+                github.mark_pr_ready(&owner, &repo, number).await?;
+            }
+
+            if json {
+                println!("{}", serde_json::to_string(&ReadyOutput { number, changed: pr.draft }).unwrap());
+            } else if pr.draft {
+                println!("{} PR #{} is ready for review", style("✓").green(), number);
+            } else {
+                println!("PR #{} is already ready for review", number);
+            }
+        }
+        PrCommands::Merge { number, method, delete_branch, json } => {
+            let method = parse_method(&method)?;
+            let number = resolve_number(number, github, system, &owner, &repo).await?;
+            let pr = github.get_pr(&owner, &repo, number).await?;
+            if pr.merged {
+                color_eyre::eyre::bail!("PR #{} is already merged", number);
+            }
+            if !pr.state.eq_ignore_ascii_case("open") {
+                color_eyre::eyre::bail!("PR #{} is not open (state: {})", number, pr.state);
+            }
+            if pr.mergeable == Some(false) {
+                color_eyre::eyre::bail!("PR #{} has conflicts with {}", number, pr.base_ref);
+            }
+
+            // GitHub refuses to merge drafts
+            if pr.draft {
+                if !json {
+                    println!("{} PR #{} is a draft, marking it ready...", style("📝").yellow(), number);
+                }
+                github.mark_pr_ready(&owner, &repo, number).await?;
+            }
+
+            if !json {
+                println!("{} {} merging PR #{}: {}", style("🔀").cyan(), method.as_str(), number, pr.title);
+            }
+            let sha = github.merge_pr(&owner, &repo, number, method).await?;
+
+            // A fork's head branch lives in another repository; a same-named branch here is unrelated
+            let from_fork = !pr.head_owner.eq_ignore_ascii_case(&owner);
+            let branch_deleted = delete_branch && !from_fork;
+            if branch_deleted {
+                github.delete_branch(&owner, &repo, &pr.head_ref).await?;
+            }
+            if branch_deleted {
+                delete_local_branch(system, &pr.head_ref, &pr.base_ref, &pr.head_sha).await?;
+            }
+
+            if json {
+                let output = MergeOutput { number, method, sha, marked_ready: pr.draft, branch_deleted };
+                println!("{}", serde_json::to_string(&output).unwrap());
+            } else {
+                println!("{} Merged PR #{} ({})", style("✓").green(), number, short_sha(&sha));
+                if branch_deleted {
+                    println!("   Deleted branch {}", pr.head_ref);
+                } else if delete_branch {
+                    println!("   Kept {}:{} (the head branch is in a fork)", pr.head_owner, pr.head_ref);
+                }
+            }
+        }
+        PrCommands::Stack(args) => {
+            stack::execute(args, github, system, &owner, &repo).await?;
+        }
+        PrCommands::Comment { number, body, json } => {
+            let number = resolve_number(number, github, system, &owner, &repo).await?;
+            github.post_comment(&owner, &repo, number, &body).await?;
+
+            if json {
+                println!("{}", serde_json::to_string(&CommentOutput { number, commented: true }).unwrap());
+            } else {
+                println!("{} Commented on PR #{}", style("✓").green(), number);
+            }
+        }
     }
     Ok(())
 }
 
-fn parse_repo_from_url(url: &str) -> color_eyre::Result<(String, String)> {
-    let url = url.trim();
-    let parts: Vec<&str> = if url.starts_with("git@") {
-        url.split(':').nth(1).unwrap_or("").split('/').collect()
+pub(crate) async fn git(system: &impl SystemPort, args: &[&str]) -> color_eyre::Result<String> {
+    let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+    Ok(system.run_command_output("git", &args).await?.trim().to_string())
+}
+
+/// The remote's default branch, `main` when origin/HEAD is not set.
+pub(crate) async fn default_branch(system: &impl SystemPort) -> String {
+    match git(system, &["symbolic-ref", "--short", "refs/remotes/origin/HEAD"]).await {
+        Ok(head) if !head.is_empty() => head.trim_start_matches("origin/").to_string(),
+        _ => "main".to_string(),
+    }
+}
+
+/// The given PR number, or the open PR whose head is the current branch.
+async fn resolve_number(
+    number: Option<u64>,
+    github: &impl GitHubPort,
+    system: &impl SystemPort,
+    owner: &str,
+    repo: &str,
+) -> color_eyre::Result<u64> {
+    if let Some(number) = number {
+        return Ok(number);
+    }
+    let branch = current_branch(system).await?;
+    let prs = github.list_prs(owner, repo, Some("open".to_string())).await?;
+    prs.iter()
+        .find(|pr| pr.head_ref == branch)
+        .map(|pr| pr.number)
+        .ok_or_else(|| color_eyre::eyre::eyre!("No open PR for branch '{}'; pass a PR number", branch))
+}
+
+fn parse_method(method: &str) -> color_eyre::Result<MergeMethod> {
+    match method {
+        "squash" => Ok(MergeMethod::Squash),
+        "rebase" => Ok(MergeMethod::Rebase),
+        "merge" => Ok(MergeMethod::Merge),
+        other => Err(color_eyre::eyre::eyre!("Unknown merge method: {}", other)),
+    }
+}
+
+/// Whether `tip` is `head` or one of its ancestors, i.e. a branch at `tip` holds nothing
+/// that `head` does not.
+pub(crate) async fn is_contained_in(system: &impl SystemPort, tip: &str, head: &str) -> bool {
+    tip == head || git(system, &["merge-base", "--is-ancestor", tip, head]).await.is_ok()
+}
+
+/// Removes the merged branch locally, leaving it first if it is checked out. A branch
+/// with commits beyond the PR's head is kept.
+async fn delete_local_branch(system: &impl SystemPort, branch: &str, base: &str, head_sha: &str) -> color_eyre::Result<()> {
+    let Ok(tip) = git(system, &["rev-parse", "--verify", "--quiet", &format!("refs/heads/{}", branch)]).await else {
+        return Ok(());
+    };
+    if !is_contained_in(system, &tip, head_sha).await {
+        return Ok(());
+    }
+    if current_branch(system).await? == branch {
+        git(system, &["checkout", base]).await?;
+        git(system, &["pull", "--ff-only"]).await?;
+    }
+    // -D: squash and rebase merges leave the branch unmerged as far as git can tell
+    git(system, &["branch", "-D", branch]).await?;
+    Ok(())
+}
+
+fn summarize_checks(checks: &[CheckRun]) -> ChecksSummary {
+    let mut summary = ChecksSummary::default();
+    for check in checks {
+        match check.conclusion.as_deref() {
+            _ if check.status != "completed" => summary.pending += 1,
+            Some("success") | Some("neutral") | Some("skipped") => summary.passed += 1,
+            _ => summary.failed += 1,
+        }
+    }
+    summary
+}
+
+/// Keeps the latest review of each reviewer; comments do not override an approval
+/// or a change request.
+fn latest_reviews(reviews: Vec<Review>) -> Vec<Review> {
+    let mut latest: Vec<Review> = Vec::new();
+    for review in reviews {
+        match latest.iter_mut().find(|r| r.author == review.author) {
+            Some(existing) if review.state == "COMMENTED" && existing.state != "COMMENTED" => {}
+            Some(existing) => *existing = review,
+            None => latest.push(review),
+        }
+    }
+    latest
+}
+
+fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
+}
+
+fn print_view(view: &ViewOutput) {
+    let pr = &view.pr;
+    let state = if pr.merged {
+        style("Merged".to_string()).magenta()
+    } else if pr.draft {
+        style("Draft".to_string()).dim()
     } else {
-        url.split("github.com/").nth(1).unwrap_or("").split('/').collect()
+        style(pr.state.clone()).cyan()
     };
+    println!("#{} {} [{}]", style(pr.number).green().bold(), style(&pr.title).bold(), state);
+    println!("   {} → {} by {}", style(&pr.head_ref).dim(), pr.base_ref, pr.author);
+    println!("   {}", style(&pr.html_url).underlined());
+    match pr.mergeable {
+        Some(false) => println!("   {} Has conflicts", style("⚠️").yellow()),
+        Some(true) if !pr.merged => println!("   {} No conflicts", style("✓").green()),
+        _ => {}
+    }
+
+    let summary = &view.checks_summary;
+    println!("\n{} Checks: {} passed, {} failed, {} pending",
+        style("🔍").yellow(),
+        style(summary.passed).green(),
+        style(summary.failed).red(),
+        style(summary.pending).yellow()
+    );
+    for check in &view.checks {
+        let mark = match check.conclusion.as_deref() {
+            _ if check.status != "completed" => style("…").yellow(),
+            Some("success") | Some("neutral") | Some("skipped") => style("✓").green(),
+            _ => style("✗").red(),
+        };
+        println!("   {} {}", mark, check.name);
+    }
+
+    println!("\n{} Reviews", style("👀").cyan());
+    if view.reviews.is_empty() {
+        println!("   None yet");
+    }
+    for review in &view.reviews {
+        let state = match review.state.as_str() {
+            "APPROVED" => style("approved").green(),
+            "CHANGES_REQUESTED" => style("changes requested").red(),
+            "DISMISSED" => style("dismissed").dim(),
+            _ => style("commented").dim(),
+        };
+        println!("   {} {}", review.author, state);
+    }
+
+    if let Some(body) = pr.body.as_deref().filter(|b| !b.trim().is_empty()) {
+        println!("\n{}", body.trim());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::{MockGitHubPort, MockSystemPort};
+    use mockall::predicate::*;
+
+    fn pr(number: u64, head: &str, draft: bool) -> PullRequest {
+        PullRequest {
+            number,
+            title: "Add login".into(),
+            body: None,
+            state: "Open".into(),
+            html_url: format!("https://github.com/acme/app/pull/{}", number),
+            head_ref: head.into(),
+            base_ref: "main".into(),
+            head_owner: "acme".into(),
+            draft,
+            author: "octocat".into(),
+            mergeable: Some(true),
+            merged: false,
+            head_sha: "abc1234def".into(),
+            labels: Vec::new(),
+        }
+    }
+
+    fn git_output(system: &mut MockSystemPort, args: &'static [&'static str], output: &'static str) {
+        let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        system.expect_run_command_output()
+            .withf(move |name, a| name == "git" && a == args.as_slice())
+            .returning(move |_, _| Ok(output.to_string()));
+    }
 
-    if parts.len() < 2 {
-        return Err(color_eyre::eyre::eyre!("Could not parse repo from URL: {}", url));
+    fn review(author: &str, state: &str) -> Review {
+        Review { author: author.into(), state: state.into(), submitted_at: None }
     }
 
-    let owner = parts[0].to_string();
-    let repo = parts[1].trim_end_matches(".git").to_string();
+    #[test]
+    fn test_summaries() {
+        let check = |status: &str, conclusion: Option<&str>| CheckRun {
+            name: "ci".into(),
+            status: status.into(),
+            conclusion: conclusion.map(str::to_string),
+        };
+        let checks = vec![
+            check("completed", Some("success")),
+            check("completed", Some("skipped")),
+            check("completed", Some("failure")),
+            check("in_progress", None),
+        ];
+        assert_eq!(summarize_checks(&checks), ChecksSummary { passed: 2, failed: 1, pending: 1 });
+
+        let reviews = latest_reviews(vec![
+            review("ana", "CHANGES_REQUESTED"),
+            review("bob", "COMMENTED"),
+            review("ana", "APPROVED"),
+            review("ana", "COMMENTED"),
+        ]);
+        assert_eq!(reviews, vec![review("ana", "APPROVED"), review("bob", "COMMENTED")]);
+    }
+
+    #[tokio::test]
+    async fn test_create_pushes_and_links_issue() {
+        let mut system = MockSystemPort::new();
+        let mut github = MockGitHubPort::new();
+
+        git_output(&mut system, &["remote", "get-url", "origin"], "git@github.com:acme/app.git\n");
+        git_output(&mut system, &["branch", "--show-current"], "feat/42-login\n");
+        git_output(&mut system, &["symbolic-ref", "--short", "refs/remotes/origin/HEAD"], "origin/develop\n");
+        git_output(&mut system, &["log", "-1", "--format=%s"], "feat: add login\n");
+        git_output(&mut system, &["push", "--set-upstream", "origin", "feat/42-login"], "");
+
+        let expected = NewPullRequest {
+            title: "feat: add login".into(),
+            body: "Closes #42".into(),
+            head: "feat/42-login".into(),
+            base: "develop".into(),
+            draft: true,
+        };
+        github.expect_create_pr()
+            .with(eq("acme"), eq("app"), eq(expected))
+            .times(1)
+            .returning(|_, _, _| Ok(pr(7, "feat/42-login", true)));
+
+        let args = PrArgs {
+            command: PrCommands::Create { title: None, body: None, base: None, head: None, draft: true, json: true },
+        };
+        execute(args, &github, &system).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_merge_readies_draft_and_deletes_branch() {
+        let mut system = MockSystemPort::new();
+        let mut github = MockGitHubPort::new();
+
+        git_output(&mut system, &["remote", "get-url", "origin"], "https://github.com/acme/app.git\n");
+        git_output(&mut system, &["branch", "--show-current"], "feat/login\n");
+        git_output(&mut system, &["rev-parse", "--verify", "--quiet", "refs/heads/feat/login"], "abc1234def\n");
+        git_output(&mut system, &["checkout", "main"], "");
+        git_output(&mut system, &["pull", "--ff-only"], "");
+        git_output(&mut system, &["branch", "-D", "feat/login"], "");
 
-    Ok((owner, repo))
+        github.expect_list_prs().returning(|_, _, _| Ok(vec![pr(3, "other", false), pr(7, "feat/login", true)]));
+        github.expect_get_pr().with(eq("acme"), eq("app"), eq(7)).returning(|_, _, _| Ok(pr(7, "feat/login", true)));
+        github.expect_mark_pr_ready().with(eq("acme"), eq("app"), eq(7)).times(1).returning(|_, _, _| Ok(()));
+        github.expect_merge_pr()
+            .with(eq("acme"), eq("app"), eq(7), eq(MergeMethod::Squash))
+            .times(1)
+            .returning(|_, _, _, _| Ok("f00dcafe".into()));
+        github.expect_delete_branch()
+            .with(eq("acme"), eq("app"), eq("feat/login"))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let args = PrArgs {
+            command: PrCommands::Merge { number: None, method: "squash".into(), delete_branch: true, json: true },
+        };
+        execute(args, &github, &system).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_merge_keeps_base_branch_named_like_fork_head() {
+        let mut system = MockSystemPort::new();
+        let mut github = MockGitHubPort::new();
+
+        git_output(&mut system, &["remote", "get-url", "origin"], "https://github.com/acme/app.git\n");
+        system.expect_run_command_output().withf(|name, a| name == "git" && a[0] == "rev-parse").never();
+        github.expect_get_pr().returning(|_, _, n| Ok(PullRequest { head_owner: "contributor".into(), ..pr(n, "main-fix", false) }));
+        github.expect_merge_pr().times(1).returning(|_, _, _, _| Ok("f00dcafe".into()));
+        github.expect_delete_branch().never();
+
+        let args = PrArgs {
+            command: PrCommands::Merge { number: Some(9), method: "squash".into(), delete_branch: true, json: true },
+        };
+        execute(args, &github, &system).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_merge_keeps_local_branch_with_commits_beyond_the_pr() {
+        let mut system = MockSystemPort::new();
+        let mut github = MockGitHubPort::new();
+
+        git_output(&mut system, &["remote", "get-url", "origin"], "https://github.com/acme/app.git\n");
+        git_output(&mut system, &["rev-parse", "--verify", "--quiet", "refs/heads/feat/login"], "1ocal0nly\n");
+        system.expect_run_command_output()
+            .withf(|name, a| name == "git" && a[..2] == ["merge-base", "--is-ancestor"])
+            .returning(|_, _| Err(gc_core::ports::CoreError::System("not an ancestor".into())));
+        system.expect_run_command_output().withf(|_, a| a[0] == "branch" || a[0] == "checkout").never();
+        github.expect_get_pr().returning(|_, _, n| Ok(pr(n, "feat/login", false)));
+        github.expect_merge_pr().times(1).returning(|_, _, _, _| Ok("f00dcafe".into()));
+        github.expect_delete_branch().times(1).returning(|_, _, _| Ok(()));
+
+        let args = PrArgs {
+            command: PrCommands::Merge { number: Some(7), method: "squash".into(), delete_branch: true, json: true },
+        };
+        execute(args, &github, &system).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_merge_deletes_local_branch_behind_the_pr_head() {
+        let mut system = MockSystemPort::new();
+        let mut github = MockGitHubPort::new();
+
+        git_output(&mut system, &["remote", "get-url", "origin"], "https://github.com/acme/app.git\n");
+        git_output(&mut system, &["rev-parse", "--verify", "--quiet", "refs/heads/feat/login"], "0lder\n");
+        git_output(&mut system, &["merge-base", "--is-ancestor", "0lder", "abc1234def"], "");
+        git_output(&mut system, &["branch", "--show-current"], "main\n");
+        git_output(&mut system, &["branch", "-D", "feat/login"], "");
+        github.expect_get_pr().returning(|_, _, n| Ok(pr(n, "feat/login", false)));
+        github.expect_merge_pr().times(1).returning(|_, _, _, _| Ok("f00dcafe".into()));
+        github.expect_delete_branch().times(1).returning(|_, _, _| Ok(()));
+
+        let args = PrArgs {
+            command: PrCommands::Merge { number: Some(7), method: "squash".into(), delete_branch: true, json: true },
+        };
+        execute(args, &github, &system).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_merge_refuses_closed_pr() {
+        let mut system = MockSystemPort::new();
+        let mut github = MockGitHubPort::new();
+
+        git_output(&mut system, &["remote", "get-url", "origin"], "https://github.com/acme/app.git\n");
+        github.expect_get_pr().returning(|_, _, n| Ok(PullRequest { state: "Closed".into(), ..pr(n, "feat/login", false) }));
+        github.expect_merge_pr().never();
+
+        let args = PrArgs {
+            command: PrCommands::Merge { number: Some(7), method: "rebase".into(), delete_branch: false, json: true },
+        };
+        assert!(execute(args, &github, &system).await.is_err());
+    }
 }
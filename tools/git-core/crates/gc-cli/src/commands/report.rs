@@ -156,7 +156,7 @@ pub async fn execute(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::commands::mocks::{MockSystemPort, MockGitHubPort};
+    use gc_core::ports::{MockSystemPort, MockGitHubPort};
     use mockall::predicate::*;
 
     #[tokio::test]
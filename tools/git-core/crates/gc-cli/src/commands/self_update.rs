@@ -0,0 +1,237 @@
+use clap::Args;
+use color_eyre::Result;
+use gc_core::ports::GitHubPort;
+use console::style;
+use std::cmp::Ordering;
+use std::io::{Cursor, Read};
+use std::path::Path;
+use zip::ZipArchive;
+use crate::commands::update::{compare_versions, download, verify_checksum, UPSTREAM_OWNER, UPSTREAM_REPO};
+
+/// Target this binary was built for (set by build.rs)
+const TARGET: &str = env!("GC_TARGET");
+/// Release asset per target, as published by .github/workflows/release.yml
+const RELEASE_ASSETS: [(&str, &str); 4] = [
+    ("x86_64-unknown-linux-gnu", "gc-linux-x86_64.tar.gz"),
+    ("x86_64-pc-windows-msvc", "gc-windows-x86_64.zip"),
+    ("x86_64-apple-darwin", "gc-macos-x86_64.tar.gz"),
+    ("aarch64-apple-darwin", "gc-macos-aarch64.tar.gz"),
+];
+
+#[derive(Args, Debug)]
+pub struct SelfUpdateArgs {
+    /// Install this release instead of the latest (e.g. 3.4.0); older versions are allowed
+    #[arg(long, value_name = "VERSION")]
+    pub to: Option<String>,
+
+    /// Install builds that have no published checksum
+    #[arg(long)]
+    pub allow_unverified: bool,
+}
+
+pub async fn execute(args: SelfUpdateArgs, github: &impl GitHubPort) -> Result<()> {
+    let exe = std::env::current_exe()?;
+    self_update(&args, github, env!("CARGO_PKG_VERSION"), TARGET, &exe).await?;
+    Ok(())
+}
+
+/// Replaces `exe` with the release build for `target`. Returns the installed version,
+/// or `None` when `current` is already the latest.
+async fn self_update(
+    args: &SelfUpdateArgs,
+    github: &impl GitHubPort,
+    current: &str,
+    target: &str,
+    exe: &Path,
+) -> Result<Option<String>> {
+    let tag = args.to.as_ref().map(|v| format!("v{}", v.trim_start_matches('v')));
+    let release = github.get_release(UPSTREAM_OWNER, UPSTREAM_REPO, tag.clone()).await?;
+    let version = release.tag_name.trim_start_matches('v').to_string();
+
+    match compare_versions(Some(current), &version) {
+        Ordering::Equal => {
+            println!("{} gc is already at {}.", style("✅").green(), current);
+            return Ok(None);
+        }
+        // Only an explicit --to may go back
+        Ordering::Greater if tag.is_none() => {
+            println!("{} gc {} is newer than the latest release ({}).", style("✅").green(), current, version);
+            return Ok(None);
+        }
+        Ordering::Greater => println!("{} Downgrading gc: {} → {}", style("⚠️").yellow(), current, version),
+        Ordering::Less => println!("{} Updating gc: {} → {}", style("ℹ").blue(), current, version),
+    }
+
+    let Some((_, asset_name)) = RELEASE_ASSETS.iter().find(|(t, _)| *t == target) else {
+        color_eyre::eyre::bail!("No release build for {}; build from source with 'cargo install --path tools/git-core/crates/gc-cli'", target);
+    };
+    let asset_url = |name: &str| release.assets.iter().find(|a| a.name == name).map(|a| a.download_url.clone());
+    let Some(archive_url) = asset_url(asset_name) else {
+        color_eyre::eyre::bail!("Release {} has no {} asset", release.tag_name, asset_name);
+    };
+    let checksum_url = asset_url(&format!("{}.sha256", asset_name));
+    if checksum_url.is_none() && !args.allow_unverified {
+        color_eyre::eyre::bail!("Release {} publishes no checksum for {}; pass --allow-unverified to install it anyway", release.tag_name, asset_name);
+    }
+
+    println!("{}", style(format!("📥 Downloading {}...", archive_url)).yellow());
+    let archive = download(&archive_url).await?;
+    match checksum_url {
+        Some(url) => {
+            let published = String::from_utf8_lossy(&download(&url).await?).to_string();
+            verify_checksum(&archive, &published)?;
+            println!("{}", style("✓ Checksum verified").green());
+        }
+        None => println!("{}", style("⚠️  No published checksum; build not verified.").yellow()),
+    }
+
+    let binary = extract_binary(asset_name, &archive)?;
+    replace_executable(exe, &binary)?;
+    println!("{} gc {} installed at {}", style("✅").green(), version, exe.display());
+    Ok(Some(version))
+}
+
+/// The `gc` executable inside a release archive (`.tar.gz`, or `.zip` on Windows).
+fn extract_binary(asset_name: &str, archive: &[u8]) -> Result<Vec<u8>> {
+    let mut binary = Vec::new();
+    if asset_name.ends_with(".zip") {
+        let mut zip = ZipArchive::new(Cursor::new(archive))?;
+        zip.by_name("gc.exe")?.read_to_end(&mut binary)?;
+        return Ok(binary);
+    }
+
+    let mut tar = tar::Archive::new(flate2::read::GzDecoder::new(archive));
+    for entry in tar.entries()? {
+        let mut entry = entry?;
+        if entry.path()?.file_name().is_some_and(|name| name == "gc") {
+            entry.read_to_end(&mut binary)?;
+            return Ok(binary);
+        }
+    }
+    color_eyre::eyre::bail!("{} does not contain a gc binary", asset_name)
+}
+
+/// Writes the new binary next to `exe` and renames it over, so `exe` is never half-written.
+fn replace_executable(exe: &Path, binary: &[u8]) -> Result<()> {
+    let dir = exe.parent().ok_or_else(|| color_eyre::eyre::eyre!("{} has no parent directory", exe.display()))?;
+    let name = exe.file_name().unwrap_or_default().to_string_lossy();
+    // Same directory, so the rename stays on one filesystem
+    let staged = dir.join(format!(".{}.new", name));
+    std::fs::write(&staged, binary)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o755))?;
+    }
+    // A running .exe cannot be replaced, but it can be moved aside
+    #[cfg(windows)]
+    let old = {
+        let old = dir.join(format!("{}.old", name));
+        let _ = std::fs::remove_file(&old);
+        std::fs::rename(exe, &old)?;
+        old
+    };
+
+    if let Err(e) = std::fs::rename(&staged, exe) {
+        let _ = std::fs::remove_file(&staged);
+        #[cfg(windows)]
+        let _ = std::fs::rename(&old, exe);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::MockGitHubPort;
+    use gc_core::{Release, ReleaseAsset};
+    use sha2::{Digest, Sha256};
+
+    const LINUX: &str = "x86_64-unknown-linux-gnu";
+
+    fn tarball(binary: &[u8]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default()));
+        let mut header = tar::Header::new_gnu();
+        header.set_size(binary.len() as u64);
+        header.set_mode(0o755);
+        header.set_cksum();
+        builder.append_data(&mut header, "gc", binary).unwrap();
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    fn github(server: &mockito::Server, tag: &'static str) -> MockGitHubPort {
+        let base = server.url();
+        let mut github = MockGitHubPort::new();
+        github.expect_get_release().returning(move |_, _, _| Ok(Release {
+            tag_name: tag.into(),
+            prerelease: false,
+            assets: ["gc-linux-x86_64.tar.gz", "gc-linux-x86_64.tar.gz.sha256"].iter().map(|name| ReleaseAsset {
+                name: name.to_string(),
+                download_url: format!("{}/download/{}", base, name),
+                size: 0,
+            }).collect(),
+        }));
+        github
+    }
+
+    fn args(to: Option<&str>) -> SelfUpdateArgs {
+        SelfUpdateArgs { to: to.map(str::to_string), allow_unverified: false }
+    }
+
+    fn installed_exe() -> (assert_fs::TempDir, std::path::PathBuf) {
+        let dir = assert_fs::TempDir::new().unwrap();
+        let exe = dir.path().join("gc");
+        std::fs::write(&exe, "old binary").unwrap();
+        (dir, exe)
+    }
+
+    #[tokio::test]
+    async fn test_downloads_verifies_and_replaces_binary() {
+        let mut server = mockito::Server::new_async().await;
+        let archive = tarball(b"new binary");
+        let checksum = format!("{}  gc-linux-x86_64.tar.gz\n", hex::encode(Sha256::digest(&archive)));
+        let archive_mock = server.mock("GET", "/download/gc-linux-x86_64.tar.gz").with_body(archive).create_async().await;
+        server.mock("GET", "/download/gc-linux-x86_64.tar.gz.sha256").with_body(checksum).create_async().await;
+        let (_dir, exe) = installed_exe();
+
+        let installed = self_update(&args(None), &github(&server, "v3.6.0"), "3.5.0", LINUX, &exe).await.unwrap();
+
+        archive_mock.assert_async().await;
+        assert_eq!(installed.as_deref(), Some("3.6.0"));
+        assert_eq!(std::fs::read(&exe).unwrap(), b"new binary");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&exe).unwrap().permissions().mode() & 0o777, 0o755);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_checksum_mismatch_keeps_current_binary() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/download/gc-linux-x86_64.tar.gz").with_body(tarball(b"tampered")).create_async().await;
+        server.mock("GET", "/download/gc-linux-x86_64.tar.gz.sha256").with_body(format!("{}  gc-linux-x86_64.tar.gz\n", "0".repeat(64))).create_async().await;
+        let (dir, exe) = installed_exe();
+
+        assert!(self_update(&args(Some("3.4.0")), &github(&server, "v3.4.0"), "3.5.0", LINUX, &exe).await.is_err());
+        assert_eq!(std::fs::read(&exe).unwrap(), b"old binary");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_skips_when_up_to_date_or_unsupported() {
+        let server = mockito::Server::new_async().await;
+        let (_dir, exe) = installed_exe();
+
+        let installed = self_update(&args(None), &github(&server, "v3.5.0"), "3.5.0", LINUX, &exe).await.unwrap();
+        assert_eq!(installed, None);
+        // A local build ahead of the latest release is not downgraded without --to
+        let installed = self_update(&args(None), &github(&server, "v3.4.0"), "3.5.0", LINUX, &exe).await.unwrap();
+        assert_eq!(installed, None);
+
+        assert!(self_update(&args(None), &github(&server, "v3.6.0"), "3.5.0", "riscv64gc-unknown-linux-gnu", &exe).await.is_err());
+        assert_eq!(std::fs::read(&exe).unwrap(), b"old binary");
+    }
+}
@@ -0,0 +1,493 @@
+//! Stacked pull requests: chains of branches where each one builds on the previous.
+//!
+//! A branch's parent is the base of its open PR when it has one, otherwise the
+//! closest local branch its history contains (the default branch if none).
+
+use clap::{Args, Subcommand};
+use gc_core::ports::{GitHubPort, SystemPort};
+use gc_core::{NewPullRequest, PullRequest};
+use console::style;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use crate::commands::finish::{current_branch, issue_from_branch};
+use crate::commands::pr::{default_branch, git};
+
+#[derive(Args, Debug)]
+pub struct StackArgs {
+    #[command(subcommand)]
+    pub command: Option<StackCommands>,
+
+    /// Output in JSON format
+    #[arg(long, global = true)]
+    pub json: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum StackCommands {
+    /// Show the stack of the current branch (default)
+    Show,
+    /// Push every branch of the stack and create or retarget its PR, bottom-up
+    Submit {
+        /// Open new PRs as drafts
+        #[arg(short, long)]
+        draft: bool,
+    },
+    /// Rebase the stack onto merged PRs and retarget the PRs above them
+    Sync,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+struct StackEntry {
+    branch: String,
+    /// Parent branch in the stack
+    base: String,
+    pr: Option<u64>,
+    /// Branch the PR currently targets (differs from `base` until submitted)
+    pr_base: Option<String>,
+    draft: bool,
+    merged: bool,
+}
+
+#[derive(Serialize)]
+struct StackOutput {
+    trunk: String,
+    current: String,
+    branches: Vec<StackEntry>,
+}
+
+#[derive(Serialize)]
+struct SubmitOutput {
+    branch: String,
+    base: String,
+    pr: u64,
+    url: String,
+    /// `created`, `retargeted` or `unchanged`
+    action: &'static str,
+}
+
+#[derive(Serialize, Default)]
+struct SyncOutput {
+    merged: Vec<String>,
+    rebased: Vec<String>,
+    retargeted: Vec<u64>,
+}
+
+/// Everything known about the stack of the current branch.
+struct Stack {
+    trunk: String,
+    current: String,
+    parents: BTreeMap<String, String>,
+    branches: Vec<String>,
+    open: HashMap<String, PullRequest>,
+    merged: HashMap<String, PullRequest>,
+}
+
+impl Stack {
+    fn entries(&self) -> Vec<StackEntry> {
+        self.branches.iter().map(|branch| {
+            let pr = self.open.get(branch).or_else(|| self.merged.get(branch));
+            StackEntry {
+                branch: branch.clone(),
+                base: self.parents[branch].clone(),
+                pr: pr.map(|pr| pr.number),
+                pr_base: pr.map(|pr| pr.base_ref.clone()),
+                draft: pr.is_some_and(|pr| pr.draft),
+                merged: self.merged.contains_key(branch) && !self.open.contains_key(branch),
+            }
+        }).collect()
+    }
+}
+
+pub async fn execute(
+    args: StackArgs,
+    github: &impl GitHubPort,
+    system: &impl SystemPort,
+    owner: &str,
+    repo: &str,
+) -> color_eyre::Result<()> {
+    let stack = load(github, system, owner, repo).await?;
+
+    match args.command.unwrap_or(StackCommands::Show) {
+        StackCommands::Show => {
+            let entries = stack.entries();
+            if args.json {
+                let output = StackOutput { trunk: stack.trunk, current: stack.current, branches: entries };
+                println!("{}", serde_json::to_string(&output).unwrap());
+            } else {
+                print!("{}", render(&stack.trunk, &entries, &stack.current));
+            }
+        }
+        StackCommands::Submit { draft } => {
+            let results = submit(&stack, draft, args.json, github, system, owner, repo).await?;
+            if args.json {
+                println!("{}", serde_json::to_string(&results).unwrap());
+            }
+        }
+        StackCommands::Sync => {
+            let output = sync(&stack, args.json, github, system, owner, repo).await?;
+            if args.json {
+                println!("{}", serde_json::to_string(&output).unwrap());
+            } else if output.merged.is_empty() {
+                println!("Nothing merged below {}; the stack is up to date.", stack.current);
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn load(github: &impl GitHubPort, system: &impl SystemPort, owner: &str, repo: &str) -> color_eyre::Result<Stack> {
+    let trunk = default_branch(system).await;
+    let current = current_branch(system).await?;
+    if current.is_empty() || current == trunk {
+        color_eyre::eyre::bail!("Switch to a branch of the stack first (currently on '{}')", current);
+    }
+
+    let mut open = HashMap::new();
+    for pr in github.list_prs(owner, repo, Some("open".to_string())).await? {
+        open.entry(pr.head_ref.clone()).or_insert(pr);
+    }
+
+    let locals = branch_list(system, &["for-each-ref", "--format=%(refname:short)", "refs/heads"]).await?;
+    // Merged PRs per local branch rather than every closed PR of the repository
+    let mut merged = HashMap::new();
+    for branch in locals.iter().filter(|b| **b != trunk && !open.contains_key(*b)) {
+        if let Some(pr) = github.list_branch_prs(owner, repo, branch).await?.into_iter().find(|pr| pr.merged) {
+            merged.insert(branch.clone(), pr);
+        }
+    }
+    let mut parents = BTreeMap::new();
+    for branch in locals.iter().filter(|b| **b != trunk) {
+        let parent = match open.get(branch).or_else(|| merged.get(branch)) {
+            Some(pr) if pr.base_ref == trunk || locals.contains(&pr.base_ref) => pr.base_ref.clone(),
+            _ => nearest_ancestor(system, branch, &trunk).await?,
+        };
+        parents.insert(branch.clone(), parent);
+    }
+
+    let branches = chain(&parents, &trunk, &current);
+    Ok(Stack { trunk, current, parents, branches, open, merged })
+}
+
+async fn branch_list(system: &impl SystemPort, args: &[&str]) -> color_eyre::Result<Vec<String>> {
+    let output = git(system, args).await?;
+    Ok(output.lines().map(|l| l.trim().to_string()).filter(|l| !l.is_empty()).collect())
+}
+
+/// The local branch whose tip is closest below `branch`, `trunk` on ties or when none is.
+async fn nearest_ancestor(system: &impl SystemPort, branch: &str, trunk: &str) -> color_eyre::Result<String> {
+    let candidates = branch_list(system, &["for-each-ref", "--merged", branch, "--format=%(refname:short)", "refs/heads"]).await?;
+    let mut best: Option<(u64, String)> = None;
+    for candidate in candidates.into_iter().filter(|c| c != branch) {
+        let count: u64 = git(system, &["rev-list", "--count", &format!("{}..{}", candidate, branch)]).await?
+            .parse()
+            .unwrap_or(0);
+        // Same tip: the candidate is as likely to be a child as a parent
+        if count == 0 {
+            continue;
+        }
+        let closer = match &best {
+            None => true,
+            Some((best_count, _)) => count < *best_count || (count == *best_count && candidate == trunk),
+        };
+        if closer {
+            best = Some((count, candidate));
+        }
+    }
+    Ok(best.map(|(_, b)| b).unwrap_or_else(|| trunk.to_string()))
+}
+
+/// The linear chain of branches through `current`, bottom (next to trunk) first.
+/// Going up stops where a branch has no child or more than one.
+fn chain(parents: &BTreeMap<String, String>, trunk: &str, current: &str) -> Vec<String> {
+    let mut below = vec![current.to_string()];
+    let mut branch = current;
+    while let Some(parent) = parents.get(branch) {
+        if parent == trunk || below.contains(parent) {
+            break;
+        }
+        below.push(parent.clone());
+        branch = parent;
+    }
+    below.reverse();
+
+    let mut branch = current.to_string();
+    loop {
+        let children: Vec<&String> = parents.iter().filter(|(_, p)| **p == branch).map(|(c, _)| c).collect();
+        match children.as_slice() {
+            [child] if !below.contains(child) => {
+                below.push((*child).clone());
+                branch = (*child).clone();
+            }
+            _ => break,
+        }
+    }
+    below
+}
+
+fn render(trunk: &str, entries: &[StackEntry], current: &str) -> String {
+    let mut out = format!("{}\n", style(trunk).bold());
+    for (depth, entry) in entries.iter().enumerate() {
+        let pr = match (entry.pr, entry.merged) {
+            (Some(n), true) => format!("#{} merged", n),
+            (Some(n), false) if entry.draft => format!("#{} draft", n),
+            (Some(n), false) => format!("#{} open", n),
+            (None, _) => "no PR".to_string(),
+        };
+        let mut line = format!("{}└─ {}  {}", "   ".repeat(depth), style(&entry.branch).cyan(), style(pr).dim());
+        if let Some(pr_base) = entry.pr_base.as_deref().filter(|b| *b != entry.base && !entry.merged) {
+            line.push_str(&format!("  {}", style(format!("(targets {})", pr_base)).yellow()));
+        }
+        if entry.branch == current {
+            line.push_str("  ← current");
+        }
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
+async fn submit(
+    stack: &Stack,
+    draft: bool,
+    json: bool,
+    github: &impl GitHubPort,
+    system: &impl SystemPort,
+    owner: &str,
+    repo: &str,
+) -> color_eyre::Result<Vec<SubmitOutput>> {
+    // Pushing a merged branch would reopen it as a new PR; sync drops it and rebases the rest
+    if let Some(branch) = stack.branches.iter().find(|b| stack.merged.contains_key(*b) && !stack.open.contains_key(*b)) {
+        color_eyre::eyre::bail!(
+            "{} was merged in #{}; run `gc pr stack sync` first",
+            branch,
+            stack.merged[branch].number
+        );
+    }
+
+    let mut results = Vec::new();
+    let mut numbers: HashMap<String, u64> = HashMap::new();
+
+    for branch in &stack.branches {
+        let base = &stack.parents[branch];
+        // Lease: `sync` rewrites history, but never clobber commits pushed by someone else
+        git(system, &["push", "--force-with-lease", "--set-upstream", "origin", branch]).await?;
+
+        let (pr, action) = match stack.open.get(branch) {
+            Some(pr) if pr.base_ref != *base => (github.set_pr_base(owner, repo, pr.number, base).await?, "retargeted"),
+            Some(pr) => (pr.clone(), "unchanged"),
+            None => {
+                let title = git(system, &["log", "-1", "--format=%s", branch]).await?;
+                let mut body = Vec::new();
+                if let Some(parent) = numbers.get(base) {
+                    body.push(format!("Depends on #{}", parent));
+                }
+                if let Some(issue) = issue_from_branch(branch) {
+                    body.push(format!("Closes #{}", issue));
+                }
+                let new_pr = NewPullRequest { title, body: body.join("\n\n"), head: branch.clone(), base: base.clone(), draft };
+                (github.create_pr(owner, repo, &new_pr).await?, "created")
+            }
+        };
+
+        if !json {
+            println!("{} {} → {}  #{} {}", style("✓").green(), style(branch).cyan(), base, pr.number, style(action).dim());
+        }
+        numbers.insert(branch.clone(), pr.number);
+        results.push(SubmitOutput { branch: branch.clone(), base: base.clone(), pr: pr.number, url: pr.html_url, action });
+    }
+    Ok(results)
+}
+
+async fn sync(
+    stack: &Stack,
+    json: bool,
+    github: &impl GitHubPort,
+    system: &impl SystemPort,
+    owner: &str,
+    repo: &str,
+) -> color_eyre::Result<SyncOutput> {
+    let mut output = SyncOutput::default();
+    let merged: HashSet<&String> = stack.branches.iter()
+        .filter(|b| stack.merged.contains_key(*b) && !stack.open.contains_key(*b))
+        .collect();
+    if merged.is_empty() {
+        return Ok(output);
+    }
+
+    git(system, &["fetch", "origin"]).await?;
+    let mut tips = HashMap::new();
+    for branch in &stack.branches {
+        tips.insert(branch.clone(), git(system, &["rev-parse", branch]).await?);
+    }
+
+    // New parent of each branch once the merged ones drop out of the stack
+    let mut new_parents: HashMap<&String, String> = HashMap::new();
+    let mut moved: HashSet<&String> = HashSet::new();
+    for branch in &stack.branches {
+        let old_parent = &stack.parents[branch];
+        let parent = match new_parents.get(old_parent) {
+            Some(grandparent) if merged.contains(old_parent) => grandparent.clone(),
+            _ => old_parent.clone(),
+        };
+        new_parents.insert(branch, parent.clone());
+
+        if merged.contains(branch) {
+            moved.insert(branch);
+            output.merged.push(branch.clone());
+            continue;
+        }
+        if !moved.contains(old_parent) {
+            continue;
+        }
+
+        let onto = if parent == stack.trunk { format!("origin/{}", stack.trunk) } else { parent.clone() };
+        if !json {
+            println!("{} Rebasing {} onto {}...", style("🔁").cyan(), branch, onto);
+        }
+        if let Err(e) = git(system, &["rebase", "--onto", &onto, &tips[old_parent], branch]).await {
+            color_eyre::eyre::bail!(
+                "Rebasing {} failed ({}); resolve the conflicts, run `git rebase --continue`, then re-run `gc pr stack sync`",
+                branch, e
+            );
+        }
+        git(system, &["push", "--force-with-lease", "origin", branch]).await?;
+        moved.insert(branch);
+        output.rebased.push(branch.clone());
+
+        if let Some(pr) = stack.open.get(branch).filter(|pr| pr.base_ref != parent) {
+            github.set_pr_base(owner, repo, pr.number, &parent).await?;
+            output.retargeted.push(pr.number);
+        }
+    }
+
+    let back_to = if merged.contains(&stack.current) { &stack.trunk } else { &stack.current };
+    git(system, &["checkout", back_to]).await?;
+    for branch in &output.merged {
+        git(system, &["branch", "-D", branch]).await?;
+        if !json {
+            println!("{} Removed merged branch {}", style("✓").green(), branch);
+        }
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::{MockGitHubPort, MockSystemPort};
+    use std::sync::{Arc, Mutex};
+
+    fn parent_map(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs.iter().map(|(b, p)| (b.to_string(), p.to_string())).collect()
+    }
+
+    fn pr(number: u64, head: &str, base: &str, merged: bool) -> PullRequest {
+        PullRequest {
+            number,
+            title: head.into(),
+            body: None,
+            state: if merged { "Closed".into() } else { "Open".into() },
+            html_url: format!("https://github.com/acme/app/pull/{}", number),
+            head_ref: head.into(),
+            base_ref: base.into(),
+            head_owner: "acme".into(),
+            draft: false,
+            author: "octocat".into(),
+            mergeable: None,
+            merged,
+            head_sha: String::new(),
+            labels: Vec::new(),
+        }
+    }
+
+    /// Answers git commands from a table and records the ones that were run.
+    fn fake_git(system: &mut MockSystemPort, answers: &'static [(&'static str, &'static str)]) -> Arc<Mutex<Vec<String>>> {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let calls = log.clone();
+        system.expect_run_command_output().returning(move |_, args| {
+            let command = args.join(" ");
+            calls.lock().unwrap().push(command.clone());
+            let answer = answers.iter().find(|(c, _)| *c == command).map(|(_, a)| *a).unwrap_or("");
+            Ok(answer.to_string())
+        });
+        log
+    }
+
+    #[test]
+    fn test_chain_follows_parents_both_ways() {
+        let parents = parent_map(&[("a", "main"), ("b", "a"), ("c", "b"), ("other", "main")]);
+        assert_eq!(chain(&parents, "main", "b"), vec!["a", "b", "c"]);
+        assert_eq!(chain(&parents, "main", "other"), vec!["other"]);
+
+        // A fork above the current branch ends the chain there
+        let forked = parent_map(&[("a", "main"), ("b", "a"), ("c", "a")]);
+        assert_eq!(chain(&forked, "main", "a"), vec!["a"]);
+        assert_eq!(chain(&forked, "main", "c"), vec!["a", "c"]);
+    }
+
+    #[test]
+    fn test_render_marks_current_and_stale_bases() {
+        let entries = vec![
+            StackEntry { branch: "a".into(), base: "main".into(), pr: Some(1), pr_base: Some("main".into()), draft: false, merged: false },
+            StackEntry { branch: "b".into(), base: "a".into(), pr: Some(2), pr_base: Some("main".into()), draft: true, merged: false },
+            StackEntry { branch: "c".into(), base: "b".into(), pr: None, pr_base: None, draft: false, merged: false },
+        ];
+        let graph = console::strip_ansi_codes(&render("main", &entries, "b")).to_string();
+        assert_eq!(graph, "main\n└─ a  #1 open\n   └─ b  #2 draft  (targets main)  ← current\n      └─ c  no PR\n");
+    }
+
+    #[tokio::test]
+    async fn test_submit_refuses_stack_with_merged_branch() {
+        let mut system = MockSystemPort::new();
+        let mut github = MockGitHubPort::new();
+        let log = fake_git(&mut system, &[
+            ("symbolic-ref --short refs/remotes/origin/HEAD", "origin/main"),
+            ("branch --show-current", "b"),
+            ("for-each-ref --format=%(refname:short) refs/heads", "main\na\nb\n"),
+        ]);
+        github.expect_list_prs().returning(|_, _, _| Ok(vec![pr(2, "b", "a", false)]));
+        github.expect_list_branch_prs()
+            .returning(|_, _, branch| Ok(if branch == "a" { vec![pr(1, "a", "main", true)] } else { vec![] }));
+        github.expect_create_pr().never();
+
+        let args = StackArgs { command: Some(StackCommands::Submit { draft: false }), json: true };
+        let err = execute(args, &github, &system, "acme", "app").await.unwrap_err();
+
+        assert!(err.to_string().contains("gc pr stack sync"));
+        assert!(!log.lock().unwrap().iter().any(|c| c.starts_with("push")));
+    }
+
+    #[tokio::test]
+    async fn test_sync_rebases_onto_merged_pr() {
+        let mut system = MockSystemPort::new();
+        let mut github = MockGitHubPort::new();
+        let log = fake_git(&mut system, &[
+            ("symbolic-ref --short refs/remotes/origin/HEAD", "origin/main"),
+            ("branch --show-current", "b"),
+            ("for-each-ref --format=%(refname:short) refs/heads", "main\na\nb\nc\n"),
+            ("rev-parse a", "aaa"),
+            ("rev-parse b", "bbb"),
+        ]);
+        github.expect_list_prs().returning(|_, _, _| Ok(vec![pr(2, "b", "a", false), pr(3, "c", "b", false)]));
+        github.expect_list_branch_prs()
+            .returning(|_, _, branch| Ok(if branch == "a" { vec![pr(1, "a", "main", true)] } else { vec![] }));
+        github.expect_set_pr_base()
+            .withf(|_, _, n, base| *n == 2 && base == "main")
+            .times(1)
+            .returning(|_, _, n, base| Ok(pr(n, "b", base, false)));
+
+        let args = StackArgs { command: Some(StackCommands::Sync), json: true };
+        execute(args, &github, &system, "acme", "app").await.unwrap();
+
+        let commands: Vec<String> = log.lock().unwrap().iter()
+            .filter(|c| c.starts_with("rebase") || c.starts_with("branch -D") || c.starts_with("checkout"))
+            .cloned()
+            .collect();
+        assert_eq!(commands, vec![
+            "rebase --onto origin/main aaa b",
+            "rebase --onto b bbb c",
+            "checkout b",
+            "branch -D a",
+        ]);
+    }
+}
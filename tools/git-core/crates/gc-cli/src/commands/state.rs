@@ -0,0 +1,265 @@
+use clap::{Args, Subcommand};
+use color_eyre::Result;
+use gc_core::agent_state::{AgentState, InputRequest, PlanItem};
+use gc_core::ports::{GitHubPort, SystemPort};
+use console::style;
+use serde::Serialize;
+use crate::commands::issue::parse_repo_from_url;
+
+#[derive(Args, Debug)]
+pub struct StateArgs {
+    #[command(subcommand)]
+    pub command: StateCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum StateCommands {
+    /// Show the latest <agent-state> of an issue
+    Read {
+        /// Issue number
+        #[arg(short, long)]
+        issue: u64,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+    /// Post an updated <agent-state> comment (fields not given are kept from the latest state)
+    Write {
+        /// Issue number
+        #[arg(short, long)]
+        issue: u64,
+
+        /// High-level goal (e.g. fix_bug, refactor)
+        #[arg(long)]
+        intent: Option<String>,
+
+        /// Current step (planning, coding, testing, waiting_for_input...)
+        #[arg(long)]
+        step: Option<String>,
+
+        /// Progress percentage (0-100)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(0..=100))]
+        progress: Option<u8>,
+
+        /// Plan item as STATUS:TEXT (repeatable, replaces the previous plan)
+        #[arg(long = "plan", value_name = "STATUS:TEXT")]
+        plan: Vec<String>,
+
+        /// Question for a human (sets input_request status to waiting)
+        #[arg(long)]
+        input_request: Option<String>,
+
+        /// Memory to carry over (usually a JSON string)
+        #[arg(long)]
+        memory: Option<String>,
+
+        /// Suggested next action
+        #[arg(long)]
+        next_action: Option<String>,
+
+        /// Start from an empty state instead of the latest one
+        #[arg(long)]
+        fresh: bool,
+
+        /// Print the block without posting it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// List every <agent-state> recorded on an issue
+    History {
+        /// Issue number
+        #[arg(short, long)]
+        issue: u64,
+
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Serialize)]
+struct HistoryEntry {
+    author: String,
+    created_at: String,
+    state: AgentState,
+}
+
+pub async fn execute(
+    args: StateArgs,
+    github: &impl GitHubPort,
+    system: &impl SystemPort,
+) -> Result<()> {
+    let output = system.run_command_output("git", &["remote", "get-url", "origin"].map(|s| s.to_string())).await?;
+    let (owner, repo) = parse_repo_from_url(&output)?;
+
+    match args.command {
+        StateCommands::Read { issue, json } => {
+            let history = load_history(github, &owner, &repo, issue).await?;
+            match history.last() {
+                Some(entry) if json => println!("{}", serde_json::to_string_pretty(&entry.state)?),
+                Some(entry) => {
+                    println!("{}", style(format!("🧠 Agent state for #{} ({} at {})", issue, entry.author, entry.created_at)).bold());
+                    print_state(&entry.state);
+                }
+                None if json => println!("{{}}"),
+                None => println!("{} No <agent-state> found on #{}", style("ℹ").blue(), issue),
+            }
+        }
+        StateCommands::Write { issue, intent, step, progress, plan, input_request, memory, next_action, fresh, dry_run } => {
+            let mut state = if fresh {
+                AgentState::default()
+            } else {
+                load_history(github, &owner, &repo, issue).await?.pop().map(|e| e.state).unwrap_or_default()
+            };
+
+            if intent.is_some() { state.intent = intent; }
+            if step.is_some() { state.step = step; }
+            if progress.is_some() { state.progress = progress; }
+            if memory.is_some() { state.memory = memory; }
+            if next_action.is_some() { state.next_action = next_action; }
+            if let Some(question) = input_request {
+                state.input_request = Some(InputRequest { status: Some("waiting".into()), question: Some(question) });
+            }
+            if !plan.is_empty() {
+                state.plan = plan.iter().map(|p| parse_plan_item(p)).collect();
+            }
+            state.set_metric("generated_at", chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string());
+
+            let xml = state.to_xml();
+            if dry_run {
+                println!("{}", xml);
+            } else {
+                github.post_comment(&owner, &repo, issue, &xml).await?;
+                println!("{} Agent state posted to #{}", style("✅").green(), issue);
+            }
+        }
+        StateCommands::History { issue, json } => {
+            let history = load_history(github, &owner, &repo, issue).await?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&history)?);
+            } else if history.is_empty() {
+                println!("{} No <agent-state> found on #{}", style("ℹ").blue(), issue);
+            } else {
+                for entry in &history {
+                    let progress = entry.state.progress.map(|p| format!("{}%", p)).unwrap_or_else(|| "-".into());
+                    println!("{} {} {} [{}] {}",
+                        style(&entry.created_at).dim(),
+                        style(&entry.author).cyan(),
+                        entry.state.step.as_deref().unwrap_or("-"),
+                        progress,
+                        entry.state.next_action.as_deref().map(|a| format!("→ {}", a)).unwrap_or_default(),
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn load_history(github: &impl GitHubPort, owner: &str, repo: &str, issue: u64) -> Result<Vec<HistoryEntry>> {
+    let comments = github.list_comments(owner, repo, issue).await?;
+    Ok(comments
+        .into_iter()
+        .filter_map(|c| {
+            AgentState::latest([c.body.as_str()]).map(|state| HistoryEntry { author: c.author, created_at: c.created_at, state })
+        })
+        .collect())
+}
+
+fn parse_plan_item(raw: &str) -> PlanItem {
+    match raw.split_once(':') {
+        Some((status, text)) if matches!(status.trim(), "done" | "in_progress" | "pending" | "blocked") => {
+            PlanItem { status: status.trim().to_string(), text: text.trim().to_string() }
+        }
+        _ => PlanItem { status: "pending".to_string(), text: raw.trim().to_string() },
+    }
+}
+
+fn print_state(state: &AgentState) {
+    let field = |name: &str, value: Option<&str>| {
+        if let Some(v) = value {
+            println!("   {:<12} {}", style(name).dim(), v);
+        }
+    };
+    field("intent", state.intent.as_deref());
+    field("step", state.step.as_deref());
+    field("progress", state.progress.map(|p| format!("{}%", p)).as_deref());
+    field("next_action", state.next_action.as_deref());
+
+    if !state.plan.is_empty() {
+        println!("   {}", style("plan").dim());
+        for item in &state.plan {
+            let mark = match item.status.as_str() {
+                "done" => style("✓").green(),
+                "in_progress" => style("▶").yellow(),
+                _ => style("·").dim(),
+            };
+            println!("     {} {}", mark, item.text);
+        }
+    }
+    if let Some(req) = &state.input_request {
+        println!("   {:<12} {} {}", style("input").dim(), req.question.as_deref().unwrap_or(""),
+            style(format!("({})", req.status.as_deref().unwrap_or("waiting"))).yellow());
+    }
+    if let Some(memory) = &state.memory {
+        println!("   {:<12} {}", style("memory").dim(), memory);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::{MockGitHubPort, MockSystemPort};
+    use mockall::predicate::*;
+
+    fn comment(body: &str) -> gc_core::Comment {
+        gc_core::Comment { id: 1, author: "agent".into(), body: body.into(), created_at: "2025-01-01T00:00:00Z".into() }
+    }
+
+    fn repo_system() -> MockSystemPort {
+        let mut mock_system = MockSystemPort::new();
+        mock_system.expect_run_command_output()
+            .with(eq("git"), always())
+            .returning(|_, _| Ok("https://github.com/owner/repo.git\n".to_string()));
+        mock_system
+    }
+
+    #[tokio::test]
+    async fn test_write_merges_with_latest_state() {
+        let mut mock_github = MockGitHubPort::new();
+        mock_github.expect_list_comments()
+            .with(eq("owner"), eq("repo"), eq(5))
+            .returning(|_, _, _| Ok(vec![
+                comment("<agent-state><intent>fix_bug</intent><step>planning</step></agent-state>"),
+                comment("LGTM"),
+            ]));
+        mock_github.expect_post_comment()
+            .with(eq("owner"), eq("repo"), eq(5), function(|body: &str| {
+                let state = AgentState::parse(body).unwrap();
+                state.intent.as_deref() == Some("fix_bug")
+                    && state.step.as_deref() == Some("coding")
+                    && state.progress == Some(40)
+                    && state.plan == vec![PlanItem { status: "done".into(), text: "Reproduce".into() }]
+            }))
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let args = StateArgs { command: StateCommands::Write {
+            issue: 5, intent: None, step: Some("coding".into()), progress: Some(40),
+            plan: vec!["done: Reproduce".into()], input_request: None, memory: None,
+            next_action: None, fresh: false, dry_run: false,
+        }};
+        assert!(execute(args, &mock_github, &repo_system()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_history_reads_comments() {
+        let mut mock_github = MockGitHubPort::new();
+        mock_github.expect_list_comments()
+            .returning(|_, _, _| Ok(vec![comment("<agent-state><step>coding</step></agent-state>")]));
+
+        let args = StateArgs { command: StateCommands::History { issue: 5, json: true } };
+        assert!(execute(args, &mock_github, &repo_system()).await.is_ok());
+    }
+}
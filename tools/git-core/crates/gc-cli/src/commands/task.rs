@@ -1,6 +1,8 @@
 use clap::Args;
 use gc_core::ports::{FileSystemPort, SystemPort, GitHubPort};
-use serde::{Serialize, Deserialize};
+use gc_core::frontmatter::IssueFrontmatter;
+use serde::Serialize;
+use gc_core::naming::{detect_type, issue_file_name, type_label};
 use slug::slugify;
 use console::style;
 
@@ -27,13 +29,6 @@ struct TaskOutput {
     web_url: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct TaskFrontmatter {
-    title: String,
-    labels: Vec<String>,
-    assignees: Vec<String>,
-}
-
 pub async fn execute(
     args: TaskArgs,
     fs: &impl FileSystemPort,
@@ -49,7 +44,7 @@ pub async fn execute(
     let slug = slugify(&args.title);
 
     // 2. Generate Filename
-    let filename = format!("{}_{}.md", task_type.to_uppercase(), slug);
+    let filename = issue_file_name(&task_type, &args.title);
     let filepath = format!(".github/issues/{}", filename);
 
 
@@ -74,10 +69,11 @@ pub async fn execute(
             println!("{} Creating issue file: {}", style("📝").green(), filepath);
         }
 
-        let frontmatter = TaskFrontmatter {
+        let frontmatter = IssueFrontmatter {
             title: args.title.clone(),
-            labels: vec![determine_label(&task_type)],
+            labels: vec![type_label(&task_type)],
             assignees: vec![], // Empty for now, user can assign later
+            ..Default::default()
         };
 
         let yaml = serde_yaml::to_string(&frontmatter).unwrap();
@@ -154,33 +150,6 @@ pub async fn execute(
     Ok(())
 }
 
-fn detect_type(title: &str) -> String {
-    let lower = title.to_lowercase();
-    if lower.contains("fix") || lower.contains("bug") || lower.contains("error") {
-        "BUG".to_string()
-    } else if lower.contains("feat") || lower.contains("add") || lower.contains("new") {
-        "FEAT".to_string()
-    } else if lower.contains("docs") || lower.contains("readme") {
-        "DOCS".to_string()
-    } else if lower.contains("refactor") {
-        "REFACTOR".to_string()
-    } else if lower.contains("test") {
-        "TEST".to_string()
-    } else {
-        "TASK".to_string()
-    }
-}
-
-fn determine_label(type_: &str) -> String {
-    match type_ {
-        "BUG" => "bug".to_string(),
-        "FEAT" => "enhancement".to_string(),
-        "DOCS" => "documentation".to_string(),
-        "REFACTOR" => "refactor".to_string(),
-        _ => "task".to_string(),
-    }
-}
-
 fn detect_role(title: &str) -> Option<&str> {
     let lower = title.to_lowercase();
     if lower.contains("security") || lower.contains("auth") || lower.contains("login") {
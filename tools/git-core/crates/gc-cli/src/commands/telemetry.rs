@@ -1,5 +1,6 @@
 use clap::Args;
 use gc_core::ports::{SystemPort, Result, CoreError};
+use gc_core::agent_state::contains_agent_state;
 use serde::{Serialize, Deserialize};
 
 use chrono::Datelike;
@@ -226,14 +227,15 @@ async fn collect_order2(system: &impl SystemPort) -> Result<Order2Metrics> {
     let mut agent_state_count = 0;
     for issue in &issues {
         if let Some(num) = issue["number"].as_u64() {
-             let args_view = ["issue".to_string(), "view".to_string(), num.to_string(), "--json".to_string(), "body".to_string()];
-             let args_view_vec = args_view.iter().map(|s| s.to_string()).collect::<Vec<_>>();
-             let body_json = system.run_command_output("gh", &args_view_vec).await?;
-             let body_obj: serde_json::Value = serde_json::from_str(&body_json).unwrap_or_default();
-             if let Some(body) = body_obj["body"].as_str() {
-                 if body.contains("<agent-state>") {
-                     agent_state_count += 1;
-                 }
+             let args_view = ["issue".to_string(), "view".to_string(), num.to_string(), "--json".to_string(), "body,comments".to_string()];
+             let view_json = system.run_command_output("gh", &args_view).await?;
+             let view: serde_json::Value = serde_json::from_str(&view_json).unwrap_or_default();
+             let body = view["body"].as_str().unwrap_or("");
+             let comments = view["comments"].as_array().cloned().unwrap_or_default();
+             let has_state = contains_agent_state(body)
+                 || comments.iter().any(|c| contains_agent_state(c["body"].as_str().unwrap_or("")));
+             if has_state {
+                 agent_state_count += 1;
              }
         }
     }
@@ -2,19 +2,156 @@ use clap::Args;
 use color_eyre::Result;
 use gc_core::ports::{SystemPort, FileSystemPort, GitHubPort};
 use console::style;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::io::Cursor;
 use std::path::Path;
 use zip::ZipArchive;
 
+pub(crate) const UPSTREAM_OWNER: &str = "iberi22";
+pub(crate) const UPSTREAM_REPO: &str = "Git-Core-Protocol";
+const VERSION_FILE: &str = ".git-core-protocol-version";
+/// Hashes of the files the last update installed, the base for the next three-way merge
+const MANIFEST_FILE: &str = ".ai-core/protocol-manifest.json";
+const BACKUP_DIR: &str = ".ai-core/backups";
+/// Names the snapshot directory `gc update --rollback` restores
+const LATEST_BACKUP: &str = ".ai-core/backups/latest";
+
 #[derive(Args, Debug)]
 pub struct UpdateArgs {
-    /// Force update (overwrites ARCHITECTURE.md)
+    /// Overwrite locally modified files instead of merging (your version is kept as <file>.orig)
     #[arg(long)]
     pub force: bool,
 
     /// Non-interactive mode
     #[arg(short, long)]
     pub auto: bool,
+
+    /// Install this release tag instead of the channel's latest (e.g. v3.5.0)
+    #[arg(long = "version", value_name = "TAG")]
+    pub version: Option<String>,
+
+    /// Where updates come from: the latest release (stable) or the main branch
+    #[arg(long, default_value = "stable", value_parser = ["stable", "main"])]
+    pub channel: String,
+
+    /// Allow installing an older version than the one installed
+    #[arg(long)]
+    pub allow_downgrade: bool,
+
+    /// Install archives that have no published checksum (the main channel, old releases)
+    #[arg(long)]
+    pub allow_unverified: bool,
+
+    /// Only report whether an update is available
+    #[arg(long)]
+    pub check: bool,
+
+    /// Output in JSON format (with --check)
+    #[arg(long)]
+    pub json: bool,
+
+    /// Show what would change in each file without writing anything
+    #[arg(short = 'n', long)]
+    pub dry_run: bool,
+
+    /// Restore the files and version saved before the last update
+    #[arg(long, conflicts_with_all = ["check", "dry_run", "version", "force"])]
+    pub rollback: bool,
+}
+
+/// The version an update would install and where its archive comes from.
+#[derive(Debug, PartialEq)]
+struct Target {
+    version: String,
+    /// Release tag, `None` on the main channel
+    tag: Option<String>,
+    archive_url: String,
+    /// Published sha256 of the archive
+    checksum_url: Option<String>,
+}
+
+#[derive(Serialize)]
+struct CheckOutput {
+    installed: Option<String>,
+    latest: String,
+    channel: String,
+    tag: Option<String>,
+    update_available: bool,
+    /// The target is older than what is installed (only with --version)
+    downgrade: bool,
+    /// A checksum is published, so the archive can be verified
+    verifiable: bool,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug)]
+struct Manifest {
+    version: String,
+    /// Upstream commit the files were taken from
+    #[serde(default)]
+    commit: Option<String>,
+    /// Path → sha256 of the content as installed
+    files: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Action {
+    Added,
+    Updated,
+    /// Local edits and upstream changes combined cleanly
+    Merged,
+    /// Only the local copy changed, or the user deleted the file
+    Kept,
+    /// Written with conflict markers (or overwritten); the local copy is in `<file>.orig`
+    Conflicted,
+    Unchanged,
+}
+
+impl Action {
+    fn label(self) -> &'static str {
+        match self {
+            Action::Added => "added",
+            Action::Updated => "updated",
+            Action::Merged => "merged",
+            Action::Kept => "kept",
+            Action::Conflicted => "conflicted",
+            Action::Unchanged => "unchanged",
+        }
+    }
+}
+
+/// Files an update touched, saved under `.ai-core/backups/<version>/` before it wrote them.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Snapshot {
+    /// Version the snapshot restores, `None` when the protocol was not installed
+    version: Option<String>,
+    /// Version installed over it
+    replaced_by: String,
+    created_at: String,
+    /// Path → whether it existed; files the update created are removed on rollback
+    files: BTreeMap<String, bool>,
+}
+
+/// What an update does to one file.
+#[derive(Debug, PartialEq)]
+struct FileChange {
+    path: String,
+    action: Action,
+    /// Local content before the update, `None` if the file does not exist
+    current: Option<String>,
+    /// New content of `path`, if it is written
+    write: Option<String>,
+    /// Local content saved to `<path>.orig` before writing
+    orig: Option<String>,
+}
+
+/// Where the previous install's files can be fetched from, for the merge base.
+struct Base<'a> {
+    manifest: Option<&'a Manifest>,
+    /// Commit, or the `v<version>` tag when there is no manifest
+    git_ref: Option<String>,
 }
 
 pub async fn execute(
@@ -23,120 +160,641 @@ pub async fn execute(
     _system: &impl SystemPort, // SystemPort not strictly needed for native download if we use reqwest directly
     github: &impl GitHubPort,
 ) -> Result<()> {
-    println!("{}", style("🔄 Upgrading Git-Core Protocol...").cyan());
+    if args.rollback {
+        return rollback(fs).await;
+    }
 
     // 1. Version Check
-    let version_file = ".git-core-protocol-version";
-    let local_version = if fs.exists(version_file).await.unwrap_or(false) {
-        fs.read_file(version_file).await.unwrap_or_else(|_| "0.0.0".to_string()).trim().to_string()
-    } else {
-        "0.0.0".to_string()
+    let local_version = match fs.read_file(VERSION_FILE).await {
+        Ok(version) if !version.trim().is_empty() => Some(version.trim().to_string()),
+        _ => None,
     };
+    let target = resolve_target(&args, github).await?;
+    let ordering = compare_versions(local_version.as_deref(), &target.version);
 
-    let latest_version = github.get_file_content(
-        "iberi22",
-        "Git-Core-Protocol",
-        "main",
-        ".git-core-protocol-version"
-    ).await.unwrap_or_else(|_| "unknown".to_string()).trim().to_string();
-
-    if local_version == latest_version && !args.force {
-        println!("{} Protocol is already at version {} (latest).", style("✅").green(), local_version);
-        println!("   Use --force if you want to reinstall anyway.");
+    if args.check {
+        let output = CheckOutput {
+            installed: local_version,
+            latest: target.version,
+            channel: args.channel,
+            tag: target.tag,
+            update_available: ordering == Ordering::Less,
+            downgrade: ordering == Ordering::Greater,
+            verifiable: target.checksum_url.is_some(),
+        };
+        if args.json {
+            println!("{}", serde_json::to_string(&output)?);
+        } else if output.update_available {
+            println!("{} Update available: {} → {} ({})", style("ℹ").blue(), output.installed.as_deref().unwrap_or("none"), output.latest, output.channel);
+            println!("   Run 'gc update' to upgrade.");
+        } else {
+            println!("{} Protocol is up to date ({}).", style("✅").green(), output.installed.as_deref().unwrap_or("none"));
+        }
         return Ok(());
     }
 
-    if latest_version != "unknown" {
-        println!("{} Update available: {} → {}", style("ℹ").blue(), local_version, latest_version);
+    println!("{}", style("🔄 Upgrading Git-Core Protocol...").cyan());
+    let local_label = local_version.as_deref().unwrap_or("none");
+    match ordering {
+        Ordering::Equal if !args.force => {
+            println!("{} Protocol is already at version {} (latest).", style("✅").green(), target.version);
+            println!("   Use --force if you want to reinstall anyway.");
+            return Ok(());
+        }
+        Ordering::Greater if !args.allow_downgrade => {
+            color_eyre::eyre::bail!("{} is older than the installed {}; pass --allow-downgrade to install it anyway", target.version, local_label);
+        }
+        Ordering::Greater => println!("{} Downgrading: {} → {}", style("⚠️").yellow(), local_label, target.version),
+        _ => println!("{} Update available: {} → {}", style("ℹ").blue(), local_label, target.version),
     }
 
     if args.force {
-        println!("{}", style("⚠️  Force mode enabled: Files will be overwritten.").red());
+        println!("{}", style("⚠️  Force mode enabled: modified files will be overwritten (kept as .orig).").red());
+    }
+    if target.checksum_url.is_none() && !args.allow_unverified {
+        color_eyre::eyre::bail!("{} has no published checksum; pass --allow-unverified to install it anyway", target.archive_url);
     }
 
-    // 2. Download Zip
-    let zip_url = "https://github.com/iberi22/Git-Core-Protocol/archive/refs/heads/main.zip";
-    println!("{}", style(format!("📥 Downloading protocol from {}...", zip_url)).yellow());
-
-    // Use async reqwest to avoid blocking the runtime
-    let response = reqwest::get(zip_url).await?;
-    let bytes = response.bytes().await?;
+    // 2. Download Zip, verified before anything is extracted
+    println!("{}", style(format!("📥 Downloading protocol from {}...", target.archive_url)).yellow());
+    let bytes = download(&target.archive_url).await?;
+    match &target.checksum_url {
+        Some(url) => {
+            let published = String::from_utf8_lossy(&download(url).await?).to_string();
+            verify_checksum(&bytes, &published)?;
+            println!("{}", style("✓ Checksum verified").green());
+        }
+        None => println!("{}", style("⚠️  No published checksum; archive not verified.").yellow()),
+    }
     let reader = Cursor::new(bytes);
 
     let mut archive = ZipArchive::new(reader)?;
+    // GitHub stores the archived commit as the zip comment
+    let commit = std::str::from_utf8(archive.comment()).ok()
+        .map(str::trim)
+        .filter(|c| c.len() == 40 && c.chars().all(|ch| ch.is_ascii_hexdigit()))
+        .map(str::to_string);
 
     println!("{}", style("📦 Extracting files...").yellow());
+    let upstream = protocol_files(&mut archive)?;
 
-    // 3. Extract specific folders
-    // Archive structure: Git-Core-Protocol-main/FOLDER/...
-    // We want to map `Git-Core-Protocol-main/FOLDER` -> `./FOLDER`
+    // 3. Merge each file against what the last update installed
+    let manifest = load_manifest(fs).await;
+    let base = Base {
+        git_ref: match &manifest {
+            Some(m) => m.commit.clone(),
+            None => local_version.as_ref().map(|v| format!("v{}", v.trim_start_matches('v'))),
+        },
+        manifest: manifest.as_ref(),
+    };
 
-    for i in 0..archive.len() {
-        let mut file = archive.by_index(i)?;
-        let name = file.name().to_string(); // e.g., Git-Core-Protocol-main/.github/workflows/main.yml
+    let mut changes = Vec::new();
+    for (path, content) in &upstream {
+        changes.push(plan_file(fs, github, &base, path, content, args.force).await);
+    }
+    if args.dry_run {
+        print_summary(&changes);
+        println!("\n{} Dry run: nothing was written.", style("ℹ️").cyan());
+        return Ok(());
+    }
+
+    let backup = snapshot(fs, local_version.as_deref(), &target.version, &changes).await?;
+    println!("{}", style(format!("💾 Saved the files it replaces to {} (undo with 'gc update --rollback')", backup)).dim());
+    for change in &changes {
+        apply(fs, change).await?;
+    }
+
+    // 4. Record what was installed, then the version
+    let new_manifest = Manifest {
+        version: target.version.clone(),
+        commit,
+        files: upstream.iter().map(|(path, content)| (path.clone(), hash(content))).collect(),
+    };
+    fs.write_file(MANIFEST_FILE, &serde_json::to_string_pretty(&new_manifest)?).await?;
+    fs.write_file(VERSION_FILE, &target.version).await?;
+    println!("{}", style(format!("✓ Updated .git-core-protocol-version to {}", target.version)).green());
+
+    print_summary(&changes);
+    if changes.iter().any(|c| c.action == Action::Conflicted) {
+        println!("\n{}", style("⚠️  Resolve the conflicted files (your previous versions are in *.orig), then delete the .orig files.").yellow());
+    } else {
+        println!("\n{}", style("✅ Protocol upgraded successfully (Native).").green());
+    }
+    Ok(())
+}
+
+/// Resolves `--version`/`--channel` to a version and archive. Releases carry
+/// `git-core-protocol-<tag>.zip` and its `.sha256` (see .github/workflows/release.yml).
+async fn resolve_target(args: &UpdateArgs, github: &impl GitHubPort) -> Result<Target> {
+    if args.version.is_none() && args.channel == "main" {
+        let version = github.get_file_content(UPSTREAM_OWNER, UPSTREAM_REPO, "main", VERSION_FILE).await?;
+        return Ok(Target {
+            version: version.trim().to_string(),
+            tag: None,
+            archive_url: format!("https://github.com/{}/{}/archive/refs/heads/main.zip", UPSTREAM_OWNER, UPSTREAM_REPO),
+            checksum_url: None,
+        });
+    }
+
+    let tag = args.version.as_ref().map(|v| format!("v{}", v.trim_start_matches('v')));
+    let release = github.get_release(UPSTREAM_OWNER, UPSTREAM_REPO, tag).await?;
+    let archive_name = format!("git-core-protocol-{}.zip", release.tag_name);
+    let asset_url = |name: &str| release.assets.iter().find(|a| a.name == name).map(|a| a.download_url.clone());
+
+    // Releases from before the archive was published only have GitHub's source zip
+    let (archive_url, checksum_url) = match asset_url(&archive_name) {
+        Some(url) => (url, asset_url(&format!("{}.sha256", archive_name))),
+        None => (format!("https://github.com/{}/{}/archive/refs/tags/{}.zip", UPSTREAM_OWNER, UPSTREAM_REPO, release.tag_name), None),
+    };
+    Ok(Target {
+        version: release.tag_name.trim_start_matches('v').to_string(),
+        tag: Some(release.tag_name),
+        archive_url,
+        checksum_url,
+    })
+}
+
+/// How the installed version compares to `target`; `Less` means an upgrade. Versions that
+/// are not semver only compare equal or not, and a missing install is always older.
+pub(crate) fn compare_versions(installed: Option<&str>, target: &str) -> Ordering {
+    let Some(installed) = installed else { return Ordering::Less };
+    let parse = |v: &str| semver::Version::parse(v.trim().trim_start_matches('v')).ok();
+    match (parse(installed), parse(target)) {
+        (Some(installed), Some(target)) => installed.cmp(&target),
+        _ if installed.trim_start_matches('v') == target.trim_start_matches('v') => Ordering::Equal,
+        _ => Ordering::Less,
+    }
+}
 
-        // Basic filtering to extract only relevant parts
-        // Remove top level folder
-        let parts: Vec<&str> = name.splitn(2, '/').collect();
-        if parts.len() < 2 { continue; }
+pub(crate) async fn download(url: &str) -> Result<Vec<u8>> {
+    // Use async reqwest to avoid blocking the runtime
+    let response = reqwest::get(url).await?.error_for_status()?;
+    Ok(response.bytes().await?.to_vec())
+}
 
-        let relative_path = parts[1]; // .github/workflows/main.yml
-        if relative_path.is_empty() { continue; }
+/// Checks `bytes` against a `sha256sum`-style line (`<hex>  <file>`).
+pub(crate) fn verify_checksum(bytes: &[u8], published: &str) -> Result<()> {
+    let expected = published.split_whitespace().next().unwrap_or_default().to_lowercase();
+    let actual = hex::encode(Sha256::digest(bytes));
+    if expected != actual {
+        color_eyre::eyre::bail!("Checksum mismatch: expected {}, got {}; the archive was not installed", expected, actual);
+    }
+    Ok(())
+}
+
+/// Text files under the synced paths, keyed by their path in the project.
+fn protocol_files<R: std::io::Read + std::io::Seek>(archive: &mut ZipArchive<R>) -> Result<BTreeMap<String, String>> {
+    let mut files = BTreeMap::new();
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i)?;
+        if file.is_dir() {
+            continue;
+        }
+        // The main channel is not checksum-verified: no entry may point outside the project
+        let escapes = || color_eyre::eyre::eyre!("Archive entry {} points outside the project; refusing to update", file.name());
+        let path = file.enclosed_name().ok_or_else(escapes)?;
+        let mut parts = Vec::new();
+        for component in path.components() {
+            match component {
+                std::path::Component::Normal(part) => parts.push(part.to_string_lossy().into_owned()),
+                _ => return Err(escapes()),
+            }
+        }
+        // Archive structure: Git-Core-Protocol-main/FOLDER/... → ./FOLDER/...
+        if parts.len() < 2 {
+            continue;
+        }
+        let relative_path = parts[1..].join("/");
+        let relative_path = relative_path.as_str();
 
         // Whitelist of paths to sync
-        if relative_path.starts_with(".github/") ||
+        if !(relative_path.starts_with(".github/") ||
            relative_path.starts_with("scripts/") ||
            relative_path.starts_with("docs/") ||
-           relative_path == "AGENTS.md" {
-
-            if file.is_dir() {
-                // Directories created implicitly by fs port usually or we need explicit create
-                // fs trait has create_dir.
-                // BUT fs trait is async and we are inside loop.
-                // NOTE: Our FileSystemPort trait abstraction might be too simple for bulk operations if we want strict mocking.
-                // However, for update logic, using std::fs directly might be pragmatic if we assume standard env,
-                // BUT we want to support mocking for tests.
-                // We'll trust fs.create_dir handles "mkdir -p".
-                // Our TokioFileSystem uses fs::create_dir_all.
-
-                // Let's continue, we create dirs when we see files or explicitly.
-                // TokioFileSystem::create_dir wraps fs::create_dir_all, so passing "path/to/dir" works.
-                let _ = fs.create_dir(relative_path).await;
-            } else {
-                // It's a file
-                // Ensure parent dir exists
-                if let Some(parent) = Path::new(relative_path).parent() {
-                     if let Some(p_str) = parent.to_str() {
-                         if !p_str.is_empty() {
-                             let _ = fs.create_dir(p_str).await;
-                         }
-                     }
-                }
-
-                // Read content
-                // Zip file reader implements Read.
-                // Our fs.write_file takes &str (string content).
-                // This assumes text files. Most protocol files are text (md, yaml, sh, ps1).
-                // If we have binaries this will fail/corrupt.
-                // Git-Core Protocol currently contains text.
-
-                // Safety check: is it a text file?
-                // For now we assume yes.
-                let mut content = String::new();
-                if std::io::Read::read_to_string(&mut file, &mut content).is_ok() {
-                     fs.write_file(relative_path, &content).await?;
-                     // println!("  extracted: {}", relative_path);
-                } else {
-                    println!("{}", style(format!("  ⚠️ Skipped binary or non-utf8 file: {}", relative_path)).dim());
-                }
-            }
+           relative_path == "AGENTS.md") {
+            continue;
+        }
+
+        // Protocol files are text (md, yaml, sh, ps1); anything else cannot go through the fs port
+        let mut content = String::new();
+        if std::io::Read::read_to_string(&mut file, &mut content).is_ok() {
+            files.insert(relative_path.to_string(), content);
+        } else {
+            println!("{}", style(format!("  ⚠️ Skipped binary or non-utf8 file: {}", relative_path)).dim());
         }
     }
+    Ok(files)
+}
 
-    // 4. Update version file
-    fs.write_file(version_file, &latest_version).await?;
-    println!("{}", style(format!("✓ Updated .git-core-protocol-version to {}", latest_version)).green());
+async fn load_manifest(fs: &impl FileSystemPort) -> Option<Manifest> {
+    let content = fs.read_file(MANIFEST_FILE).await.ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn hash(content: &str) -> String {
+    hex::encode(Sha256::digest(content.as_bytes()))
+}
 
-    println!("\n{}", style("✅ Protocol upgraded successfully (Native).").green());
+/// Decides how one upstream file lands, merging with local edits when both sides changed.
+async fn plan_file(
+    fs: &impl FileSystemPort,
+    github: &impl GitHubPort,
+    base: &Base<'_>,
+    path: &str,
+    upstream: &str,
+    force: bool,
+) -> FileChange {
+    let local = fs.read_file(path).await.ok();
+    let change = |action, write: Option<&str>, orig: Option<&String>| FileChange {
+        path: path.to_string(),
+        action,
+        current: local.clone(),
+        write: write.map(str::to_string),
+        orig: orig.cloned(),
+    };
+    let installed = base.manifest.and_then(|m| m.files.get(path));
+
+    let Some(current) = &local else {
+        // Deleted since the last update: respect that unless forced
+        if installed.is_some() && !force {
+            return change(Action::Kept, None, None);
+        }
+        return change(Action::Added, Some(upstream), None);
+    };
+    if current == upstream {
+        return change(Action::Unchanged, None, None);
+    }
+
+    if installed == Some(&hash(current)) {
+        return change(Action::Updated, Some(upstream), None);
+    }
+    if installed == Some(&hash(upstream)) {
+        return change(Action::Kept, None, None);
+    }
+    if force {
+        return change(Action::Updated, Some(upstream), Some(current));
+    }
+
+    let ancestor = match &base.git_ref {
+        Some(git_ref) => github.get_file_content(UPSTREAM_OWNER, UPSTREAM_REPO, git_ref, path).await.ok(),
+        None => None,
+    };
+    match ancestor {
+        // No manifest yet, but the file matches the previous release: it was never edited
+        Some(ancestor) if &ancestor == current => change(Action::Updated, Some(upstream), None),
+        Some(ancestor) if ancestor == upstream => change(Action::Kept, None, None),
+        Some(ancestor) => match diffy::merge(&ancestor, current, upstream) {
+            Ok(merged) => change(Action::Merged, Some(&merged), None),
+            Err(with_markers) => change(Action::Conflicted, Some(&with_markers), Some(current)),
+        },
+        // Without a base there is nothing to merge against: take upstream, keep the edits aside
+        None => change(Action::Conflicted, Some(upstream), Some(current)),
+    }
+}
+
+async fn apply(fs: &impl FileSystemPort, change: &FileChange) -> Result<()> {
+    let Some(content) = &change.write else { return Ok(()) };
+    if let Some(orig) = &change.orig {
+        write_file(fs, &format!("{}.orig", change.path), orig).await?;
+    }
+    write_file(fs, &change.path, content).await
+}
+
+async fn write_file(fs: &impl FileSystemPort, path: &str, content: &str) -> Result<()> {
+    if let Some(parent) = Path::new(path).parent().and_then(Path::to_str) {
+        if !parent.is_empty() {
+            // TokioFileSystem::create_dir wraps fs::create_dir_all
+            fs.create_dir(parent).await?;
+        }
+    }
+    fs.write_file(path, content).await?;
+    Ok(())
+}
+
+/// Copies every file the update is about to write, plus the version and manifest, to
+/// `.ai-core/backups/<installed version>/` and points `latest` at it. Returns the directory.
+async fn snapshot(fs: &impl FileSystemPort, local_version: Option<&str>, target: &str, changes: &[FileChange]) -> Result<String> {
+    let dir = format!("{}/{}", BACKUP_DIR, local_version.unwrap_or("none"));
+    let mut touched = vec![VERSION_FILE.to_string(), MANIFEST_FILE.to_string()];
+    for change in changes.iter().filter(|c| c.write.is_some()) {
+        touched.push(change.path.clone());
+        if change.orig.is_some() {
+            touched.push(format!("{}.orig", change.path));
+        }
+    }
+
+    let mut files = BTreeMap::new();
+    for path in touched {
+        let existed = fs.exists(&path).await?;
+        if existed {
+            // A file that cannot be saved must not be overwritten
+            let content = fs.read_file(&path).await
+                .map_err(|e| color_eyre::eyre::eyre!("Cannot back up {}: {}", path, e))?;
+            write_file(fs, &format!("{}/{}", dir, path), &content).await?;
+        }
+        files.insert(path, existed);
+    }
+
+    let snapshot = Snapshot {
+        version: local_version.map(str::to_string),
+        replaced_by: target.to_string(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        files,
+    };
+    fs.write_file(&format!("{}/snapshot.json", dir), &serde_json::to_string_pretty(&snapshot)?).await?;
+    fs.write_file(LATEST_BACKUP, &dir).await?;
+    Ok(dir)
+}
+
+/// Restores the latest snapshot: saved files are written back, files the update created are removed.
+async fn rollback(fs: &impl FileSystemPort) -> Result<()> {
+    let dir = match fs.read_file(LATEST_BACKUP).await {
+        Ok(dir) => dir.trim().to_string(),
+        Err(_) => color_eyre::eyre::bail!("No update to roll back ({} not found)", LATEST_BACKUP),
+    };
+    let snapshot: Snapshot = serde_json::from_str(&fs.read_file(&format!("{}/snapshot.json", dir)).await?)?;
+    println!("{}", style(format!("⏪ Rolling back {} → {}...", snapshot.replaced_by, snapshot.version.as_deref().unwrap_or("none"))).cyan());
+
+    for (path, existed) in &snapshot.files {
+        if *existed {
+            let content = fs.read_file(&format!("{}/{}", dir, path)).await?;
+            write_file(fs, path, &content).await?;
+            println!("   {} {}", style(format!("{:<10}", "restored")).green(), path);
+        } else if fs.exists(path).await? {
+            fs.remove_file(path).await?;
+            println!("   {} {}", style(format!("{:<10}", "removed")).red(), path);
+        }
+    }
+    // The snapshot stays on disk, but it cannot be rolled back twice
+    fs.remove_file(LATEST_BACKUP).await?;
+
+    println!("\n{} Rolled back to {}.", style("✅").green(), snapshot.version.as_deref().unwrap_or("no protocol"));
     Ok(())
 }
+
+fn print_summary(changes: &[FileChange]) {
+    println!();
+    for change in changes.iter().filter(|c| c.action != Action::Unchanged) {
+        let label = format!("{:<10}", change.action.label());
+        let label = match change.action {
+            Action::Added | Action::Updated | Action::Merged => style(label).green(),
+            Action::Kept => style(label).cyan(),
+            _ => style(label).red(),
+        };
+        let stats = match &change.write {
+            Some(new) => {
+                let patch = diffy::create_patch(change.current.as_deref().unwrap_or(""), new);
+                let lines = patch.hunks().iter().flat_map(|h| h.lines());
+                let (added, removed) = lines.fold((0, 0), |(a, r), line| match line {
+                    diffy::Line::Insert(_) => (a + 1, r),
+                    diffy::Line::Delete(_) => (a, r + 1),
+                    diffy::Line::Context(_) => (a, r),
+                });
+                format!(" (+{} -{})", added, removed)
+            }
+            None => String::new(),
+        };
+        let orig = if change.orig.is_some() { ", local copy → .orig" } else { "" };
+        println!("   {} {}{}", label, change.path, style(format!("{}{}", stats, orig)).dim());
+    }
+
+    let count = |action| changes.iter().filter(|c| c.action == action).count();
+    println!(
+        "\n{} {} added, {} updated, {} merged, {} kept, {} conflicted, {} unchanged",
+        style("📊").cyan(),
+        count(Action::Added),
+        count(Action::Updated),
+        count(Action::Merged),
+        count(Action::Kept),
+        count(Action::Conflicted),
+        count(Action::Unchanged),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::{MockFileSystemPort, MockGitHubPort};
+    use gc_core::ports::CoreError;
+
+    fn local_fs(content: Option<&'static str>) -> MockFileSystemPort {
+        let mut fs = MockFileSystemPort::new();
+        fs.expect_read_file().returning(move |_| content.map(str::to_string).ok_or_else(|| CoreError::System("missing".into())));
+        fs
+    }
+
+    fn manifest(path: &str, content: &str) -> Manifest {
+        Manifest { version: "1.0.0".into(), commit: Some("abc".into()), files: BTreeMap::from([(path.to_string(), hash(content))]) }
+    }
+
+    fn update_args(version: Option<&str>, channel: &str) -> UpdateArgs {
+        UpdateArgs {
+            force: false,
+            auto: true,
+            version: version.map(str::to_string),
+            channel: channel.into(),
+            allow_downgrade: false,
+            allow_unverified: false,
+            check: true,
+            json: true,
+            dry_run: false,
+            rollback: false,
+        }
+    }
+
+    fn asset(name: &str) -> gc_core::ReleaseAsset {
+        gc_core::ReleaseAsset { name: name.into(), download_url: format!("https://example.test/{}", name), size: 1 }
+    }
+
+    fn zip_archive(entries: &[(&str, &str)]) -> ZipArchive<Cursor<Vec<u8>>> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in entries {
+            writer.start_file(*name, zip::write::FileOptions::default()).unwrap();
+            std::io::Write::write_all(&mut writer, content.as_bytes()).unwrap();
+        }
+        ZipArchive::new(writer.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_protocol_files_rejects_entries_outside_the_project() {
+        let mut archive = zip_archive(&[("Git-Core-Protocol-main/AGENTS.md", "# Agents"), ("Git-Core-Protocol-main/docs/guide.md", "guide"), ("Git-Core-Protocol-main/src/lib.rs", "")]);
+        let files = protocol_files(&mut archive).unwrap();
+        assert_eq!(files.keys().collect::<Vec<_>>(), vec!["AGENTS.md", "docs/guide.md"]);
+
+        for name in ["Git-Core-Protocol-main/scripts/../../x", "Git-Core-Protocol-main/scripts/../AGENTS.md", "/etc/scripts/x"] {
+            let mut archive = zip_archive(&[(name, "boom")]);
+            assert!(protocol_files(&mut archive).is_err(), "{} was accepted", name);
+        }
+    }
+
+    #[test]
+    fn test_compare_versions_uses_semver() {
+        assert_eq!(compare_versions(Some("3.9.0"), "3.10.0"), Ordering::Less);
+        assert_eq!(compare_versions(Some("v3.5.0"), "3.5.0"), Ordering::Equal);
+        assert_eq!(compare_versions(Some("3.5.0"), "3.4.2"), Ordering::Greater);
+        assert_eq!(compare_versions(Some("3.5.0-beta.1"), "3.5.0"), Ordering::Less);
+        assert_eq!(compare_versions(None, "1.0.0"), Ordering::Less);
+        assert_eq!(compare_versions(Some("nightly"), "1.0.0"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_verify_checksum() {
+        let published = format!("{}  git-core-protocol-v1.0.0.zip\n", hex::encode(Sha256::digest(b"archive")));
+        assert!(verify_checksum(b"archive", &published).is_ok());
+        assert!(verify_checksum(b"tampered", &published).is_err());
+        assert!(verify_checksum(b"archive", "").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_target_pins_release_tag() {
+        let mut github = MockGitHubPort::new();
+        github.expect_get_release()
+            .withf(|_, _, tag| tag.as_deref() == Some("v3.4.0"))
+            .returning(|_, _, _| Ok(gc_core::Release {
+                tag_name: "v3.4.0".into(),
+                prerelease: false,
+                assets: vec![asset("gc-linux-x86_64.tar.gz"), asset("git-core-protocol-v3.4.0.zip"), asset("git-core-protocol-v3.4.0.zip.sha256")],
+            }));
+        github.expect_get_release()
+            .withf(|_, _, tag| tag.is_none())
+            .returning(|_, _, _| Ok(gc_core::Release { tag_name: "v2.0.0".into(), prerelease: false, assets: vec![] }));
+
+        let target = resolve_target(&update_args(Some("3.4.0"), "stable"), &github).await.unwrap();
+        assert_eq!(target, Target {
+            version: "3.4.0".into(),
+            tag: Some("v3.4.0".into()),
+            archive_url: "https://example.test/git-core-protocol-v3.4.0.zip".into(),
+            checksum_url: Some("https://example.test/git-core-protocol-v3.4.0.zip.sha256".into()),
+        });
+
+        // Old release without the archive: source zip, nothing to verify
+        let target = resolve_target(&update_args(None, "stable"), &github).await.unwrap();
+        assert_eq!(target.archive_url, "https://github.com/iberi22/Git-Core-Protocol/archive/refs/tags/v2.0.0.zip");
+        assert_eq!(target.checksum_url, None);
+    }
+
+    #[tokio::test]
+    async fn test_pristine_files_update_and_edited_ones_are_kept() {
+        let github = MockGitHubPort::new();
+        let installed = manifest("AGENTS.md", "v1\n");
+        let base = Base { manifest: Some(&installed), git_ref: Some("abc".into()) };
+
+        let change = plan_file(&local_fs(Some("v1\n")), &github, &base, "AGENTS.md", "v2\n", false).await;
+        assert_eq!((change.action, change.write.as_deref()), (Action::Updated, Some("v2\n")));
+
+        // Upstream did not change since install: the local edit stays
+        let change = plan_file(&local_fs(Some("mine\n")), &github, &base, "AGENTS.md", "v1\n", false).await;
+        assert_eq!((change.action, change.write), (Action::Kept, None));
+
+        // Deleted locally
+        let change = plan_file(&local_fs(None), &github, &base, "AGENTS.md", "v2\n", false).await;
+        assert_eq!(change.action, Action::Kept);
+        let change = plan_file(&local_fs(None), &github, &base, "docs/new.md", "new\n", false).await;
+        assert_eq!(change.action, Action::Added);
+    }
+
+    #[tokio::test]
+    async fn test_three_way_merge_and_conflicts() {
+        let ancestor = "title\none\ntwo\nthree\n";
+        let mut github = MockGitHubPort::new();
+        github.expect_get_file_content()
+            .withf(|_, _, git_ref, path| git_ref == "abc" && path == "AGENTS.md")
+            .returning(move |_, _, _, _| Ok(ancestor.to_string()));
+        let installed = manifest("AGENTS.md", ancestor);
+        let base = Base { manifest: Some(&installed), git_ref: Some("abc".into()) };
+
+        let fs = local_fs(Some("title\none\ntwo\nthree\nmy note\n"));
+        let change = plan_file(&fs, &github, &base, "AGENTS.md", "new title\none\ntwo\nthree\n", false).await;
+        assert_eq!(change.action, Action::Merged);
+        assert_eq!(change.write.as_deref(), Some("new title\none\ntwo\nthree\nmy note\n"));
+
+        let fs = local_fs(Some("my title\none\ntwo\nthree\n"));
+        let change = plan_file(&fs, &github, &base, "AGENTS.md", "new title\none\ntwo\nthree\n", false).await;
+        assert_eq!(change.action, Action::Conflicted);
+        assert!(change.write.unwrap().contains("<<<<<<<"));
+        assert_eq!(change.orig.as_deref(), Some("my title\none\ntwo\nthree\n"));
+
+        // --force takes upstream but still saves the local copy
+        let change = plan_file(&fs, &github, &base, "AGENTS.md", "new title\n", true).await;
+        assert_eq!((change.action, change.write.as_deref()), (Action::Updated, Some("new title\n")));
+        assert!(change.orig.is_some());
+    }
+
+    /// Filesystem mock backed by a map, for round trips.
+    fn memory_fs(files: &[(&str, &str)]) -> (MockFileSystemPort, std::sync::Arc<std::sync::Mutex<BTreeMap<String, String>>>) {
+        let store = std::sync::Arc::new(std::sync::Mutex::new(
+            files.iter().map(|(p, c)| (p.to_string(), c.to_string())).collect::<BTreeMap<_, _>>(),
+        ));
+        let mut fs = MockFileSystemPort::new();
+        let s = store.clone();
+        fs.expect_read_file().returning(move |p| s.lock().unwrap().get(p).cloned().ok_or_else(|| CoreError::System(format!("{} missing", p))));
+        let s = store.clone();
+        fs.expect_exists().returning(move |p| Ok(s.lock().unwrap().contains_key(p)));
+        let s = store.clone();
+        fs.expect_write_file().returning(move |p, c| {
+            s.lock().unwrap().insert(p.to_string(), c.to_string());
+            Ok(())
+        });
+        let s = store.clone();
+        fs.expect_remove_file().returning(move |p| {
+            s.lock().unwrap().remove(p);
+            Ok(())
+        });
+        fs.expect_create_dir().returning(|_| Ok(()));
+        (fs, store)
+    }
+
+    #[tokio::test]
+    async fn test_rollback_restores_snapshot() {
+        let (fs, store) = memory_fs(&[(VERSION_FILE, "1.0.0"), ("AGENTS.md", "mine\n")]);
+        let change = |path: &str, action, current: Option<&str>, orig: Option<&str>| FileChange {
+            path: path.into(),
+            action,
+            current: current.map(str::to_string),
+            write: Some("upstream\n".into()),
+            orig: orig.map(str::to_string),
+        };
+        let changes = vec![
+            change("AGENTS.md", Action::Conflicted, Some("mine\n"), Some("mine\n")),
+            change("docs/new.md", Action::Added, None, None),
+        ];
+
+        let dir = snapshot(&fs, Some("1.0.0"), "2.0.0", &changes).await.unwrap();
+        assert_eq!(dir, ".ai-core/backups/1.0.0");
+        for c in &changes {
+            apply(&fs, c).await.unwrap();
+        }
+        fs.write_file(VERSION_FILE, "2.0.0").await.unwrap();
+        fs.write_file(MANIFEST_FILE, "{}").await.unwrap();
+
+        rollback(&fs).await.unwrap();
+        {
+            let store = store.lock().unwrap();
+            let project: Vec<(&str, &str)> = store.iter()
+                .filter(|(p, _)| !p.starts_with(BACKUP_DIR))
+                .map(|(p, c)| (p.as_str(), c.as_str()))
+                .collect();
+            assert_eq!(project, vec![(VERSION_FILE, "1.0.0"), ("AGENTS.md", "mine\n")]);
+            assert!(!store.contains_key(LATEST_BACKUP));
+        }
+        assert!(rollback(&fs).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_apply_writes_orig_before_file() {
+        let mut fs = MockFileSystemPort::new();
+        let mut seq = mockall::Sequence::new();
+        fs.expect_create_dir().withf(|p| p == ".github/workflows").returning(|_| Ok(()));
+        fs.expect_write_file().withf(|p, c| p == ".github/workflows/ci.yml.orig" && c == "mine").times(1).in_sequence(&mut seq).returning(|_, _| Ok(()));
+        fs.expect_write_file().withf(|p, _| p == ".github/workflows/ci.yml").times(1).in_sequence(&mut seq).returning(|_, _| Ok(()));
+
+        let change = FileChange {
+            path: ".github/workflows/ci.yml".into(),
+            action: Action::Conflicted,
+            current: Some("mine".into()),
+            write: Some("theirs".into()),
+            orig: Some("mine".into()),
+        };
+        apply(&fs, &change).await.unwrap();
+    }
+}
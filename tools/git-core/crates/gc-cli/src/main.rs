@@ -8,7 +8,7 @@ pub struct Cli {
 }
 
 mod commands;
-use commands::{InitArgs, ContextCmd, ReportCmd, ValidateCmd, TelemetryArgs, CiDetectArgs, TaskArgs, FinishArgs, IssueArgs, PrArgs, GitArgs, InfoArgs, CheckArgs, NextArgs, WorkflowArgs, UpdateArgs, DispatchArgs, AnalyzeArgs};
+use commands::{InitArgs, ContextCmd, ReportCmd, ValidateCmd, TelemetryArgs, CiDetectArgs, TaskArgs, FinishArgs, IssueArgs, PrArgs, GitArgs, InfoArgs, CheckArgs, NextArgs, WorkflowArgs, UpdateArgs, SelfUpdateArgs, DispatchArgs, AnalyzeArgs, PlanArgs, StateArgs, AtomizeArgs};
 
 #[derive(Subcommand)]
 pub enum Commands {
@@ -49,11 +49,19 @@ pub enum Commands {
     Next(NextArgs),
     /// Upgrade Protocol in current project
     Update(UpdateArgs),
+    /// Update the gc Binary Itself
+    SelfUpdate(SelfUpdateArgs),
     /// Dispatch task to Agent (Jules, Copilot)
     /// Dispatch task to Agent (Jules, Copilot)
     Dispatch(DispatchArgs),
     /// Analyze Architecture & Generate Prompt
     Analyze(AnalyzeArgs),
+    /// Show Issue Dependency Graph & Work Order
+    Plan(PlanArgs),
+    /// Read & Write Agent State on Issues
+    State(StateArgs),
+    /// Split Staged Changes into Atomic Commits
+    Atomize(AtomizeArgs),
 }
 
 #[tokio::main]
@@ -108,10 +116,9 @@ async fn main() -> color_eyre::Result<()> {
             commands::finish::execute(args, &system, &github).await?;
         }
         Commands::Issue(args) => {
-            let fs = gc_adapter_fs::TokioFileSystem;
             let github = gc_adapter_github::OctocrabGitHub::new();
             let system = gc_adapter_system::TokioSystem;
-            commands::issue::execute(args, &github, &system, &fs).await?;
+            commands::issue::execute(args, &github, &system).await?;
         }
         Commands::Pr(args) => {
             let github = gc_adapter_github::OctocrabGitHub::new();
@@ -119,18 +126,24 @@ async fn main() -> color_eyre::Result<()> {
             commands::pr::execute(args, &github, &system).await?;
         }
         Commands::Git(args) => {
+            let github = gc_adapter_github::OctocrabGitHub::new();
             let system = gc_adapter_system::TokioSystem;
-            commands::git::execute(args, &system).await?;
+            commands::git::execute(args, &github, &system).await?;
         }
         Commands::Info(args) => {
+            let fs = gc_adapter_fs::TokioFileSystem;
+            let github = gc_adapter_github::OctocrabGitHub::new();
             let system = gc_adapter_system::TokioSystem;
-            commands::info::execute(args, &system).await?;
+            commands::info::execute(args, &fs, &github, &system).await?;
         }
         Commands::Check(args) => {
             let fs = gc_adapter_fs::TokioFileSystem;
             let system = gc_adapter_system::TokioSystem;
             let github = gc_adapter_github::OctocrabGitHub::new();
-            commands::check::execute(args, &fs, &system, &github).await?;
+            let code = commands::check::execute(args, &fs, &system, &github).await?;
+            if code != 0 {
+                std::process::exit(code);
+            }
         }
         Commands::Next(args) => {
             let fs = gc_adapter_fs::TokioFileSystem;
@@ -144,6 +157,10 @@ async fn main() -> color_eyre::Result<()> {
             let github = gc_adapter_github::OctocrabGitHub::new();
             commands::update::execute(args, &fs, &system, &github).await?;
         }
+        Commands::SelfUpdate(args) => {
+            let github = gc_adapter_github::OctocrabGitHub::new();
+            commands::self_update::execute(args, &github).await?;
+        }
         Commands::Dispatch(args) => {
             let git = gc_adapter_cli::CliGitAdapter;
             let jules = gc_adapter_cli::CliJulesAdapter;
@@ -153,6 +170,20 @@ async fn main() -> color_eyre::Result<()> {
         Commands::Analyze(args) => {
             commands::analyze::execute(args).await?;
         }
+        Commands::Plan(args) => {
+            let fs = gc_adapter_fs::TokioFileSystem;
+            let system = gc_adapter_system::TokioSystem;
+            commands::plan::execute(args, &fs, &system).await?;
+        }
+        Commands::State(args) => {
+            let github = gc_adapter_github::OctocrabGitHub::new();
+            let system = gc_adapter_system::TokioSystem;
+            commands::state::execute(args, &github, &system).await?;
+        }
+        Commands::Atomize(args) => {
+            let system = gc_adapter_system::TokioSystem;
+            commands::atomize::execute(args, &system).await?;
+        }
     }
 
     Ok(())
@@ -0,0 +1,369 @@
+//! Typed model of the `<agent-state>` block agents leave in issue comments.
+//!
+//! See `docs/agent-docs/specs/SPEC_CONTEXT_PROTOCOL.md` for the format. The parser is
+//! deliberately lenient: unknown tags are ignored, `input_request` may be plain text,
+//! and hyphenated tag names (`next-action`) are accepted alongside the canonical ones.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use thiserror::Error;
+
+const OPEN_TAG: &str = "<agent-state>";
+const CLOSE_TAG: &str = "</agent-state>";
+
+#[derive(Error, Debug, PartialEq)]
+pub enum AgentStateError {
+    #[error("no <agent-state> block found")]
+    Missing,
+    #[error("unterminated <agent-state> block")]
+    Unterminated,
+    #[error("invalid <{tag}> value '{value}': {reason}")]
+    InvalidValue { tag: String, value: String, reason: String },
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlanItem {
+    pub status: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub question: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AgentState {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub step: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<u8>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plan: Vec<PlanItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_request: Option<InputRequest>,
+    /// Metric name/value pairs in document order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metrics: Vec<(String, String)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_action: Option<String>,
+}
+
+/// Returns the raw contents of every `<agent-state>` block in `text`, in order.
+pub fn extract_blocks(text: &str) -> Result<Vec<&str>, AgentStateError> {
+    blocks(text).collect()
+}
+
+/// True if `text` contains at least one well-formed `<agent-state>` block.
+pub fn contains_agent_state(text: &str) -> bool {
+    // Blocks before an unterminated one still count
+    blocks(text).map_while(Result::ok).any(|b| AgentState::parse_inner(b).is_ok())
+}
+
+/// The blocks in order; an unterminated block ends the sequence with an error.
+fn blocks(text: &str) -> impl Iterator<Item = Result<&str, AgentStateError>> + '_ {
+    let mut rest = Some(text);
+    std::iter::from_fn(move || {
+        let text = rest?;
+        let after = &text[text.find(OPEN_TAG)? + OPEN_TAG.len()..];
+        match after.find(CLOSE_TAG) {
+            Some(end) => {
+                rest = Some(&after[end + CLOSE_TAG.len()..]);
+                Some(Ok(&after[..end]))
+            }
+            None => {
+                rest = None;
+                Some(Err(AgentStateError::Unterminated))
+            }
+        }
+    })
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Regex matching a `<tag>` element, compiled once per tag name.
+fn element_re(tag: &str) -> Regex {
+    static CACHE: OnceLock<Mutex<HashMap<String, Regex>>> = OnceLock::new();
+    let mut cache = CACHE.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+    cache
+        .entry(tag.to_string())
+        .or_insert_with(|| Regex::new(&format!(r"(?s)<{0}(?:\s[^>]*)?>(.*?)</{0}>", regex::escape(tag))).unwrap())
+        .clone()
+}
+
+fn comment_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?s)<!--.*?-->").unwrap())
+}
+
+fn plan_item_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r#"(?s)<item(?:\s+status\s*=\s*"([^"]*)")?[^>]*>(.*?)</item>"#).unwrap())
+}
+
+fn metric_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?s)<([A-Za-z_][\w-]*)>(.*?)</([A-Za-z_][\w-]*)>").unwrap())
+}
+
+/// Contents of the first `<tag>` / `<tag-variant>` element, trimmed.
+fn element(xml: &str, tag: &str) -> Option<String> {
+    let variants = [tag.to_string(), tag.replace('_', "-")];
+    variants.iter().find_map(|t| element_re(t).captures(xml).map(|c| c[1].trim().to_string()))
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.is_empty()).map(|v| unescape(&v))
+}
+
+impl AgentState {
+    /// Parses the first `<agent-state>` block found in `text`.
+    pub fn parse(text: &str) -> Result<Self, AgentStateError> {
+        let blocks = extract_blocks(text)?;
+        let block = blocks.first().ok_or(AgentStateError::Missing)?;
+        Self::parse_inner(block)
+    }
+
+    /// Returns the most recent valid state across `texts` (oldest first):
+    /// the last block that parses, so an invalid trailing block falls back to an earlier one.
+    pub fn latest<'a>(texts: impl IntoIterator<Item = &'a str>) -> Option<Self> {
+        texts
+            .into_iter()
+            .filter_map(|t| extract_blocks(t).ok())
+            .flat_map(|blocks| blocks.into_iter())
+            .filter_map(|b| Self::parse_inner(b).ok())
+            .last()
+    }
+
+    fn parse_inner(block: &str) -> Result<Self, AgentStateError> {
+        let xml = comment_re().replace_all(block, "");
+
+        let progress = match non_empty(element(&xml, "progress")) {
+            Some(raw) => {
+                let value: u8 = raw.trim_end_matches('%').parse().map_err(|_| AgentStateError::InvalidValue {
+                    tag: "progress".into(),
+                    value: raw.clone(),
+                    reason: "expected an integer between 0 and 100".into(),
+                })?;
+                if value > 100 {
+                    return Err(AgentStateError::InvalidValue {
+                        tag: "progress".into(),
+                        value: raw,
+                        reason: "expected an integer between 0 and 100".into(),
+                    });
+                }
+                Some(value)
+            }
+            None => None,
+        };
+
+        let plan = element(&xml, "plan")
+            .map(|plan| {
+                plan_item_re()
+                    .captures_iter(&plan)
+                    .map(|c| PlanItem {
+                        status: c.get(1).map(|m| unescape(m.as_str())).unwrap_or_else(|| "pending".into()),
+                        text: unescape(c[2].trim()),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let input_request = element(&xml, "input_request").filter(|r| !r.is_empty()).map(|raw| {
+            let status = non_empty(element(&raw, "status"));
+            let question = non_empty(element(&raw, "question"));
+            if status.is_none() && question.is_none() {
+                InputRequest { status: None, question: Some(unescape(&raw)) }
+            } else {
+                InputRequest { status, question }
+            }
+        });
+
+        let metrics = element(&xml, "metrics")
+            .map(|raw| {
+                metric_re()
+                    .captures_iter(&raw)
+                    .filter(|c| c[1] == c[3])
+                    .map(|c| (c[1].to_string(), unescape(c[2].trim())))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            intent: non_empty(element(&xml, "intent")),
+            step: non_empty(element(&xml, "step")),
+            progress,
+            plan,
+            input_request,
+            metrics,
+            memory: non_empty(element(&xml, "memory")),
+            next_action: non_empty(element(&xml, "next_action")),
+        })
+    }
+
+    /// Sets (or replaces) a metric value.
+    pub fn set_metric(&mut self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+        match self.metrics.iter_mut().find(|(k, _)| k == name) {
+            Some(entry) => entry.1 = value,
+            None => self.metrics.push((name.to_string(), value)),
+        }
+    }
+
+    /// Serializes the state as an `<agent-state>` block.
+    pub fn to_xml(&self) -> String {
+        let mut out = String::from(OPEN_TAG);
+        out.push('\n');
+        let simple = |out: &mut String, tag: &str, value: &Option<String>| {
+            if let Some(v) = value {
+                out.push_str(&format!("  <{0}>{1}</{0}>\n", tag, escape(v)));
+            }
+        };
+
+        simple(&mut out, "intent", &self.intent);
+        simple(&mut out, "step", &self.step);
+        simple(&mut out, "progress", &self.progress.map(|p| p.to_string()));
+
+        if !self.plan.is_empty() {
+            out.push_str("  <plan>\n");
+            for item in &self.plan {
+                out.push_str(&format!("    <item status=\"{}\">{}</item>\n", escape(&item.status), escape(&item.text)));
+            }
+            out.push_str("  </plan>\n");
+        }
+
+        if let Some(req) = &self.input_request {
+            out.push_str("  <input_request>\n");
+            if let Some(status) = &req.status {
+                out.push_str(&format!("    <status>{}</status>\n", escape(status)));
+            }
+            if let Some(question) = &req.question {
+                out.push_str(&format!("    <question>{}</question>\n", escape(question)));
+            }
+            out.push_str("  </input_request>\n");
+        }
+
+        if !self.metrics.is_empty() {
+            out.push_str("  <metrics>\n");
+            for (k, v) in &self.metrics {
+                out.push_str(&format!("    <{0}>{1}</{0}>\n", k, escape(v)));
+            }
+            out.push_str("  </metrics>\n");
+        }
+
+        if let Some(memory) = &self.memory {
+            out.push_str(&format!("  <memory>\n{}\n  </memory>\n", escape(memory)));
+        }
+        simple(&mut out, "next_action", &self.next_action);
+
+        out.push_str(CLOSE_TAG);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC_EXAMPLE: &str = r#"Progress update
+<agent-state>
+  <!-- 1. INTENT -->
+  <intent>implement_auth_flow</intent>
+  <step>waiting_for_input</step>
+  <progress>45</progress>
+  <plan>
+    <item status="done">Analyze OAuth requirements</item>
+    <item status="in_progress">Implement API endpoints</item>
+    <item status="pending">Integration tests</item>
+  </plan>
+  <input_request>
+    <status>waiting</status>
+    <question>What is the staging Client ID?</question>
+  </input_request>
+  <metrics>
+    <tool_calls>12</tool_calls>
+    <errors>0</errors>
+  </metrics>
+  <memory>
+    {"last_file_edited": "src/auth/router.ts"}
+  </memory>
+  <next_action>check_user_response</next_action>
+</agent-state>"#;
+
+    #[test]
+    fn test_parse_spec_example() {
+        let state = AgentState::parse(SPEC_EXAMPLE).unwrap();
+        assert_eq!(state.intent.as_deref(), Some("implement_auth_flow"));
+        assert_eq!(state.step.as_deref(), Some("waiting_for_input"));
+        assert_eq!(state.progress, Some(45));
+        assert_eq!(state.plan.len(), 3);
+        assert_eq!(state.plan[1], PlanItem { status: "in_progress".into(), text: "Implement API endpoints".into() });
+        assert_eq!(state.input_request.unwrap().question.as_deref(), Some("What is the staging Client ID?"));
+        assert_eq!(state.metrics, vec![("tool_calls".into(), "12".into()), ("errors".into(), "0".into())]);
+        assert_eq!(state.memory.as_deref(), Some(r#"{"last_file_edited": "src/auth/router.ts"}"#));
+        assert_eq!(state.next_action.as_deref(), Some("check_user_response"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let state = AgentState::parse(SPEC_EXAMPLE).unwrap();
+        assert_eq!(AgentState::parse(&state.to_xml()).unwrap(), state);
+    }
+
+    #[test]
+    fn test_round_trip_escapes_markup_and_quotes() {
+        let state = AgentState {
+            step: Some("compare <a> & \"b\"".into()),
+            plan: vec![PlanItem { status: "blocked \"on\" <review>".into(), text: "say \"hi\" & <wave>".into() }],
+            ..Default::default()
+        };
+        assert_eq!(AgentState::parse(&state.to_xml()).unwrap(), state);
+    }
+
+    #[test]
+    fn test_hyphenated_tags_and_plain_input_request() {
+        let state = AgentState::parse("<agent-state><next-action>merge</next-action><input-request>Need API key</input-request></agent-state>").unwrap();
+        assert_eq!(state.next_action.as_deref(), Some("merge"));
+        assert_eq!(state.input_request.unwrap().question.as_deref(), Some("Need API key"));
+    }
+
+    #[test]
+    fn test_invalid_and_missing_blocks() {
+        assert_eq!(AgentState::parse("no state here"), Err(AgentStateError::Missing));
+        assert_eq!(AgentState::parse("<agent-state><step>x</step>"), Err(AgentStateError::Unterminated));
+        assert!(matches!(AgentState::parse("<agent-state><progress>150</progress></agent-state>"), Err(AgentStateError::InvalidValue { .. })));
+        assert!(!contains_agent_state("mentions <agent-state> in prose"));
+        assert!(contains_agent_state(SPEC_EXAMPLE));
+        assert!(contains_agent_state(&format!("{}\n<agent-state><step>cut off", SPEC_EXAMPLE)));
+    }
+
+    #[test]
+    fn test_latest_picks_last_valid_block() {
+        let comments = [
+            "<agent-state><step>planning</step></agent-state>",
+            "just a comment",
+            "<agent-state><step>coding</step></agent-state>",
+            "<agent-state><progress>bogus</progress></agent-state>",
+        ];
+        assert_eq!(AgentState::latest(comments).unwrap().step.as_deref(), Some("coding"));
+    }
+}
@@ -0,0 +1,72 @@
+//! Model for `.ai-core/features.json`, the Planner Agent's roadmap file.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+pub const FEATURES_PATH: &str = ".ai-core/features.json";
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FeaturesManifest {
+    #[serde(default)]
+    pub phases: BTreeMap<String, Phase>,
+    #[serde(default)]
+    pub features: Vec<Feature>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Phase {
+    pub name: String,
+    #[serde(default)]
+    pub status: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Feature {
+    pub id: String,
+    pub name: String,
+    pub phase: u32,
+    #[serde(default)]
+    pub passes: bool,
+}
+
+impl FeaturesManifest {
+    /// The first phase marked `in_progress`, or else the first one not yet completed.
+    pub fn current_phase(&self) -> Option<u32> {
+        let mut phases: Vec<(u32, &Phase)> = self
+            .phases
+            .iter()
+            .filter_map(|(k, p)| k.parse::<u32>().ok().map(|n| (n, p)))
+            .collect();
+        phases.sort_by_key(|(n, _)| *n);
+
+        phases
+            .iter()
+            .find(|(_, p)| p.status == "in_progress")
+            .or_else(|| phases.iter().find(|(_, p)| !matches!(p.status.as_str(), "completed" | "done")))
+            .map(|(n, _)| *n)
+    }
+
+    /// Resolves the phase of an issue from a `phase-N` / `phase:N` label,
+    /// or from a feature id or name mentioned in its title or labels.
+    pub fn phase_for(&self, title: &str, labels: &[String]) -> Option<u32> {
+        for label in labels {
+            let lower = label.to_lowercase();
+            if let Some(rest) = lower.strip_prefix("phase") {
+                if let Ok(n) = rest.trim_start_matches(['-', ':', ' ']).parse::<u32>() {
+                    return Some(n);
+                }
+            }
+        }
+
+        let title = title.to_lowercase();
+        self.features
+            .iter()
+            .find(|f| {
+                let id = f.id.to_lowercase();
+                title.contains(&id)
+                    || title.contains(&f.name.to_lowercase())
+                    || labels.iter().any(|l| l.eq_ignore_ascii_case(&id))
+            })
+            .map(|f| f.phase)
+    }
+}
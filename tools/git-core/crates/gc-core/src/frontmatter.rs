@@ -0,0 +1,157 @@
+//! Frontmatter schema of local issue files (`.github/issues/*.md`).
+//!
+//! ```yaml
+//! title: "Add OAuth login"
+//! labels: [enhancement]
+//! assignees: [ana]
+//! milestone: v1.2          # synced (GitHub milestone title)
+//! state: open              # synced (open | closed)
+//! parent: 12               # synced (GitHub sub-issue of #12)
+//! priority: high           # critical | high | medium | low
+//! estimate: 3d             # points (3) or duration (4h, 3d, 2w)
+//! due: 2025-06-30
+//! column: In Progress      # project board column
+//! custom:
+//!   team: platform
+//! ```
+//!
+//! Keys outside the schema are allowed and left alone.
+
+use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+pub const STATES: &[&str] = &["open", "closed"];
+pub const PRIORITIES: &[&str] = &["critical", "high", "medium", "low"];
+
+/// Frontmatter of an issue file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IssueFrontmatter {
+    pub title: String,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub assignees: Vec<String>,
+    #[serde(default, deserialize_with = "scalar_string", skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(default, deserialize_with = "scalar_string", skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    /// Number of the parent issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<u64>,
+    #[serde(default, deserialize_with = "scalar_string", skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<String>,
+    #[serde(default, deserialize_with = "scalar_string", skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    #[serde(default, deserialize_with = "scalar_string", skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: BTreeMap<String, serde_json::Value>,
+}
+
+impl IssueFrontmatter {
+    /// Check values the types alone do not catch. Returns one message per problem.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        if self.title.trim().is_empty() {
+            errors.push("title must not be empty".to_string());
+        }
+        for (key, values) in [("labels", &self.labels), ("assignees", &self.assignees)] {
+            if values.iter().any(|v| v.trim().is_empty()) {
+                errors.push(format!("{} must not contain empty entries", key));
+            }
+        }
+        if let Some(state) = &self.state {
+            if !STATES.contains(&state.as_str()) {
+                errors.push(format!("state '{}' must be one of: {}", state, STATES.join(", ")));
+            }
+        }
+        if let Some(priority) = &self.priority {
+            if !PRIORITIES.contains(&priority.as_str()) {
+                errors.push(format!("priority '{}' must be one of: {}", priority, PRIORITIES.join(", ")));
+            }
+        }
+        if self.parent == Some(0) {
+            errors.push("parent must be an issue number".to_string());
+        }
+        if let Some(estimate) = &self.estimate {
+            if !estimate_re().is_match(estimate) {
+                errors.push(format!("estimate '{}' must be points (3) or a duration (4h, 3d, 2w)", estimate));
+            }
+        }
+        if let Some(due) = &self.due {
+            if !is_date(due) {
+                errors.push(format!("due '{}' must be a YYYY-MM-DD date", due));
+            }
+        }
+        if let Some(milestone) = &self.milestone {
+            if milestone.trim().is_empty() {
+                errors.push("milestone must not be empty".to_string());
+            }
+        }
+        for (key, value) in &self.custom {
+            if value.is_object() {
+                errors.push(format!("custom.{} must be a value or a list, not a map", key));
+            }
+        }
+
+        errors
+    }
+}
+
+fn estimate_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^\d+(\.\d+)?[hdw]?$").unwrap())
+}
+
+fn is_date(value: &str) -> bool {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| Regex::new(r"^(\d{4})-(\d{2})-(\d{2})$").unwrap());
+    let Some(caps) = re.captures(value) else { return false };
+    let month: u32 = caps[2].parse().unwrap_or(0);
+    let day: u32 = caps[3].parse().unwrap_or(0);
+    (1..=12).contains(&month) && (1..=31).contains(&day)
+}
+
+/// Accept `milestone: 2` or `estimate: 3` as well as strings.
+fn scalar_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(s)) => Ok(Some(s)),
+        Some(serde_json::Value::Number(n)) => Ok(Some(n.to_string())),
+        Some(serde_json::Value::Bool(b)) => Ok(Some(b.to_string())),
+        Some(other) => Err(serde::de::Error::custom(format!("expected a single value, found {}", other))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> IssueFrontmatter {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_valid_frontmatter() {
+        let fm = parse(r#"{"title": "A", "milestone": 2, "state": "open", "priority": "high", "parent": 12,
+            "estimate": 3, "due": "2025-06-30", "column": "Todo", "custom": {"team": "platform", "tags": ["a"]}}"#);
+        assert_eq!(fm.milestone.as_deref(), Some("2"));
+        assert_eq!(fm.estimate.as_deref(), Some("3"));
+        assert!(fm.validate().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_values_are_reported() {
+        let fm = parse(r#"{"title": " ", "state": "done", "priority": "urgent", "estimate": "soon",
+            "due": "2025-13-01", "custom": {"nested": {"a": 1}}}"#);
+        let errors = fm.validate();
+        assert_eq!(errors.len(), 6, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.contains("priority 'urgent'")));
+        assert!(errors.iter().any(|e| e.contains("due '2025-13-01'")));
+    }
+}
@@ -0,0 +1,328 @@
+//! Issue dependency graph.
+//!
+//! Issues reference each other in their bodies ("depends on #3", "blocked by #7")
+//! and epics list their sub-issues as task lists (`- [ ] #12`). This module turns
+//! those references into a DAG so work can be ordered and blocked issues skipped.
+
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// References to other issues found in an issue body.
+#[derive(Debug, Default, PartialEq)]
+pub struct References {
+    /// Issues that must be finished first ("depends on" / "blocked by").
+    pub depends_on: Vec<u64>,
+    /// Issues listed in a task list; the parent is done only when they are.
+    pub subtasks: Vec<u64>,
+}
+
+/// Extracts dependency and task-list references from an issue body.
+pub fn parse_references(body: &str) -> References {
+    let dep_re = Regex::new(r"(?i)(?:blocked\s+by|depends\s+on)((?:[\s,:]*(?:and\s+)?#\d+)+)")
+        .expect("valid dependency regex");
+    let task_re = Regex::new(r"(?m)^\s*[-*]\s+\[[ xX]\]\s+.*?#(\d+)").expect("valid task list regex");
+    let num_re = Regex::new(r"#(\d+)").expect("valid number regex");
+
+    let mut refs = References::default();
+    for cap in dep_re.captures_iter(body) {
+        for n in num_re.captures_iter(&cap[1]) {
+            if let Ok(value) = n[1].parse::<u64>() {
+                if !refs.depends_on.contains(&value) {
+                    refs.depends_on.push(value);
+                }
+            }
+        }
+    }
+    for cap in task_re.captures_iter(body) {
+        if let Ok(value) = cap[1].parse::<u64>() {
+            if !refs.subtasks.contains(&value) && !refs.depends_on.contains(&value) {
+                refs.subtasks.push(value);
+            }
+        }
+    }
+    refs
+}
+
+/// Extracts only the "depends on / blocked by" references from an issue body.
+pub fn parse_dependencies(body: &str) -> Vec<u64> {
+    parse_references(body).depends_on
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Node {
+    pub number: u64,
+    pub title: String,
+    pub open: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeKind {
+    DependsOn,
+    Subtask,
+}
+
+/// `from` cannot be finished before `to`.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Edge {
+    pub from: u64,
+    pub to: u64,
+    pub kind: EdgeKind,
+}
+
+/// Raised when the dependency graph is not acyclic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CycleError {
+    /// Issue numbers forming the cycle, with the first repeated at the end.
+    pub cycle: Vec<u64>,
+}
+
+impl std::fmt::Display for CycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path: Vec<String> = self.cycle.iter().map(|n| format!("#{}", n)).collect();
+        write!(f, "dependency cycle: {}", path.join(" -> "))
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IssueGraph {
+    nodes: BTreeMap<u64, Node>,
+    edges: BTreeSet<Edge>,
+}
+
+impl IssueGraph {
+    /// Builds the graph from `(number, title, body, open)` tuples.
+    /// References to issues that are not part of the input are treated as closed.
+    pub fn build<'a>(issues: impl IntoIterator<Item = (u64, &'a str, &'a str, bool)>) -> Self {
+        let mut graph = Self::default();
+        let mut bodies = Vec::new();
+        for (number, title, body, open) in issues {
+            graph.nodes.insert(number, Node { number, title: title.to_string(), open });
+            bodies.push((number, body));
+        }
+        for (number, body) in bodies {
+            let refs = parse_references(body);
+            for to in refs.depends_on {
+                graph.add_edge(number, to, EdgeKind::DependsOn);
+            }
+            for to in refs.subtasks {
+                graph.add_edge(number, to, EdgeKind::Subtask);
+            }
+        }
+        graph
+    }
+
+    fn add_edge(&mut self, from: u64, to: u64, kind: EdgeKind) {
+        if from != to && self.nodes.contains_key(&to) {
+            self.edges.insert(Edge { from, to, kind });
+        }
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &Node> {
+        self.nodes.values()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = &Edge> {
+        self.edges.iter()
+    }
+
+    pub fn contains(&self, number: u64) -> bool {
+        self.nodes.contains_key(&number)
+    }
+
+    fn is_open(&self, number: u64) -> bool {
+        self.nodes.get(&number).is_some_and(|n| n.open)
+    }
+
+    /// Direct prerequisites of an issue that are still open.
+    pub fn open_blockers(&self, number: u64) -> Vec<&Edge> {
+        self.edges
+            .iter()
+            .filter(|e| e.from == number && self.is_open(e.to))
+            .collect()
+    }
+
+    /// Topological work order of the open issues: prerequisites come first,
+    /// ties are broken by issue number.
+    pub fn topological_order(&self) -> Result<Vec<u64>, CycleError> {
+        Ok(self.layers()?.into_iter().flatten().collect())
+    }
+
+    /// Open issues grouped into layers; every issue in a layer only depends on earlier layers,
+    /// so issues within the same layer can be worked on in parallel.
+    pub fn layers(&self) -> Result<Vec<Vec<u64>>, CycleError> {
+        if let Some(cycle) = self.find_cycle() {
+            return Err(CycleError { cycle });
+        }
+
+        let open: BTreeSet<u64> = self.nodes.values().filter(|n| n.open).map(|n| n.number).collect();
+        let mut remaining = open.clone();
+        let mut layers = Vec::new();
+
+        while !remaining.is_empty() {
+            let ready: Vec<u64> = remaining
+                .iter()
+                .copied()
+                .filter(|n| {
+                    self.edges
+                        .iter()
+                        .filter(|e| e.from == *n)
+                        .all(|e| !remaining.contains(&e.to))
+                })
+                .collect();
+            for n in &ready {
+                remaining.remove(n);
+            }
+            layers.push(ready);
+        }
+
+        Ok(layers)
+    }
+
+    /// Returns one cycle in the graph, if any.
+    pub fn find_cycle(&self) -> Option<Vec<u64>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark {
+            Unvisited,
+            InProgress,
+            Done,
+        }
+
+        fn visit(graph: &IssueGraph, n: u64, marks: &mut BTreeMap<u64, Mark>, stack: &mut Vec<u64>) -> Option<Vec<u64>> {
+            marks.insert(n, Mark::InProgress);
+            stack.push(n);
+            for edge in graph.edges.iter().filter(|e| e.from == n) {
+                match marks.get(&edge.to).copied().unwrap_or(Mark::Unvisited) {
+                    Mark::InProgress => {
+                        let start = stack.iter().position(|&s| s == edge.to).unwrap_or(0);
+                        let mut cycle = stack[start..].to_vec();
+                        cycle.push(edge.to);
+                        return Some(cycle);
+                    }
+                    Mark::Unvisited => {
+                        if let Some(cycle) = visit(graph, edge.to, marks, stack) {
+                            return Some(cycle);
+                        }
+                    }
+                    Mark::Done => {}
+                }
+            }
+            stack.pop();
+            marks.insert(n, Mark::Done);
+            None
+        }
+
+        let mut marks: BTreeMap<u64, Mark> = BTreeMap::new();
+        for &n in self.nodes.keys() {
+            if marks.get(&n).copied().unwrap_or(Mark::Unvisited) == Mark::Unvisited {
+                if let Some(cycle) = visit(self, n, &mut marks, &mut Vec::new()) {
+                    return Some(cycle);
+                }
+            }
+        }
+        None
+    }
+
+    /// Renders the graph as a Mermaid flowchart. Arrows point from prerequisite to dependent.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("graph TD\n");
+        for node in self.nodes.values() {
+            let title = node.title.replace('"', "'");
+            out.push_str(&format!("    I{}[\"#{} {}\"]\n", node.number, node.number, title));
+        }
+        for edge in &self.edges {
+            let arrow = match edge.kind {
+                EdgeKind::DependsOn => "-->",
+                EdgeKind::Subtask => "-.->",
+            };
+            out.push_str(&format!("    I{} {} I{}\n", edge.to, arrow, edge.from));
+        }
+        for node in self.nodes.values().filter(|n| !n.open) {
+            out.push_str(&format!("    style I{} fill:#ddd,stroke:#999\n", node.number));
+        }
+        out
+    }
+
+    /// Renders the graph in Graphviz DOT format. Arrows point from prerequisite to dependent.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph issues {\n    rankdir=LR;\n");
+        for node in self.nodes.values() {
+            let title = node.title.replace('\\', "\\\\").replace('"', "\\\"");
+            let style = if node.open { "" } else { ", style=filled, fillcolor=lightgrey" };
+            out.push_str(&format!("    \"{}\" [label=\"#{} {}\"{}];\n", node.number, node.number, title, style));
+        }
+        for edge in &self.edges {
+            let style = match edge.kind {
+                EdgeKind::DependsOn => "",
+                EdgeKind::Subtask => " [style=dashed]",
+            };
+            out.push_str(&format!("    \"{}\" -> \"{}\"{};\n", edge.to, edge.from, style));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_references() {
+        let body = "Depends on #2 and #3.\n\n## Tasks\n- [ ] Write parser #4\n- [x] #5\n- [ ] #2\n";
+        let refs = parse_references(body);
+        assert_eq!(refs.depends_on, vec![2, 3]);
+        assert_eq!(refs.subtasks, vec![4, 5]);
+    }
+
+    #[test]
+    fn test_topological_order() {
+        let graph = IssueGraph::build(vec![
+            (1, "Epic", "- [ ] #2\n- [ ] #3", true),
+            (2, "API", "blocked by #4", true),
+            (3, "UI", "depends on #2", true),
+            (4, "Schema", "", true),
+            (5, "Done already", "", false),
+        ]);
+        assert_eq!(graph.topological_order().unwrap(), vec![4, 2, 3, 1]);
+        assert_eq!(graph.layers().unwrap(), vec![vec![4], vec![2], vec![3], vec![1]]);
+        assert_eq!(graph.open_blockers(3).len(), 1);
+        assert!(graph.open_blockers(4).is_empty());
+    }
+
+    #[test]
+    fn test_closed_and_unknown_dependencies_do_not_block() {
+        let graph = IssueGraph::build(vec![
+            (1, "A", "depends on #2, #99", true),
+            (2, "B", "", false),
+        ]);
+        assert!(graph.open_blockers(1).is_empty());
+        assert_eq!(graph.topological_order().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_cycle_detection() {
+        let graph = IssueGraph::build(vec![
+            (1, "A", "depends on #2", true),
+            (2, "B", "depends on #3", true),
+            (3, "C", "blocked by #1", true),
+        ]);
+        let err = graph.topological_order().unwrap_err();
+        assert_eq!(err.cycle, vec![1, 2, 3, 1]);
+        assert_eq!(err.to_string(), "dependency cycle: #1 -> #2 -> #3 -> #1");
+    }
+
+    #[test]
+    fn test_exports() {
+        let graph = IssueGraph::build(vec![(1, "A \"quoted\"", "depends on #2", true), (2, "B", "", true)]);
+        let mermaid = graph.to_mermaid();
+        assert!(mermaid.contains("I2 --> I1"));
+        assert!(mermaid.contains("#1 A 'quoted'"));
+        let dot = graph.to_dot();
+        assert!(dot.contains("\"2\" -> \"1\";"));
+        assert!(dot.contains("A \\\"quoted\\\""));
+    }
+}
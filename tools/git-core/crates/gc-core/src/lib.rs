@@ -1,4 +1,10 @@
 pub mod ports;
+pub mod agent_state;
+pub mod features;
+pub mod frontmatter;
+pub mod graph;
+pub mod naming;
+pub mod priority;
 use serde::{Serialize, Deserialize};
 
 // Basic Core setup
@@ -15,6 +21,31 @@ pub struct Issue {
     pub html_url: String,
     pub assignees: Vec<String>,
     pub labels: Vec<String>,
+    /// RFC 3339 timestamp of the last change on GitHub.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// RFC 3339 timestamp of when the issue was closed.
+    #[serde(default)]
+    pub closed_at: Option<String>,
+    /// Why the issue was closed (`completed`, `not_planned`, `duplicate`).
+    #[serde(default)]
+    pub state_reason: Option<String>,
+    /// Milestone title.
+    #[serde(default)]
+    pub milestone: Option<String>,
+}
+
+/// Fields to change on an issue. `None` leaves the field untouched.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct IssuePatch {
+    pub title: Option<String>,
+    pub body: Option<String>,
+    pub labels: Option<Vec<String>>,
+    pub assignees: Option<Vec<String>>,
+    /// Milestone title.
+    pub milestone: Option<String>,
+    /// `open` or `closed`.
+    pub state: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -26,4 +57,112 @@ pub struct PullRequest {
     pub html_url: String,
     pub head_ref: String,
     pub base_ref: String,
+    /// Owner of the repository the head branch lives in; differs from the base owner for forks.
+    #[serde(default)]
+    pub head_owner: String,
+    /// Drafts must be marked ready before they can be merged.
+    #[serde(default)]
+    pub draft: bool,
+    /// Login of the author.
+    #[serde(default)]
+    pub author: String,
+    /// Whether the head merges cleanly into the base (`None` while GitHub computes it).
+    #[serde(default)]
+    pub mergeable: Option<bool>,
+    #[serde(default)]
+    pub merged: bool,
+    /// Commit the head branch points at.
+    #[serde(default)]
+    pub head_sha: String,
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+/// Pull request to open.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct NewPullRequest {
+    pub title: String,
+    pub body: String,
+    /// Branch with the changes.
+    pub head: String,
+    /// Branch to merge into.
+    pub base: String,
+    pub draft: bool,
+}
+
+/// How a pull request is merged.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeMethod {
+    Merge,
+    Squash,
+    Rebase,
+}
+
+impl MergeMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Merge => "merge",
+            Self::Squash => "squash",
+            Self::Rebase => "rebase",
+        }
+    }
+}
+
+/// Check run reported on a commit (CI job, linter, ...).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CheckRun {
+    pub name: String,
+    /// `queued`, `in_progress` or `completed`.
+    pub status: String,
+    /// `success`, `failure`, `neutral`, `skipped`, ... once completed.
+    pub conclusion: Option<String>,
+}
+
+/// GitHub Actions workflow run.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WorkflowRun {
+    pub name: String,
+    pub head_branch: String,
+    /// `queued`, `in_progress` or `completed`.
+    pub status: String,
+    /// `success`, `failure`, `cancelled`, ... once completed.
+    pub conclusion: Option<String>,
+    pub html_url: String,
+    /// RFC 3339 timestamp.
+    pub created_at: String,
+}
+
+/// Published GitHub release.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Release {
+    pub tag_name: String,
+    pub prerelease: bool,
+    pub assets: Vec<ReleaseAsset>,
+}
+
+/// File attached to a release.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub download_url: String,
+    pub size: u64,
+}
+
+/// Review left on a pull request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Review {
+    pub author: String,
+    /// `APPROVED`, `CHANGES_REQUESTED`, `COMMENTED` or `DISMISSED`.
+    pub state: String,
+    /// RFC 3339 timestamp.
+    pub submitted_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Comment {
+    pub id: u64,
+    pub author: String,
+    pub body: String,
+    pub created_at: String,
 }
@@ -0,0 +1,70 @@
+//! Naming rules for local issue files (`.github/issues/TYPE_slug.md`).
+//!
+//! Shared by `gc task`, which creates files from a title, and the issue syncer,
+//! which materialises GitHub issues as files.
+
+use slug::slugify;
+
+/// Detect the task type (`BUG`, `FEAT`, `DOCS`, ...) from keywords in a title.
+pub fn detect_type(title: &str) -> String {
+    let lower = title.to_lowercase();
+    if lower.contains("fix") || lower.contains("bug") || lower.contains("error") {
+        "BUG".to_string()
+    } else if lower.contains("feat") || lower.contains("add") || lower.contains("new") {
+        "FEAT".to_string()
+    } else if lower.contains("docs") || lower.contains("readme") {
+        "DOCS".to_string()
+    } else if lower.contains("refactor") {
+        "REFACTOR".to_string()
+    } else if lower.contains("test") {
+        "TEST".to_string()
+    } else {
+        "TASK".to_string()
+    }
+}
+
+/// GitHub label used for a task type.
+pub fn type_label(type_: &str) -> String {
+    match type_ {
+        "BUG" => "bug".to_string(),
+        "FEAT" => "enhancement".to_string(),
+        "DOCS" => "documentation".to_string(),
+        "REFACTOR" => "refactor".to_string(),
+        _ => "task".to_string(),
+    }
+}
+
+/// Task type of an existing issue: its labels first, then its title.
+pub fn type_from_issue(title: &str, labels: &[String]) -> String {
+    let from_label = labels.iter().find_map(|label| match label.to_lowercase().as_str() {
+        "bug" => Some("BUG"),
+        "enhancement" | "feature" => Some("FEAT"),
+        "documentation" | "docs" => Some("DOCS"),
+        "refactor" => Some("REFACTOR"),
+        "test" | "tests" => Some("TEST"),
+        _ => None,
+    });
+    from_label.map(str::to_string).unwrap_or_else(|| detect_type(title))
+}
+
+/// `TYPE_slug.md` file name for a task.
+pub fn issue_file_name(type_: &str, title: &str) -> String {
+    format!("{}_{}.md", type_.to_uppercase(), slugify(title))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name_from_title() {
+        assert_eq!(issue_file_name(&detect_type("Fix login bug"), "Fix login bug"), "BUG_fix-login-bug.md");
+        assert_eq!(issue_file_name("feat", "Add `gc pr` command!"), "FEAT_add-gc-pr-command.md");
+    }
+
+    #[test]
+    fn test_labels_take_precedence_over_title() {
+        assert_eq!(type_from_issue("Fix typo in README", &["documentation".into()]), "DOCS");
+        assert_eq!(type_from_issue("Fix typo in README", &["good first issue".into()]), "BUG");
+    }
+}
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use thiserror::Error;
-use crate::{Issue, PullRequest};
+use crate::{CheckRun, Comment, Issue, IssuePatch, MergeMethod, NewPullRequest, PullRequest, Release, Review, WorkflowRun};
 
 #[derive(Error, Debug)]
 pub enum CoreError {
@@ -10,12 +10,16 @@ pub enum CoreError {
     Git(String),
     #[error("GitHub Error: {0}")]
     GitHub(String),
+    /// Primary or secondary rate limit hit; the request can be retried later
+    #[error("GitHub rate limit: {0}")]
+    RateLimited(String),
     #[error("System Error: {0}")]
     System(String),
 }
 
 pub type Result<T> = std::result::Result<T, CoreError>;
 
+#[cfg_attr(feature = "test-utils", mockall::automock)]
 #[async_trait]
 pub trait GitPort: Send + Sync {
     async fn init(&self) -> Result<()>;
@@ -25,19 +29,37 @@ pub trait GitPort: Send + Sync {
     async fn push(&self) -> Result<()>;
 }
 
+#[cfg_attr(feature = "test-utils", mockall::automock)]
 #[async_trait]
 pub trait GitHubPort: Send + Sync {
     async fn check_auth(&self) -> Result<String>; // returns username
     async fn create_repo(&self, name: &str, private: bool) -> Result<()>;
-    async fn create_issue(&self, owner: &str, repo: &str, title: &str, body: &str, labels: &[String]) -> Result<()>;
+    async fn create_issue(&self, owner: &str, repo: &str, title: &str, body: &str, labels: &[String]) -> Result<Issue>;
+    async fn update_issue(&self, owner: &str, repo: &str, issue_number: u64, patch: &IssuePatch) -> Result<Issue>;
+    async fn close_issue(&self, owner: &str, repo: &str, issue_number: u64) -> Result<()>;
+    async fn add_sub_issue(&self, owner: &str, repo: &str, parent: u64, child: u64) -> Result<()>;
+    async fn closing_pr(&self, owner: &str, repo: &str, issue_number: u64) -> Result<Option<u64>>; // PR that closed the issue, if any
     async fn create_label(&self, name: &str, color: &str, desc: &str) -> Result<()>;
     async fn get_file_content(&self, owner: &str, repo: &str, branch: &str, path: &str) -> Result<String>;
     async fn get_pr_diff(&self, owner: &str, repo: &str, pr_number: u64) -> Result<String>;
     async fn post_comment(&self, owner: &str, repo: &str, issue_number: u64, body: &str) -> Result<()>;
+    async fn list_comments(&self, owner: &str, repo: &str, issue_number: u64) -> Result<Vec<Comment>>; // oldest first
     async fn list_issues(&self, owner: &str, repo: &str, state: Option<String>, assignee: Option<String>) -> Result<Vec<Issue>>;
     async fn list_prs(&self, owner: &str, repo: &str, state: Option<String>) -> Result<Vec<PullRequest>>;
+    async fn list_branch_prs(&self, owner: &str, repo: &str, branch: &str) -> Result<Vec<PullRequest>>; // any state, head in this repo, most recently updated first
+    async fn create_pr(&self, owner: &str, repo: &str, pr: &NewPullRequest) -> Result<PullRequest>;
+    async fn get_pr(&self, owner: &str, repo: &str, pr_number: u64) -> Result<PullRequest>;
+    async fn set_pr_base(&self, owner: &str, repo: &str, pr_number: u64, base: &str) -> Result<PullRequest>; // retarget onto another branch
+    async fn list_checks(&self, owner: &str, repo: &str, git_ref: &str) -> Result<Vec<CheckRun>>; // check runs of a commit or branch
+    async fn list_reviews(&self, owner: &str, repo: &str, pr_number: u64) -> Result<Vec<Review>>; // oldest first
+    async fn mark_pr_ready(&self, owner: &str, repo: &str, pr_number: u64) -> Result<()>; // draft → ready for review
+    async fn merge_pr(&self, owner: &str, repo: &str, pr_number: u64, method: MergeMethod) -> Result<String>; // returns the merge commit SHA
+    async fn delete_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<()>; // Ok if it is already gone
+    async fn list_workflow_runs(&self, owner: &str, repo: &str, limit: u8) -> Result<Vec<WorkflowRun>>; // most recent first
+    async fn get_release(&self, owner: &str, repo: &str, tag: Option<String>) -> Result<Release>; // latest release when tag is None
 }
 
+#[cfg_attr(feature = "test-utils", mockall::automock)]
 #[async_trait]
 pub trait FileSystemPort: Send + Sync {
     async fn create_dir(&self, path: &str) -> Result<()>;
@@ -45,9 +67,11 @@ pub trait FileSystemPort: Send + Sync {
     async fn read_file(&self, path: &str) -> Result<String>;
     async fn exists(&self, path: &str) -> Result<bool>;
     async fn move_file(&self, source: &str, dest: &str) -> Result<()>;
-    async fn list_files(&self, dir: &str, pattern: Option<&str>) -> Result<Vec<String>>;
+    async fn remove_file(&self, path: &str) -> Result<()>;
+    async fn list_files(&self, dir: &str, pattern: Option<String>) -> Result<Vec<String>>;
 }
 
+#[cfg_attr(feature = "test-utils", mockall::automock)]
 #[async_trait]
 pub trait SystemPort: Send + Sync {
     async fn check_command(&self, name: &str) -> Result<bool>;
@@ -55,11 +79,13 @@ pub trait SystemPort: Send + Sync {
     async fn run_command_output(&self, name: &str, args: &[String]) -> Result<String>;
 }
 
+#[cfg_attr(feature = "test-utils", mockall::automock)]
 #[async_trait]
 pub trait JulesPort: Send + Sync {
     async fn execute_task(&self, task_desc: &str) -> Result<()>;
 }
 
+#[cfg_attr(feature = "test-utils", mockall::automock)]
 #[async_trait]
 pub trait CopilotPort: Send + Sync {
     async fn suggest(&self, prompt: &str) -> Result<String>;
@@ -0,0 +1,290 @@
+//! Weighted priority scoring used by `gc next` to pick the next issue.
+//!
+//! Each factor contributes a signed number of points and is recorded in a
+//! [`ScoreBreakdown`] so the selection can be explained to humans and agents.
+
+use crate::graph::{EdgeKind, IssueGraph};
+use serde::Serialize;
+
+/// An open issue as seen by the priority engine.
+#[derive(Debug, Clone, Default)]
+pub struct Candidate {
+    pub number: u64,
+    pub title: String,
+    pub body: String,
+    pub labels: Vec<String>,
+    pub assignees: Vec<String>,
+    pub milestone: Option<String>,
+    /// Days until the milestone is due (negative when overdue).
+    pub milestone_due_in_days: Option<i64>,
+    pub age_days: i64,
+    /// Hours since the issue was last updated.
+    pub idle_hours: i64,
+    /// Roadmap phase from `features.json`, if the issue maps to one.
+    pub phase: Option<u32>,
+}
+
+/// Tunable weights for every scoring factor.
+#[derive(Debug, Clone)]
+pub struct ScoringWeights {
+    pub bug: i64,
+    pub urgent: i64,
+    pub security: i64,
+    pub enhancement: i64,
+    pub low_priority: i64,
+    pub milestone: i64,
+    pub milestone_due_soon: i64,
+    pub milestone_overdue: i64,
+    pub age_per_week: i64,
+    pub age_cap: i64,
+    pub unassigned: i64,
+    pub assigned_to_me: i64,
+    pub assigned_to_other: i64,
+    pub current_phase: i64,
+    pub earlier_phase: i64,
+    pub later_phase_per_step: i64,
+}
+
+impl Default for ScoringWeights {
+    fn default() -> Self {
+        Self {
+            bug: 50,
+            urgent: 40,
+            security: 35,
+            enhancement: 10,
+            low_priority: -20,
+            milestone: 15,
+            milestone_due_soon: 25,
+            milestone_overdue: 35,
+            age_per_week: 2,
+            age_cap: 20,
+            unassigned: 10,
+            assigned_to_me: 20,
+            assigned_to_other: -60,
+            current_phase: 30,
+            earlier_phase: 40,
+            later_phase_per_step: -15,
+        }
+    }
+}
+
+/// Context shared by every candidate during a scoring pass.
+#[derive(Debug, Clone, Default)]
+pub struct ScoringContext {
+    /// Login of the user running `gc next`.
+    pub current_user: Option<String>,
+    /// Phase currently in progress according to `features.json`.
+    pub current_phase: Option<u32>,
+    /// Dependency graph of the known issues (used to skip blocked work).
+    pub graph: IssueGraph,
+    /// Hours after which an idle `in-progress` claim is considered abandoned.
+    /// `None` means claims never expire.
+    pub claim_timeout_hours: Option<i64>,
+}
+
+/// Label used to mark an issue as claimed by a developer or agent.
+pub const CLAIM_LABEL: &str = "in-progress";
+
+/// One line of a score explanation.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ScoreFactor {
+    pub factor: String,
+    pub points: i64,
+}
+
+/// Full result of scoring a single candidate.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScoreBreakdown {
+    pub number: u64,
+    pub title: String,
+    pub total: i64,
+    pub factors: Vec<ScoreFactor>,
+    /// Reasons the issue cannot be picked right now (ai-blocked, claimed, open dependencies).
+    pub blocked_by: Vec<String>,
+}
+
+impl ScoreBreakdown {
+    pub fn is_blocked(&self) -> bool {
+        !self.blocked_by.is_empty()
+    }
+
+    fn add(&mut self, factor: impl Into<String>, points: i64) {
+        if points != 0 {
+            self.factors.push(ScoreFactor { factor: factor.into(), points });
+            self.total += points;
+        }
+    }
+}
+
+fn has_label(labels: &[String], names: &[&str]) -> bool {
+    labels.iter().any(|l| names.iter().any(|n| l.eq_ignore_ascii_case(n)))
+}
+
+/// Scores a single candidate.
+pub fn score(candidate: &Candidate, ctx: &ScoringContext, weights: &ScoringWeights) -> ScoreBreakdown {
+    let mut result = ScoreBreakdown {
+        number: candidate.number,
+        title: candidate.title.clone(),
+        total: 0,
+        factors: Vec::new(),
+        blocked_by: Vec::new(),
+    };
+    let labels = &candidate.labels;
+
+    // Labels
+    if has_label(labels, &["bug"]) {
+        result.add("label: bug", weights.bug);
+    }
+    if has_label(labels, &["urgent", "critical", "high priority", "priority-high", "priority:high"]) {
+        result.add("label: urgent", weights.urgent);
+    }
+    if has_label(labels, &["security"]) {
+        result.add("label: security", weights.security);
+    }
+    if has_label(labels, &["enhancement", "feature"]) {
+        result.add("label: enhancement", weights.enhancement);
+    }
+    if has_label(labels, &["low priority", "priority-low", "priority:low"]) {
+        result.add("label: low priority", weights.low_priority);
+    }
+
+    // Milestone
+    if let Some(milestone) = &candidate.milestone {
+        result.add(format!("milestone: {}", milestone), weights.milestone);
+        match candidate.milestone_due_in_days {
+            Some(days) if days < 0 => result.add("milestone overdue", weights.milestone_overdue),
+            Some(days) if days <= 7 => result.add("milestone due within 7 days", weights.milestone_due_soon),
+            _ => {}
+        }
+    }
+
+    // Age
+    let age_points = (candidate.age_days.max(0) / 7 * weights.age_per_week).min(weights.age_cap);
+    result.add(format!("age: {} days", candidate.age_days), age_points);
+
+    // Assignee
+    if candidate.assignees.is_empty() {
+        result.add("unassigned", weights.unassigned);
+    } else if ctx.current_user.as_ref().is_some_and(|me| candidate.assignees.iter().any(|a| a.eq_ignore_ascii_case(me))) {
+        result.add("assigned to you", weights.assigned_to_me);
+    } else {
+        result.add(format!("assigned to {}", candidate.assignees.join(", ")), weights.assigned_to_other);
+    }
+
+    // Roadmap phase
+    if let (Some(phase), Some(current)) = (candidate.phase, ctx.current_phase) {
+        if phase == current {
+            result.add(format!("phase {} (current)", phase), weights.current_phase);
+        } else if phase < current {
+            result.add(format!("phase {} (behind schedule)", phase), weights.earlier_phase);
+        } else {
+            let steps = i64::from(phase - current);
+            result.add(format!("phase {} (upcoming)", phase), steps * weights.later_phase_per_step);
+        }
+    }
+
+    // Blockers
+    if has_label(labels, &["ai-blocked"]) {
+        result.blocked_by.push("label: ai-blocked".to_string());
+    }
+    if has_label(labels, &[CLAIM_LABEL]) {
+        // Claims idle for longer than the timeout are treated as abandoned and return to the pool
+        let stale = ctx.claim_timeout_hours.is_some_and(|t| candidate.idle_hours >= t);
+        if !stale {
+            result.blocked_by.push(format!("claimed ({}, idle {}h)", CLAIM_LABEL, candidate.idle_hours));
+        }
+    }
+    for edge in ctx.graph.open_blockers(candidate.number) {
+        match edge.kind {
+            EdgeKind::DependsOn => result.blocked_by.push(format!("depends on open issue #{}", edge.to)),
+            EdgeKind::Subtask => result.blocked_by.push(format!("waiting for sub-issue #{}", edge.to)),
+        }
+    }
+
+    result
+}
+
+/// Scores every candidate and sorts them best-first.
+/// Blocked issues are kept but always ranked after unblocked ones.
+pub fn rank(candidates: &[Candidate], ctx: &ScoringContext, weights: &ScoringWeights) -> Vec<ScoreBreakdown> {
+    let mut scored: Vec<ScoreBreakdown> = candidates.iter().map(|c| score(c, ctx, weights)).collect();
+    scored.sort_by(|a, b| {
+        a.is_blocked()
+            .cmp(&b.is_blocked())
+            .then(b.total.cmp(&a.total))
+            .then(a.number.cmp(&b.number))
+    });
+    scored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(number: u64, labels: &[&str]) -> Candidate {
+        Candidate {
+            number,
+            title: format!("Issue {}", number),
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_bug_outranks_enhancement() {
+        let ctx = ScoringContext::default();
+        let ranked = rank(&[candidate(1, &["enhancement"]), candidate(2, &["bug"])], &ctx, &ScoringWeights::default());
+        assert_eq!(ranked[0].number, 2);
+    }
+
+    #[test]
+    fn test_blocked_issues_rank_last() {
+        let graph = IssueGraph::build(vec![(1, "", "blocked by #2", true), (2, "", "", true), (3, "", "", true)]);
+        let ctx = ScoringContext { graph, ..Default::default() };
+        let dependent = candidate(1, &["bug", "urgent"]);
+
+        let ranked = rank(&[dependent, candidate(2, &[]), candidate(3, &["ai-blocked", "bug"])], &ctx, &ScoringWeights::default());
+        assert_eq!(ranked[0].number, 2);
+        assert!(ranked[1].is_blocked());
+        assert!(ranked[2].is_blocked());
+    }
+
+    #[test]
+    fn test_dependency_on_closed_issue_does_not_block() {
+        let graph = IssueGraph::build(vec![(1, "", "depends on #5", true), (5, "", "", false)]);
+        let ctx = ScoringContext { graph, ..Default::default() };
+        assert!(!score(&candidate(1, &[]), &ctx, &ScoringWeights::default()).is_blocked());
+    }
+
+    #[test]
+    fn test_claimed_issues_blocked_until_stale() {
+        let mut claimed = candidate(1, &[CLAIM_LABEL]);
+        claimed.idle_hours = 5;
+        let mut ctx = ScoringContext { claim_timeout_hours: Some(24), ..Default::default() };
+        assert!(score(&claimed, &ctx, &ScoringWeights::default()).is_blocked());
+
+        claimed.idle_hours = 30;
+        assert!(!score(&claimed, &ctx, &ScoringWeights::default()).is_blocked());
+
+        ctx.claim_timeout_hours = None;
+        assert!(score(&claimed, &ctx, &ScoringWeights::default()).is_blocked());
+    }
+
+    #[test]
+    fn test_phase_and_assignee_factors() {
+        let weights = ScoringWeights::default();
+        let ctx = ScoringContext { current_user: Some("me".into()), current_phase: Some(2), ..Default::default() };
+
+        let mut mine = candidate(1, &[]);
+        mine.assignees = vec!["me".into()];
+        mine.phase = Some(2);
+        let mut theirs = candidate(2, &[]);
+        theirs.assignees = vec!["someone".into()];
+        theirs.phase = Some(3);
+
+        let a = score(&mine, &ctx, &weights);
+        let b = score(&theirs, &ctx, &weights);
+        assert_eq!(a.total, weights.assigned_to_me + weights.current_phase);
+        assert_eq!(b.total, weights.assigned_to_other + weights.later_phase_per_step);
+    }
+}
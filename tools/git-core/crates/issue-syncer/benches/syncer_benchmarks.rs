@@ -2,31 +2,32 @@
 //!
 //! Measures operations against PowerShell baseline (~5-10s for full sync).
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use gc_adapter_github::OctocrabGitHub;
+use gc_core::ports::MockGitHubPort;
 use issue_syncer::{
-    github::GitHubClient,
     mapping::IssueMapping,
     parser::parse_frontmatter,
     syncer::IssueSyncer,
 };
-use octocrab::Octocrab;
+use mockito::Matcher;
 use std::fs;
 use tempfile::TempDir;
 use tokio::runtime::Runtime;
 
-fn create_test_syncer(temp_dir: &TempDir) -> IssueSyncer {
-    let rt = Runtime::new().unwrap();
+fn empty_github() -> MockGitHubPort {
+    let mut github = MockGitHubPort::new();
+    github.expect_list_issues().returning(|_, _, _, _| Ok(vec![]));
+    github
+}
+
+fn create_test_syncer<'a>(temp_dir: &TempDir, github: &'a MockGitHubPort) -> IssueSyncer<'a, MockGitHubPort> {
     let issues_dir = temp_dir.path().join("issues");
     fs::create_dir_all(&issues_dir).unwrap();
 
     let mapping_file = issues_dir.join(".issue-mapping.json");
 
-    let github = rt.block_on(async {
-        let client = Octocrab::builder().build().unwrap();
-        GitHubClient::new(client, "owner".to_string(), "repo".to_string())
-    });
-
-    IssueSyncer::new(github, issues_dir, mapping_file)
+    IssueSyncer::new(github, "owner", "repo", issues_dir, mapping_file)
         .unwrap()
         .with_dry_run(true)
 }
@@ -148,7 +149,8 @@ fn bench_file_scanning(c: &mut Criterion) {
 
     // Setup: Create temp directory with multiple issue files
     let temp_dir = TempDir::new().unwrap();
-    let syncer = create_test_syncer(&temp_dir);
+    let github = empty_github();
+    let syncer = create_test_syncer(&temp_dir, &github);
 
     let issues_dir = temp_dir.path().join("issues");
 
@@ -170,9 +172,7 @@ Body for issue {}.
 
     group.bench_function("scan_10_files", |b| {
         b.iter(|| {
-            // Access scan_issue_files through a public method or create new syncer
-            let syncer = create_test_syncer(&temp_dir);
-            black_box(syncer);
+            let _status = black_box(syncer.status().unwrap());
         });
     });
 
@@ -204,10 +204,11 @@ Body content {}.
 
     group.bench_function("push_5_files_dry_run", |b| {
         let rt = Runtime::new().unwrap();
+        let github = empty_github();
 
         b.iter(|| {
             rt.block_on(async {
-                let mut syncer = create_test_syncer(&temp_dir);
+                let mut syncer = create_test_syncer(&temp_dir, &github);
                 let _report = syncer.push().await.unwrap();
             });
         });
@@ -216,11 +217,70 @@ Body content {}.
     group.finish();
 }
 
+/// Issue as the GitHub API returns it, numbered after the `Issue <n>` title that was posted
+fn created_issue(request: &mockito::Request) -> Vec<u8> {
+    let posted: serde_json::Value = serde_json::from_slice(request.body().unwrap()).unwrap();
+    let title = posted["title"].as_str().unwrap();
+    let mut issue: serde_json::Value = serde_json::from_str(include_str!("../tests/fixtures/github_issue.json")).unwrap();
+    issue["number"] = title.trim_start_matches("Issue ").parse::<u64>().unwrap().into();
+    issue["title"] = title.into();
+    serde_json::to_vec(&issue).unwrap()
+}
+
+/// Fresh issues directory with `count` unmapped files
+fn new_issue_files(count: usize) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let issues_dir = temp_dir.path().join("issues");
+    fs::create_dir_all(&issues_dir).unwrap();
+    for i in 1..=count {
+        fs::write(issues_dir.join(format!("FEAT_{}.md", i)), format!("---\ntitle: Issue {}\n---\nBody {}.\n", i, i)).unwrap();
+    }
+    temp_dir
+}
+
+fn bench_parallel_push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel_push");
+    group.sample_size(10);
+
+    // Real HTTP client against a local mock of the GitHub API
+    let mut server = mockito::Server::new();
+    server.mock("GET", "/repos/owner/repo/issues").match_query(Matcher::Any).with_body("[]").create();
+    server.mock("POST", "/repos/owner/repo/issues").with_status(201).with_body_from_request(created_issue).create();
+
+    let rt = Runtime::new().unwrap();
+    let _guard = rt.enter();
+    let github = OctocrabGitHub::with_base_uri("token", &server.url()).unwrap();
+
+    for concurrency in [1, 4, 16] {
+        group.bench_with_input(BenchmarkId::new("create_50_issues", concurrency), &concurrency, |b, &concurrency| {
+            b.to_async(&rt).iter_batched(
+                || new_issue_files(50),
+                |temp_dir| {
+                    let github = &github;
+                    async move {
+                        let issues_dir = temp_dir.path().join("issues");
+                        let mapping_file = issues_dir.join(".issue-mapping.json");
+                        let mut syncer = IssueSyncer::new(github, "owner", "repo", issues_dir, mapping_file)
+                            .unwrap()
+                            .with_concurrency(concurrency);
+                        let report = syncer.push().await.unwrap();
+                        assert_eq!(report.created, 50);
+                    }
+                },
+                BatchSize::PerIteration,
+            );
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_frontmatter_parsing,
     bench_mapping_operations,
     bench_file_scanning,
     bench_dry_run_sync,
+    bench_parallel_push,
 );
 criterion_main!(benches);
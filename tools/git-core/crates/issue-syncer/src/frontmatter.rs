@@ -0,0 +1,384 @@
+//! Frontmatter Lexer
+//!
+//! Splits an issue file into its `---` delimited frontmatter and body without
+//! losing anything on the way back:
+//!
+//! - UTF-8 BOM, `\r\n` line endings and trailing whitespace on the markers are accepted
+//! - A closing `---` on the last line (no trailing newline) is accepted
+//! - Top-level keys keep their order; comments and blank lines are kept where they are
+//! - Errors carry the line and column in the file, counting the opening `---` as line 1
+//!
+//! Values are still YAML: each top-level entry is kept as raw lines and handed to
+//! `serde_yaml` when it is read, so only the keys that change are re-rendered.
+
+use serde::de::DeserializeOwned;
+use std::fmt;
+
+/// Lexing or YAML error with its position in the file (1-based)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrontmatterError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl FrontmatterError {
+    fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self { line, column, message: message.into() }
+    }
+}
+
+impl fmt::Display for FrontmatterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for FrontmatterError {}
+
+/// One top-level frontmatter item
+#[derive(Debug, Clone, PartialEq)]
+enum Item {
+    /// `key: value` line plus its continuation lines (nested values, list items)
+    Entry { key: String, lines: Vec<String> },
+    /// Blank line or comment between entries
+    Trivia(String),
+}
+
+/// Issue file split into frontmatter items and body
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    bom: bool,
+    newline: &'static str,
+    items: Vec<Item>,
+    body: String,
+}
+
+impl Default for Document {
+    fn default() -> Self {
+        Self { bom: false, newline: "\n", items: Vec::new(), body: String::new() }
+    }
+}
+
+impl Document {
+    /// Lex `content`. The body is kept verbatim.
+    pub fn parse(content: &str) -> Result<Self, FrontmatterError> {
+        let (bom, content) = match content.strip_prefix('\u{feff}') {
+            Some(rest) => (true, rest),
+            None => (false, content),
+        };
+
+        let mut lines = content.split_inclusive('\n');
+        let first = lines.next().unwrap_or("");
+        if strip_newline(first).trim_end() != "---" {
+            return Err(FrontmatterError::new(1, 1, "expected `---` to open the frontmatter"));
+        }
+        let newline = if first.ends_with("\r\n") { "\r\n" } else { "\n" };
+
+        let mut items: Vec<Item> = Vec::new();
+        let mut pending: Vec<String> = Vec::new();
+        let mut offset = first.len();
+
+        for (index, raw) in lines.enumerate() {
+            let number = index + 2;
+            let line = strip_newline(raw);
+            offset += raw.len();
+
+            if line.trim_end() == "---" {
+                items.extend(pending.drain(..).map(Item::Trivia));
+                return Ok(Self { bom, newline, items, body: content[offset..].to_string() });
+            }
+
+            let indent = line.len() - line.trim_start_matches([' ', '\t']).len();
+            if let Some(tab) = line[..indent].find('\t') {
+                return Err(FrontmatterError::new(number, tab + 1, "tabs are not allowed for indentation"));
+            }
+
+            let trimmed = line.trim();
+            if trimmed.is_empty() || (indent == 0 && trimmed.starts_with('#')) {
+                pending.push(line.to_string());
+                continue;
+            }
+
+            if indent > 0 || line.starts_with("- ") || line == "-" {
+                match items.last_mut() {
+                    Some(Item::Entry { lines, .. }) => {
+                        lines.append(&mut pending);
+                        lines.push(line.to_string());
+                        continue;
+                    }
+                    _ => {
+                        return Err(FrontmatterError::new(
+                            number,
+                            indent + 1,
+                            "indented line does not belong to a key",
+                        ))
+                    }
+                }
+            }
+
+            let key = entry_key(line).ok_or_else(|| FrontmatterError::new(number, 1, "expected `key: value`"))?;
+            if items.iter().any(|item| matches!(item, Item::Entry { key: k, .. } if *k == key)) {
+                return Err(FrontmatterError::new(number, 1, format!("duplicate key `{}`", key)));
+            }
+            items.extend(pending.drain(..).map(Item::Trivia));
+            items.push(Item::Entry { key, lines: vec![line.to_string()] });
+        }
+
+        Err(FrontmatterError::new(1, 1, "frontmatter is not closed with `---`"))
+    }
+
+    /// Empty frontmatter with `body`
+    pub fn with_body(body: impl Into<String>) -> Self {
+        Self { body: body.into(), ..Default::default() }
+    }
+
+    /// `\n` or `\r\n`, as used by the opening marker
+    pub fn newline(&self) -> &'static str {
+        self.newline
+    }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    pub fn set_body(&mut self, body: impl Into<String>) {
+        self.body = body.into();
+    }
+
+    /// Top-level keys in file order
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.items.iter().filter_map(|item| match item {
+            Item::Entry { key, .. } => Some(key.as_str()),
+            Item::Trivia(_) => None,
+        })
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.keys().any(|k| k == key)
+    }
+
+    /// Frontmatter text between the markers, with `\n` line endings
+    pub fn yaml(&self) -> String {
+        let mut yaml = String::new();
+        for line in self.items.iter().flat_map(item_lines) {
+            yaml.push_str(line);
+            yaml.push('\n');
+        }
+        yaml
+    }
+
+    /// Deserialize the whole frontmatter. YAML errors are mapped to file positions.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, FrontmatterError> {
+        let yaml = self.yaml();
+        let yaml = if yaml.trim().is_empty() { "{}".to_string() } else { yaml };
+        serde_yaml::from_str(&yaml).map_err(|e| yaml_error(&e, 1))
+    }
+
+    /// Value of a top-level key, `None` if missing or not valid YAML
+    pub fn get(&self, key: &str) -> Option<serde_yaml::Value> {
+        let (_, lines) = self.entry(key)?;
+        let mapping: serde_yaml::Mapping = serde_yaml::from_str(&lines.join("\n")).ok()?;
+        mapping.get(key).cloned()
+    }
+
+    /// Set a top-level key in place, or append it. An inline comment on a replaced
+    /// single-line value is kept.
+    pub fn set(&mut self, key: &str, value: impl Into<serde_yaml::Value>) -> Result<(), FrontmatterError> {
+        let mut mapping = serde_yaml::Mapping::new();
+        mapping.insert(key.into(), value.into());
+        let rendered = serde_yaml::to_string(&mapping).map_err(|e| yaml_error(&e, 0))?;
+        let mut new_lines: Vec<String> = rendered.lines().map(str::to_string).collect();
+
+        for item in &mut self.items {
+            if let Item::Entry { key: k, lines } = item {
+                if k == key {
+                    if let (Some(comment), [line]) = (inline_comment(&lines[0]), new_lines.as_mut_slice()) {
+                        line.push(' ');
+                        line.push_str(comment);
+                    }
+                    *lines = new_lines;
+                    return Ok(());
+                }
+            }
+        }
+
+        // Keep trailing comments and blank lines after the new key
+        let at = self.items.iter().rposition(|item| matches!(item, Item::Entry { .. })).map_or(0, |i| i + 1);
+        self.items.insert(at, Item::Entry { key: key.to_string(), lines: new_lines });
+        Ok(())
+    }
+
+    /// Remove a top-level key. Returns whether it was present.
+    pub fn remove(&mut self, key: &str) -> bool {
+        let before = self.items.len();
+        self.items.retain(|item| !matches!(item, Item::Entry { key: k, .. } if k == key));
+        self.items.len() != before
+    }
+
+    /// Render the file back. Untouched entries, comments and the body come out as they went in.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        if self.bom {
+            out.push('\u{feff}');
+        }
+        out.push_str("---");
+        out.push_str(self.newline);
+        for line in self.items.iter().flat_map(item_lines) {
+            out.push_str(line);
+            out.push_str(self.newline);
+        }
+        out.push_str("---");
+        out.push_str(self.newline);
+        out.push_str(&self.body);
+        out
+    }
+
+    /// File line of the entry's first line and its lines
+    fn entry(&self, key: &str) -> Option<(usize, &[String])> {
+        let mut line = 2;
+        for item in &self.items {
+            match item {
+                Item::Entry { key: k, lines } if k == key => return Some((line, lines)),
+                Item::Entry { lines, .. } => line += lines.len(),
+                Item::Trivia(_) => line += 1,
+            }
+        }
+        None
+    }
+
+    /// File line (1-based) where `key` is defined
+    pub fn line_of(&self, key: &str) -> Option<usize> {
+        self.entry(key).map(|(line, _)| line)
+    }
+}
+
+fn item_lines(item: &Item) -> Box<dyn Iterator<Item = &str> + '_> {
+    match item {
+        Item::Entry { lines, .. } => Box::new(lines.iter().map(String::as_str)),
+        Item::Trivia(line) => Box::new(std::iter::once(line.as_str())),
+    }
+}
+
+fn strip_newline(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// Key of a top-level `key: value` line, unquoted
+fn entry_key(line: &str) -> Option<String> {
+    let key = if let Some(quote @ ('"' | '\'')) = line.chars().next() {
+        let end = line[1..].find(quote)? + 1;
+        if !line[end + 1..].starts_with(':') {
+            return None;
+        }
+        &line[1..end]
+    } else {
+        let colon = line.match_indices(':').map(|(i, _)| i).find(|&i| {
+            line[i + 1..].is_empty() || line[i + 1..].starts_with([' ', '\t'])
+        })?;
+        line[..colon].trim_end()
+    };
+    (!key.is_empty()).then(|| key.to_string())
+}
+
+/// `# comment` at the end of a line, outside of quotes
+fn inline_comment(line: &str) -> Option<&str> {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, '#') if previous == ' ' || previous == '\t' => return Some(&line[i..]),
+            _ => {}
+        }
+        previous = c;
+    }
+    None
+}
+
+/// Map a `serde_yaml` error to a file position. `line_offset` is the number of file lines
+/// before the parsed YAML.
+fn yaml_error(error: &serde_yaml::Error, line_offset: usize) -> FrontmatterError {
+    match error.location() {
+        Some(location) => {
+            // Drop serde_yaml's own (YAML-relative) position from the message
+            let message = error
+                .to_string()
+                .replacen(&format!(" at line {} column {}", location.line(), location.column()), "", 1);
+            FrontmatterError::new(location.line() + line_offset, location.column(), message)
+        }
+        None => FrontmatterError::new(1, 1, error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_is_lossless() {
+        let content = "\u{feff}---  \r\n# Synced with GitHub\r\ntitle: \"A\"   # keep\r\nlabels:\r\n- bug\r\n\r\n- ui\r\n\r\npriority: high\r\n---\r\nBody\r\n---\r\nMore";
+
+        let doc = Document::parse(content).unwrap();
+        assert_eq!(doc.keys().collect::<Vec<_>>(), vec!["title", "labels", "priority"]);
+        assert_eq!(doc.newline(), "\r\n");
+        assert_eq!(doc.body(), "Body\r\n---\r\nMore");
+        assert_eq!(doc.get("title"), Some("A".into()));
+        assert_eq!(doc.line_of("priority"), Some(9));
+        assert_eq!(Document::parse(&doc.render()).unwrap(), doc);
+        assert_eq!(doc.render(), content.replacen("---  ", "---", 1));
+    }
+
+    #[test]
+    fn test_closing_marker_at_eof() {
+        let doc = Document::parse("---\ntitle: A\n--- \t").unwrap();
+        assert_eq!(doc.get("title"), Some("A".into()));
+        assert_eq!(doc.body(), "");
+
+        let doc = Document::parse("---\n---").unwrap();
+        assert_eq!(doc.keys().count(), 0);
+    }
+
+    #[test]
+    fn test_set_and_remove_keep_order_and_comments() {
+        let mut doc = Document::parse("---\ntitle: A\nstate: open  # synced\nlabels: [bug]\n# trailing\n---\nBody\n").unwrap();
+
+        doc.set("state", "closed").unwrap();
+        doc.set("labels", vec!["bug", "ui"]).unwrap();
+        doc.set("milestone", "v1").unwrap();
+        doc.remove("title");
+
+        assert_eq!(
+            doc.render(),
+            "---\nstate: closed # synced\nlabels:\n- bug\n- ui\nmilestone: v1\n# trailing\n---\nBody\n"
+        );
+    }
+
+    #[test]
+    fn test_errors_have_positions() {
+        let cases = [
+            ("title: A\n", 1, 1, "expected `---`"),
+            ("---\ntitle: A\n", 1, 1, "not closed"),
+            ("---\ntitle: A\n\tlabels: []\n---\n", 3, 1, "tabs"),
+            ("---\n  - bug\n---\n", 2, 3, "does not belong"),
+            ("---\ntitle: A\njust text\n---\n", 3, 1, "expected `key: value`"),
+            ("---\ntitle: A\ntitle: B\n---\n", 3, 1, "duplicate key `title`"),
+        ];
+        for (content, line, column, message) in cases {
+            let err = Document::parse(content).unwrap_err();
+            assert_eq!((err.line, err.column), (line, column), "{}", err);
+            assert!(err.message.contains(message), "{}", err);
+        }
+
+        #[derive(Debug, serde::Deserialize)]
+        #[allow(dead_code)]
+        struct Fm {
+            parent: u64,
+        }
+        let err = Document::parse("---\ntitle: A\nparent: epic\n---\n").unwrap().deserialize::<Fm>().unwrap_err();
+        assert_eq!((err.line, err.column), (3, 9), "{}", err);
+        assert!(!err.message.contains(" at line "), "{}", err);
+    }
+}
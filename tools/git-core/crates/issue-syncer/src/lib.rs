@@ -0,0 +1,11 @@
+//! Issue Syncer Library
+//!
+//! The sync engine behind both the `issue-syncer` binary and `gc issue sync`.
+//! GitHub access goes through the `gc-core` [`GitHubPort`](gc_core::ports::GitHubPort).
+
+pub mod frontmatter;
+pub mod mapping;
+pub mod parser;
+pub mod rate_limit;
+pub mod syncer;
+pub mod watch;
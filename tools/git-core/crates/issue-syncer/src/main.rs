@@ -0,0 +1,244 @@
+//! Issue Syncer - Bidirectional sync between .md files and GitHub Issues
+//!
+//! High-performance Rust tool replacing sync-issues.ps1
+
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use gc_adapter_github::OctocrabGitHub;
+use issue_syncer::syncer::{self, ChangeKind, ClosedPolicy, ConflictStrategy, DirectoryMapping, IssueSyncer};
+use issue_syncer::watch::{self, WatchConfig};
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::{info, Level};
+use tracing_subscriber::FmtSubscriber;
+
+#[derive(Parser)]
+#[command(name = "issue-syncer")]
+#[command(about = "Bidirectional sync between .md files and GitHub Issues", long_about = None)]
+#[command(version)]
+struct Cli {
+    /// GitHub repository (format: owner/repo)
+    #[arg(short, long)]
+    repo: Option<String>,
+
+    /// GitHub token
+    #[arg(short, long)]
+    token: Option<String>,
+
+    /// Issues directory path
+    #[arg(long, default_value = ".github/issues")]
+    issues_dir: PathBuf,
+
+    /// Enable dry-run mode (no actual changes)
+    #[arg(long, default_value = "false")]
+    dry_run: bool,
+
+    /// What to do when a file and its issue both changed since the last sync
+    #[arg(long, default_value = "conflict-file", value_parser = ["local-wins", "remote-wins", "fail", "conflict-file"])]
+    on_conflict: String,
+
+    /// What to do with files whose issue was closed on GitHub
+    #[arg(long, default_value = "archive", value_parser = ["delete", "archive", "keep-and-mark"])]
+    on_close: String,
+
+    /// Only write new files for issues with this label (repeatable, all must match)
+    #[arg(short, long = "label")]
+    labels: Vec<String>,
+
+    /// What directory names of nested files become: nothing, labels, or the milestone (top-level directory)
+    #[arg(long, default_value = "none", value_parser = ["none", "labels", "milestone"])]
+    dirs_as: String,
+
+    /// Maximum number of issues created or updated at the same time
+    #[arg(long, default_value_t = syncer::DEFAULT_CONCURRENCY)]
+    concurrency: usize,
+
+    /// Enable verbose logging
+    #[arg(short, long, default_value = "false")]
+    verbose: bool,
+
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Bidirectional sync: push local files + pull new issues, remote edits and closed issues
+    Sync,
+
+    /// Push local .md files to GitHub Issues
+    Push,
+
+    /// Write files for new issues, update files of edited issues and archive files of closed issues
+    Pull,
+
+    /// Show current mapping statistics
+    Status,
+
+    /// Watch the issues directory and keep it in sync until Ctrl+C
+    Watch {
+        /// Quiet period after the last file change before pushing (milliseconds)
+        #[arg(long, default_value = "500")]
+        debounce_ms: u64,
+
+        /// Interval between GitHub polls for remote changes (seconds)
+        #[arg(long, default_value = "60")]
+        poll_secs: u64,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    // Setup logging
+    let log_level = if cli.verbose { Level::DEBUG } else { Level::INFO };
+    let subscriber = FmtSubscriber::builder()
+        .with_max_level(log_level)
+        .finish();
+    tracing::subscriber::set_global_default(subscriber)
+        .context("Failed to set tracing subscriber")?;
+
+    // Parse repo
+    let repo = cli.repo
+        .or_else(|| std::env::var("GITHUB_REPOSITORY").ok())
+        .context("Repository not specified (use --repo or GITHUB_REPOSITORY)")?;
+
+    let (owner, repo_name) = repo
+        .split_once('/')
+        .context("Invalid repo format (expected owner/repo)")?;
+
+    // Get GitHub token
+    let token = cli.token
+        .or_else(|| std::env::var("GITHUB_TOKEN").ok())
+        .context("GitHub token not provided (use --token or GITHUB_TOKEN)")?;
+
+    // Create GitHub client
+    let github = OctocrabGitHub::with_token(token);
+
+    // Setup paths
+    let issues_dir = if cli.issues_dir.is_absolute() {
+        cli.issues_dir
+    } else {
+        std::env::current_dir()?.join(&cli.issues_dir)
+    };
+
+    let mapping_file = issues_dir.join(".issue-mapping.json");
+
+    // Create syncer
+    let mut syncer = IssueSyncer::new(&github, owner, repo_name, issues_dir, mapping_file)?
+        .with_dry_run(cli.dry_run)
+        .with_conflict_strategy(cli.on_conflict.parse::<ConflictStrategy>()?)
+        .with_closed_policy(cli.on_close.parse::<ClosedPolicy>()?)
+        .with_label_filter(cli.labels)
+        .with_directory_mapping(cli.dirs_as.parse::<DirectoryMapping>()?)
+        .with_concurrency(cli.concurrency);
+
+    // Execute command
+    match cli.command {
+        Commands::Sync => {
+            info!("Starting bidirectional sync");
+            let report = syncer.sync_all().await?;
+            print_report(&report);
+        }
+        Commands::Push => {
+            info!("Pushing local files to GitHub");
+            let report = syncer.push().await?;
+            print_report(&report);
+        }
+        Commands::Pull => {
+            info!("Pulling issue changes from GitHub");
+            let report = syncer.pull().await?;
+            print_report(&report);
+        }
+        Commands::Watch { debounce_ms, poll_secs } => {
+            let config = WatchConfig {
+                debounce: Duration::from_millis(debounce_ms),
+                poll_interval: Duration::from_secs(poll_secs.max(1)),
+            };
+            watch::watch(&mut syncer, config, shutdown_signal(), print_report).await?;
+        }
+        Commands::Status => {
+            let status = syncer.status()?;
+            println!("📊 Mapping Statistics:");
+            println!("  Total mappings: {}", syncer.mapping().len());
+            println!("  Mapped files:   {}", status.mapped.len());
+            for file in &status.unmapped {
+                println!("  + {} (not yet pushed)", file);
+            }
+            for (file, number) in &status.missing {
+                println!("  - {} → #{} (file deleted)", file, number);
+            }
+            for (file, reason) in &status.invalid {
+                println!("  ✗ {}: {}", file, reason);
+            }
+            for file in &status.conflicts {
+                println!("  ! {} (unresolved conflict)", file);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolves on Ctrl+C (or SIGTERM on Unix)
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+    info!("Shutdown requested");
+}
+
+fn print_report(report: &syncer::SyncReport) {
+    for (file, reason) in &report.invalid {
+        println!("✗ {}: {}", file, reason);
+    }
+    for change in &report.changes {
+        let (sign, action) = match change.kind {
+            ChangeKind::Create => ("+", "create"),
+            ChangeKind::Update => ("~", "update"),
+            ChangeKind::Close => ("-", "close"),
+            ChangeKind::Delete => ("-", "delete"),
+            ChangeKind::Archive => ("-", "archive"),
+            ChangeKind::Mark => ("~", "mark closed"),
+            ChangeKind::Pull => ("<", "pull"),
+            ChangeKind::Materialize => ("<", "new file"),
+            ChangeKind::Conflict => ("!", "conflict"),
+        };
+        let issue = change.issue.map(|n| format!(" #{}", n)).unwrap_or_default();
+        println!("{} {}{} {} ({})", sign, action, issue, change.file, change.title);
+        for line in &change.diff {
+            println!("    {}", line);
+        }
+    }
+
+    println!("\n✅ Sync Complete");
+    println!("  Created:   {}", report.created);
+    println!("  Updated:   {}", report.updated);
+    println!("  Closed:    {}", report.closed);
+    println!("  Deleted:   {}", report.deleted);
+    println!("  Archived:  {}", report.archived);
+    println!("  Pulled:    {}", report.pulled);
+    println!("  Conflicts: {}", report.conflicts);
+    println!("  Unchanged: {}", report.unchanged);
+    println!("  Skipped:   {}", report.skipped);
+    println!("  Errors:    {}", report.errors);
+    println!("  Total:     {}", report.total_operations());
+}
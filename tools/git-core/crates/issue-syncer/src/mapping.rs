@@ -0,0 +1,292 @@
+//! Issue Mapping Storage
+//!
+//! Manages bidirectional mapping between local .md files and GitHub Issue numbers.
+//!
+//! # File Format
+//! ```json
+//! {
+//!   "FEAT_my-feature.md": {
+//!     "issue": 42,
+//!     "hash": "9f86d08…",
+//!     "updated_at": "2025-01-01T12:00:00+00:00"
+//!   },
+//!   "BUG_login-error.md": 43,
+//!   "TASK_subtask.md": { "issue": 44, "hash": "…", "parent": 42 },
+//!   "epics/FEAT_login.md": { "issue": 45, "hash": "…" }
+//! }
+//! ```
+//!
+//! Keys are paths relative to the issues directory, with `/` separators. Entries whose
+//! file moved to another directory are re-keyed by the syncer on the next run.
+//!
+//! `hash` is the content hash both sides had after the last sync and `updated_at`
+//! the remote timestamp at that point; together they tell local and remote edits apart.
+//! `parent` is the issue it was last linked to as a sub-issue, so the link is made once.
+//! Plain numbers (the original format) are still accepted and are upgraded on save.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Sync state of a single file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RawEntry")]
+pub struct MappingEntry {
+    pub issue: u64,
+    /// Content hash at the last successful sync
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// Remote `updated_at` at the last successful sync
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// A `.conflict.md` file was written and is waiting to be resolved
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub conflict: bool,
+    /// Parent issue this issue was last linked to as a sub-issue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<u64>,
+}
+
+impl MappingEntry {
+    pub fn new(issue: u64) -> Self {
+        Self { issue, hash: None, updated_at: None, conflict: false, parent: None }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawEntry {
+    Number(u64),
+    Entry {
+        issue: u64,
+        #[serde(default)]
+        hash: Option<String>,
+        #[serde(default)]
+        updated_at: Option<String>,
+        #[serde(default)]
+        conflict: bool,
+        #[serde(default)]
+        parent: Option<u64>,
+    },
+}
+
+impl From<RawEntry> for MappingEntry {
+    fn from(raw: RawEntry) -> Self {
+        match raw {
+            RawEntry::Number(issue) => Self::new(issue),
+            RawEntry::Entry { issue, hash, updated_at, conflict, parent } => Self { issue, hash, updated_at, conflict, parent },
+        }
+    }
+}
+
+/// Bidirectional mapping between files and issue numbers
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct IssueMapping {
+    #[serde(flatten)]
+    file_to_issue: BTreeMap<String, MappingEntry>,
+}
+
+impl IssueMapping {
+    /// Load mapping from file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = std::fs::read_to_string(path.as_ref())
+            .context("Failed to read mapping file")?;
+
+        let mapping: Self = serde_json::from_str(&content)
+            .context("Failed to parse mapping JSON")?;
+
+        Ok(mapping)
+    }
+
+    /// Save mapping to file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.file_to_issue)
+            .context("Failed to serialize mapping")?;
+
+        std::fs::write(path.as_ref(), json)
+            .with_context(|| format!("Failed to write mapping to {}", path.as_ref().display()))?;
+
+        Ok(())
+    }
+
+    /// Get issue number for a file
+    pub fn get_issue(&self, file: &str) -> Option<u64> {
+        self.file_to_issue.get(file).map(|e| e.issue)
+    }
+
+    /// Get the full sync state for a file
+    pub fn get_entry(&self, file: &str) -> Option<&MappingEntry> {
+        self.file_to_issue.get(file)
+    }
+
+    /// Get file path for an issue number
+    pub fn get_file(&self, issue_number: u64) -> Option<String> {
+        self.file_to_issue
+            .iter()
+            .find(|(_, entry)| entry.issue == issue_number)
+            .map(|(file, _)| file.clone())
+    }
+
+    /// Add a new mapping (without sync state)
+    pub fn add(&mut self, file: String, issue_number: u64) {
+        self.file_to_issue.insert(file, MappingEntry::new(issue_number));
+    }
+
+    /// Record the state both sides agreed on after a sync (the parent link is kept)
+    pub fn record(&mut self, file: String, issue_number: u64, hash: String, updated_at: Option<String>) {
+        let parent = self.file_to_issue.get(&file).filter(|e| e.issue == issue_number).and_then(|e| e.parent);
+        self.file_to_issue.insert(file, MappingEntry {
+            issue: issue_number,
+            hash: Some(hash),
+            updated_at,
+            conflict: false,
+            parent,
+        });
+    }
+
+    /// Move a file's sync state to a new key (the file was moved)
+    pub fn rename(&mut self, file: &str, new_file: String) {
+        if let Some(entry) = self.file_to_issue.remove(file) {
+            self.file_to_issue.insert(new_file, entry);
+        }
+    }
+
+    /// Remember the parent a file's issue was linked to
+    pub fn set_parent(&mut self, file: &str, parent: Option<u64>) {
+        if let Some(entry) = self.file_to_issue.get_mut(file) {
+            entry.parent = parent;
+        }
+    }
+
+    /// Flag (or clear) an unresolved conflict for a file
+    pub fn set_conflict(&mut self, file: &str, conflict: bool) {
+        if let Some(entry) = self.file_to_issue.get_mut(file) {
+            entry.conflict = conflict;
+        }
+    }
+
+    /// Remove a mapping by file name
+    pub fn remove_by_file(&mut self, file: &str) -> Option<u64> {
+        self.file_to_issue.remove(file).map(|e| e.issue)
+    }
+
+    /// Remove a mapping by issue number
+    pub fn remove_by_issue(&mut self, issue_number: u64) -> Option<String> {
+        let file = self.get_file(issue_number)?;
+        self.file_to_issue.remove(&file);
+        Some(file)
+    }
+
+    /// Check if a file is mapped
+    pub fn contains_file(&self, file: &str) -> bool {
+        self.file_to_issue.contains_key(file)
+    }
+
+    /// Check if an issue is mapped
+    pub fn contains_issue(&self, issue_number: u64) -> bool {
+        self.file_to_issue.values().any(|e| e.issue == issue_number)
+    }
+
+    /// Get all mapped files
+    pub fn files(&self) -> Vec<String> {
+        self.file_to_issue.keys().cloned().collect()
+    }
+
+    /// Get all mapped issue numbers
+    pub fn issues(&self) -> Vec<u64> {
+        self.file_to_issue.values().map(|e| e.issue).collect()
+    }
+
+    /// Get the number of mappings
+    pub fn len(&self) -> usize {
+        self.file_to_issue.len()
+    }
+
+    /// Check if mapping is empty
+    pub fn is_empty(&self) -> bool {
+        self.file_to_issue.is_empty()
+    }
+
+    /// Clear all mappings
+    pub fn clear(&mut self) {
+        self.file_to_issue.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_add_and_get() {
+        let mut mapping = IssueMapping::default();
+        mapping.add("FEAT_test.md".to_string(), 42);
+
+        assert_eq!(mapping.get_issue("FEAT_test.md"), Some(42));
+        assert_eq!(mapping.get_file(42), Some("FEAT_test.md".to_string()));
+    }
+
+    #[test]
+    fn test_remove_by_file() {
+        let mut mapping = IssueMapping::default();
+        mapping.add("FEAT_test.md".to_string(), 42);
+
+        let removed = mapping.remove_by_file("FEAT_test.md");
+        assert_eq!(removed, Some(42));
+        assert!(!mapping.contains_file("FEAT_test.md"));
+    }
+
+    #[test]
+    fn test_remove_by_issue() {
+        let mut mapping = IssueMapping::default();
+        mapping.add("FEAT_test.md".to_string(), 42);
+
+        let removed = mapping.remove_by_issue(42);
+        assert_eq!(removed, Some("FEAT_test.md".to_string()));
+        assert!(!mapping.contains_issue(42));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let mut mapping = IssueMapping::default();
+        mapping.add("FEAT_test.md".to_string(), 42);
+        mapping.add("BUG_error.md".to_string(), 43);
+
+        let temp_file = NamedTempFile::new().unwrap();
+        mapping.save(temp_file.path()).unwrap();
+
+        let loaded = IssueMapping::load(temp_file.path()).unwrap();
+        assert_eq!(loaded.get_issue("FEAT_test.md"), Some(42));
+        assert_eq!(loaded.get_issue("BUG_error.md"), Some(43));
+    }
+
+    #[test]
+    fn test_legacy_numbers_are_upgraded() {
+        let temp_file = NamedTempFile::new().unwrap();
+        std::fs::write(temp_file.path(), r#"{"FEAT_a.md": 1, "FEAT_b.md": {"issue": 2, "hash": "abc"}}"#).unwrap();
+
+        let mut mapping = IssueMapping::load(temp_file.path()).unwrap();
+        assert_eq!(mapping.get_entry("FEAT_a.md"), Some(&MappingEntry::new(1)));
+        assert_eq!(mapping.get_entry("FEAT_b.md").unwrap().hash.as_deref(), Some("abc"));
+
+        mapping.record("FEAT_a.md".to_string(), 1, "def".to_string(), Some("2025-01-01T00:00:00+00:00".to_string()));
+        mapping.save(temp_file.path()).unwrap();
+        let saved = std::fs::read_to_string(temp_file.path()).unwrap();
+        assert!(saved.contains(r#""hash": "def""#));
+        assert!(!saved.contains("conflict"));
+    }
+
+    #[test]
+    fn test_bidirectional_lookup() {
+        let mut mapping = IssueMapping::default();
+        mapping.add("FEAT_test.md".to_string(), 42);
+
+        // Forward lookup
+        assert_eq!(mapping.get_issue("FEAT_test.md"), Some(42));
+
+        // Reverse lookup
+        assert_eq!(mapping.get_file(42), Some("FEAT_test.md".to_string()));
+    }
+}
@@ -0,0 +1,279 @@
+//! YAML Frontmatter Parser
+//!
+//! Extracts GitHub Issue metadata from Markdown files with YAML frontmatter.
+//!
+//! # Format
+//! ```markdown
+//! ---
+//! title: "Issue title"
+//! labels:
+//!   - enhancement
+//!   - rust
+//! assignees: []
+//! milestone: v1.2
+//! ---
+//!
+//! Issue body content here...
+//! ```
+//!
+//! The full schema (priority, estimate, due, ...) is [`gc_core::frontmatter::IssueFrontmatter`];
+//! only the fields GitHub knows about end up in [`IssueData`].
+
+use anyhow::{Context, Result};
+use gc_core::frontmatter::IssueFrontmatter;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+use crate::frontmatter::Document;
+
+/// Parsed GitHub Issue data from frontmatter
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IssueData {
+    pub title: String,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub assignees: Vec<String>,
+    /// Milestone title
+    #[serde(default)]
+    pub milestone: Option<String>,
+    /// `open` or `closed`
+    #[serde(default)]
+    pub state: Option<String>,
+    /// Parent issue number (linked as a GitHub sub-issue)
+    #[serde(default)]
+    pub parent: Option<u64>,
+    pub body: String,
+}
+
+/// Parse a markdown file with YAML frontmatter
+///
+/// # Example
+/// ```no_run
+/// # use issue_syncer::parser::parse_issue_file;
+/// let issue = parse_issue_file("issues/FEAT_my-feature.md")?;
+/// println!("Title: {}", issue.title);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn parse_issue_file<P: AsRef<Path>>(path: P) -> Result<IssueData> {
+    let content = std::fs::read_to_string(path.as_ref())
+        .with_context(|| format!("Failed to read file: {}", path.as_ref().display()))?;
+
+    parse_frontmatter(&content)
+}
+
+/// Parse frontmatter from markdown content
+///
+/// Lexing and YAML errors carry the line and column in the file; schema
+/// problems are listed together.
+pub fn parse_frontmatter(content: &str) -> Result<IssueData> {
+    let doc = Document::parse(content)?;
+    let frontmatter: IssueFrontmatter = doc.deserialize().context("Invalid frontmatter")?;
+    let errors = frontmatter.validate();
+    if !errors.is_empty() {
+        anyhow::bail!("Invalid frontmatter: {}", errors.join("; "));
+    }
+
+    Ok(IssueData {
+        title: frontmatter.title,
+        labels: frontmatter.labels,
+        assignees: frontmatter.assignees,
+        milestone: frontmatter.milestone,
+        state: frontmatter.state,
+        parent: frontmatter.parent,
+        body: normalize_body(doc.body()),
+    })
+}
+
+/// Normalize a body for comparison with GitHub (line endings, surrounding whitespace)
+pub fn normalize_body(body: &str) -> String {
+    body.replace("\r\n", "\n").trim().to_string()
+}
+
+/// Hash of the fields that are synced, used to tell which side changed since the last sync.
+///
+/// Labels and assignees are sorted; empty lists hash the same as missing ones. Milestone and
+/// state only count when set, so hashes recorded before they existed stay valid. The parent
+/// link is tracked separately in the mapping.
+pub fn content_hash(data: &IssueData) -> String {
+    let mut labels = data.labels.clone();
+    labels.sort();
+    let mut assignees = data.assignees.clone();
+    assignees.sort();
+
+    let mut hasher = Sha256::new();
+    for part in [data.title.as_str(), &normalize_body(&data.body), &labels.join(","), &assignees.join(",")] {
+        hasher.update(part.as_bytes());
+        hasher.update([0u8]);
+    }
+    for (key, value) in [("milestone", &data.milestone), ("state", &data.state)] {
+        if let Some(value) = value {
+            hasher.update(format!("{}={}", key, value).as_bytes());
+            hasher.update([0u8]);
+        }
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Render an issue file, keeping the other keys, comments, key order and line endings of `existing`
+pub fn render_issue_file(existing: Option<&str>, data: &IssueData) -> Result<String> {
+    let mut doc = existing.and_then(|content| Document::parse(content).ok()).unwrap_or_default();
+
+    doc.set("title", data.title.as_str())?;
+    for (key, values) in [("labels", &data.labels), ("assignees", &data.assignees)] {
+        if !values.is_empty() || doc.contains_key(key) {
+            doc.set(key, serde_yaml::to_value(values)?)?;
+        }
+    }
+    for (key, value) in [("milestone", &data.milestone), ("state", &data.state)] {
+        match value {
+            Some(value) => doc.set(key, value.as_str())?,
+            None => {
+                doc.remove(key);
+            }
+        }
+    }
+    if let Some(parent) = data.parent {
+        doc.set("parent", parent)?;
+    }
+
+    let newline = doc.newline();
+    doc.set_body(format!("{nl}{}{nl}", normalize_body(&data.body).replace('\n', newline), nl = newline));
+    Ok(doc.render())
+}
+
+/// Set frontmatter keys of an issue file, leaving the other keys, comments and the body untouched
+pub fn set_frontmatter_fields(content: &str, fields: &[(&str, String)]) -> Result<String> {
+    let mut doc = Document::parse(content)?;
+    for (key, value) in fields {
+        doc.set(key, value.as_str())?;
+    }
+    Ok(doc.render())
+}
+
+/// Read a string frontmatter key
+pub fn frontmatter_field(content: &str, key: &str) -> Option<String> {
+    let doc = Document::parse(content).ok()?;
+    doc.get(key)?.as_str().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_frontmatter_basic() {
+        let content = r#"---
+title: "Test Issue"
+labels:
+  - bug
+  - urgent
+assignees:
+  - john
+---
+
+This is the issue body.
+"#;
+
+        let issue = parse_frontmatter(content).unwrap();
+        assert_eq!(issue.title, "Test Issue");
+        assert_eq!(issue.labels, vec!["bug", "urgent"]);
+        assert_eq!(issue.assignees, vec!["john"]);
+        assert_eq!(issue.body, "This is the issue body.");
+    }
+
+    #[test]
+    fn test_parse_frontmatter_no_labels() {
+        let content = r#"---
+title: "Simple Issue"
+---
+
+Body content.
+"#;
+
+        let issue = parse_frontmatter(content).unwrap();
+        assert_eq!(issue.title, "Simple Issue");
+        assert!(issue.labels.is_empty());
+        assert!(issue.assignees.is_empty());
+    }
+
+    #[test]
+    fn test_parse_frontmatter_multiline_body() {
+        let content = r#"---
+title: "Multi-line"
+---
+
+Line 1
+Line 2
+Line 3
+"#;
+
+        let issue = parse_frontmatter(content).unwrap();
+        assert!(issue.body.contains("Line 1"));
+        assert!(issue.body.contains("Line 2"));
+        assert!(issue.body.contains("Line 3"));
+    }
+
+    #[test]
+    fn test_render_round_trip_keeps_extra_keys() {
+        let original = "---\ntitle: Old\npriority: high\n---\n\nOld body\n";
+        let data = IssueData {
+            title: "New".into(),
+            labels: vec!["bug".into()],
+            milestone: Some("v1".into()),
+            body: "New body".into(),
+            ..Default::default()
+        };
+
+        let rendered = render_issue_file(Some(original), &data).unwrap();
+        assert!(rendered.contains("priority: high"));
+        assert!(!rendered.contains("assignees"));
+
+        let parsed = parse_frontmatter(&rendered).unwrap();
+        assert_eq!(content_hash(&parsed), content_hash(&data));
+    }
+
+    #[test]
+    fn test_invalid_schema_lists_every_problem() {
+        let content = "---\ntitle: A\npriority: urgent\ndue: tomorrow\n---\nBody";
+        let err = parse_frontmatter(content).unwrap_err().to_string();
+        assert!(err.contains("priority 'urgent'") && err.contains("due 'tomorrow'"), "{}", err);
+
+        let err = format!("{:#}", parse_frontmatter("---\ntitle: A\nparent: epic\n---\n").unwrap_err());
+        assert!(err.contains("parent"), "{}", err);
+    }
+
+    #[test]
+    fn test_set_frontmatter_fields_keeps_body() {
+        let original = "---\ntitle: Done\n---\n\nBody\r\n---\nMore\n";
+        let updated = set_frontmatter_fields(original, &[("state", "closed".into())]).unwrap();
+
+        assert!(updated.ends_with("---\n\nBody\r\n---\nMore\n"));
+        assert_eq!(frontmatter_field(&updated, "state").as_deref(), Some("closed"));
+        assert_eq!(parse_frontmatter(&updated).unwrap().title, "Done");
+    }
+
+    #[test]
+    fn test_parse_frontmatter_keeps_horizontal_rules() {
+        let content = "---\r\ntitle: A\r\n---\r\nIntro\n\n---\n\nMore\n";
+
+        let issue = parse_frontmatter(content).unwrap();
+        assert_eq!(issue.title, "A");
+        assert_eq!(issue.body, "Intro\n\n---\n\nMore");
+        assert!(parse_frontmatter("---\ntitle: A\n").is_err());
+    }
+
+    #[test]
+    fn test_windows_file_round_trip() {
+        let content = "\u{feff}---\r\ntitle: A  # from Windows\r\npriority: low\r\n---";
+        assert_eq!(parse_frontmatter(content).unwrap().body, "");
+
+        let data = IssueData { title: "B".into(), body: "One\nTwo".into(), ..Default::default() };
+        let rendered = render_issue_file(Some(content), &data).unwrap();
+        assert_eq!(rendered, "\u{feff}---\r\ntitle: B # from Windows\r\npriority: low\r\n---\r\n\r\nOne\r\nTwo\r\n");
+
+        let err = format!("{:#}", parse_frontmatter("---\ntitle: A\nlabels: [bug\n---\n").unwrap_err());
+        assert!(err.starts_with("Invalid frontmatter: line 4, column 1: did not find expected"), "{}", err);
+    }
+}
@@ -0,0 +1,104 @@
+//! Rate-Limit Back-off
+//!
+//! Requests running concurrently share one back-off: when any of them hits GitHub's
+//! rate limit ([`CoreError::RateLimited`]), every request waits before its next call,
+//! with the delay doubling on each retry.
+
+use gc_core::ports::{CoreError, Result};
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::warn;
+
+/// Shared retry policy for GitHub calls
+#[derive(Debug)]
+pub struct Backoff {
+    initial: Duration,
+    max_retries: u32,
+    resume_at: Mutex<Option<Instant>>,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1), 5)
+    }
+}
+
+impl Backoff {
+    /// Wait `initial`, then twice as long on each further retry, at most `max_retries` times
+    pub fn new(initial: Duration, max_retries: u32) -> Self {
+        Self { initial, max_retries, resume_at: Mutex::new(None) }
+    }
+
+    /// Run `request`, retrying while it is rate limited
+    pub async fn call<T, F, Fut>(&self, mut request: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            self.wait().await;
+            match request().await {
+                Err(CoreError::RateLimited(message)) if attempt < self.max_retries => {
+                    let delay = self.initial * 2u32.saturating_pow(attempt);
+                    warn!("Rate limited ({}), pausing requests for {:?}", message, delay);
+                    self.pause(delay);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn wait(&self) {
+        let resume_at = *self.resume_at.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(resume_at) = resume_at {
+            tokio::time::sleep_until(resume_at).await;
+        }
+    }
+
+    fn pause(&self, delay: Duration) {
+        let until = Instant::now() + delay;
+        let mut resume_at = self.resume_at.lock().unwrap_or_else(|e| e.into_inner());
+        if resume_at.is_none_or(|current| current < until) {
+            *resume_at = Some(until);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn test_retries_rate_limited_calls() {
+        let backoff = Backoff::new(Duration::from_millis(1), 2);
+        let calls = AtomicU32::new(0);
+
+        let result = backoff
+            .call(|| async {
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(CoreError::RateLimited("secondary rate limit".into())),
+                    n => Ok(n),
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 1);
+
+        let result: Result<()> = backoff.call(|| async { Err(CoreError::RateLimited("limit".into())) }).await;
+        assert!(matches!(result, Err(CoreError::RateLimited(_))));
+
+        calls.store(0, Ordering::SeqCst);
+        let result: Result<()> = backoff
+            .call(|| async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(CoreError::GitHub("Not Found".into()))
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1, "other errors are not retried");
+    }
+}
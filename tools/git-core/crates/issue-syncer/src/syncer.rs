@@ -0,0 +1,1676 @@
+//! Core Issue Syncer Logic
+//!
+//! Bidirectional sync between `.github/issues/**/*.md` files and GitHub Issues.
+//!
+//! Files may live in nested directories (`epics/FEAT_login.md`); they are keyed by their
+//! path relative to the issues directory, and directory names can become labels or the
+//! milestone ([`DirectoryMapping`]).
+//!
+//! - New files create issues, edited files update them (only when something changed)
+//! - Deleted files close their issue
+//! - Files whose issue was closed on GitHub are archived, marked or deleted ([`ClosedPolicy`])
+//! - Issues edited on GitHub are written back to their file
+//! - Open issues without a file are written as `TYPE_slug.md` (same naming as `gc task`)
+//!
+//! Which side changed is decided against the content hash and remote `updated_at`
+//! stored in the mapping at the last sync. When both sides changed, the configured
+//! [`ConflictStrategy`] decides.
+//!
+//! Creates and updates are planned during a sequential pass over the files, then sent
+//! to GitHub concurrently (at most [`IssueSyncer::with_concurrency`] at a time) behind a
+//! shared [`Backoff`] for rate limits.
+
+use anyhow::{Context, Result};
+use futures::stream::{self, StreamExt};
+use gc_core::naming::{issue_file_name, type_from_issue};
+use gc_core::ports::GitHubPort;
+use gc_core::{Issue, IssuePatch};
+use similar::{ChangeTag, TextDiff};
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+use tracing::{info, warn};
+use walkdir::WalkDir;
+
+use crate::mapping::{IssueMapping, MappingEntry};
+use crate::parser::{
+    content_hash, frontmatter_field, normalize_body, parse_issue_file, render_issue_file, set_frontmatter_fields,
+    IssueData,
+};
+use crate::rate_limit::Backoff;
+
+/// Default number of GitHub writes in flight during a push
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Kind of change applied (or planned, in dry-run mode)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// A new issue was created from a local file
+    Create,
+    /// An existing issue was updated from its local file
+    Update,
+    /// The local file was deleted, so its issue was closed
+    Close,
+    /// The issue was closed on GitHub, so its local file was deleted
+    Delete,
+    /// The issue was closed on GitHub, so its local file was moved to `_archive/YYYY-MM/`
+    Archive,
+    /// The issue was closed on GitHub, so its local file was marked as closed in place
+    Mark,
+    /// The issue was edited on GitHub, so its local file was rewritten
+    Pull,
+    /// The issue has no local file yet, so one was written
+    Materialize,
+    /// Both sides changed and neither was applied
+    Conflict,
+}
+
+/// What to do when a file and its issue both changed since the last sync
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictStrategy {
+    /// Overwrite the issue with the local file
+    LocalWins,
+    /// Overwrite the local file with the issue
+    RemoteWins,
+    /// Leave both untouched and fail the sync
+    Fail,
+    /// Write `<name>.conflict.md` with both versions and skip the file until it is deleted
+    #[default]
+    ConflictFile,
+}
+
+impl ConflictStrategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LocalWins => "local-wins",
+            Self::RemoteWins => "remote-wins",
+            Self::Fail => "fail",
+            Self::ConflictFile => "conflict-file",
+        }
+    }
+}
+
+impl FromStr for ConflictStrategy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "local-wins" => Ok(Self::LocalWins),
+            "remote-wins" => Ok(Self::RemoteWins),
+            "fail" => Ok(Self::Fail),
+            "conflict-file" => Ok(Self::ConflictFile),
+            other => anyhow::bail!("Unknown conflict strategy '{}' (expected local-wins, remote-wins, fail or conflict-file)", other),
+        }
+    }
+}
+
+/// What happens to a local file once its issue is closed on GitHub
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClosedPolicy {
+    /// Remove the file
+    Delete,
+    /// Move it to `_archive/YYYY-MM/` with the closing details added to its frontmatter
+    #[default]
+    Archive,
+    /// Leave it in place with the closing details added to its frontmatter
+    KeepAndMark,
+}
+
+impl ClosedPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Delete => "delete",
+            Self::Archive => "archive",
+            Self::KeepAndMark => "keep-and-mark",
+        }
+    }
+}
+
+impl FromStr for ClosedPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "delete" => Ok(Self::Delete),
+            "archive" => Ok(Self::Archive),
+            "keep-and-mark" => Ok(Self::KeepAndMark),
+            other => anyhow::bail!("Unknown closed-issue policy '{}' (expected delete, archive or keep-and-mark)", other),
+        }
+    }
+}
+
+/// What the directories of a nested file contribute to its issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DirectoryMapping {
+    /// Directories only organise files
+    #[default]
+    None,
+    /// Every directory name is added as a label (`epics/auth/FEAT_x.md` → `epics`, `auth`)
+    Labels,
+    /// The top-level directory name is the milestone unless the frontmatter sets one (`v1.2/FEAT_x.md`)
+    Milestone,
+}
+
+impl DirectoryMapping {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Labels => "labels",
+            Self::Milestone => "milestone",
+        }
+    }
+}
+
+impl FromStr for DirectoryMapping {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "none" => Ok(Self::None),
+            "labels" => Ok(Self::Labels),
+            "milestone" => Ok(Self::Milestone),
+            other => anyhow::bail!("Unknown directory mapping '{}' (expected none, labels or milestone)", other),
+        }
+    }
+}
+
+/// A single change, with a line diff for previews
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncChange {
+    pub kind: ChangeKind,
+    pub file: String,
+    pub issue: Option<u64>,
+    pub title: String,
+    /// `-old` / `+new` lines (empty for closes and deletes)
+    pub diff: Vec<String>,
+}
+
+/// Sync report with statistics
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    pub created: usize,
+    pub updated: usize,
+    pub closed: usize,
+    pub deleted: usize,
+    /// Files archived or marked for closed issues
+    pub archived: usize,
+    pub pulled: usize,
+    pub conflicts: usize,
+    pub unchanged: usize,
+    pub skipped: usize,
+    pub errors: usize,
+    pub changes: Vec<SyncChange>,
+    /// Files that could not be parsed, with the reason
+    pub invalid: Vec<(String, String)>,
+}
+
+impl SyncReport {
+    pub fn total_operations(&self) -> usize {
+        self.created + self.updated + self.closed + self.deleted + self.archived + self.pulled
+    }
+
+    /// Add the outcome of a push job
+    fn absorb(&mut self, other: SyncReport) {
+        self.created += other.created;
+        self.updated += other.updated;
+        self.errors += other.errors;
+        self.changes.extend(other.changes);
+    }
+}
+
+/// Local view of the mapping, without calling GitHub
+#[derive(Debug, Default)]
+pub struct SyncStatus {
+    /// Files with an issue number
+    pub mapped: Vec<(String, u64)>,
+    /// Files that will create a new issue on the next push
+    pub unmapped: Vec<String>,
+    /// Mapped files that no longer exist (their issue will be closed)
+    pub missing: Vec<(String, u64)>,
+    /// Files waiting for their `.conflict.md` to be resolved
+    pub conflicts: Vec<String>,
+    /// Files that could not be parsed, with the reason
+    pub invalid: Vec<(String, String)>,
+}
+
+/// How a mapped file and its open issue are brought back in line
+enum Resolution {
+    Push,
+    Pull,
+    Conflict,
+}
+
+/// GitHub write planned during the pass over the files, run concurrently afterwards
+enum Job<'r> {
+    Create { filename: String, data: IssueData },
+    Update { filename: String, data: IssueData, issue: &'r Issue },
+}
+
+/// Issue syncer core
+pub struct IssueSyncer<'a, G: GitHubPort> {
+    github: &'a G,
+    owner: String,
+    repo: String,
+    issues_dir: PathBuf,
+    mapping_file: PathBuf,
+    /// Shared by concurrent push jobs; never locked across an `.await`
+    mapping: Mutex<IssueMapping>,
+    dry_run: bool,
+    on_conflict: ConflictStrategy,
+    on_close: ClosedPolicy,
+    labels: Vec<String>,
+    directories: DirectoryMapping,
+    concurrency: usize,
+    backoff: Backoff,
+}
+
+impl<'a, G: GitHubPort> IssueSyncer<'a, G> {
+    /// Create a new issue syncer
+    pub fn new(
+        github: &'a G,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        issues_dir: PathBuf,
+        mapping_file: PathBuf,
+    ) -> Result<Self> {
+        // Load or create mapping
+        let mapping = if mapping_file.exists() {
+            IssueMapping::load(&mapping_file)
+                .context("Failed to load issue mapping")?
+        } else {
+            IssueMapping::default()
+        };
+
+        Ok(Self {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+            issues_dir,
+            mapping_file,
+            mapping: Mutex::new(mapping),
+            dry_run: false,
+            on_conflict: ConflictStrategy::default(),
+            on_close: ClosedPolicy::default(),
+            labels: Vec::new(),
+            directories: DirectoryMapping::default(),
+            concurrency: DEFAULT_CONCURRENCY,
+            backoff: Backoff::default(),
+        })
+    }
+
+    /// Enable dry-run mode (GitHub is read but nothing is written)
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Set how files changed on both sides are resolved
+    pub fn with_conflict_strategy(mut self, strategy: ConflictStrategy) -> Self {
+        self.on_conflict = strategy;
+        self
+    }
+
+    /// Set what happens to files whose issue was closed on GitHub
+    pub fn with_closed_policy(mut self, policy: ClosedPolicy) -> Self {
+        self.on_close = policy;
+        self
+    }
+
+    /// Only write new files for issues carrying all of these labels (existing files are always updated)
+    pub fn with_label_filter(mut self, labels: Vec<String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Set what the directories of nested files contribute (labels, milestone)
+    pub fn with_directory_mapping(mut self, directories: DirectoryMapping) -> Self {
+        self.directories = directories;
+        self
+    }
+
+    /// Set how many creates and updates may be in flight at once (at least 1)
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set the back-off for rate-limited requests: `initial` delay, doubled on each of `max_retries` retries
+    pub fn with_rate_limit_backoff(mut self, initial: Duration, max_retries: u32) -> Self {
+        self.backoff = Backoff::new(initial, max_retries);
+        self
+    }
+
+    /// Sync all: push local changes + pull remote edits and closed issues
+    pub async fn sync_all(&mut self) -> Result<SyncReport> {
+        info!("Starting bidirectional sync");
+
+        let remote = self.fetch_remote().await?;
+        self.run(&remote, true, true, None).await
+    }
+
+    /// Push: Sync local .md files to GitHub Issues
+    ///
+    /// Issues edited on GitHub since the last sync are left alone (see [`Self::pull`]).
+    pub async fn push(&mut self) -> Result<SyncReport> {
+        info!("Pushing local files to GitHub");
+
+        let remote = self.fetch_remote().await?;
+        self.run(&remote, true, false, None).await
+    }
+
+    /// Push only the named files: existing ones are created or updated, missing mapped ones close their issue
+    ///
+    /// Used by watch mode, which knows exactly which files changed.
+    pub async fn push_files(&mut self, files: &BTreeSet<String>) -> Result<SyncReport> {
+        info!("Pushing {} changed file(s) to GitHub", files.len());
+
+        let remote = self.fetch_remote().await?;
+        self.run(&remote, true, false, Some(files)).await
+    }
+
+    /// Pull: Write files for new issues, rewrite files of edited issues and retire files of closed issues
+    pub async fn pull(&mut self) -> Result<SyncReport> {
+        info!("Pulling issue changes from GitHub");
+
+        let remote = self.fetch_remote().await?;
+        self.run(&remote, false, true, None).await
+    }
+
+    /// Compare the mapping with the files on disk
+    pub fn status(&self) -> Result<SyncStatus> {
+        let mut status = SyncStatus::default();
+        let mut files = BTreeSet::new();
+        for path in self.scan_issue_files()? {
+            let name = file_key(&self.issues_dir, &path);
+            if let Err(e) = parse_issue_file(&path) {
+                status.invalid.push((name.clone(), format!("{:#}", e)));
+            }
+            files.insert(name);
+        }
+        // Moved files are shown under their new path, as the next sync will record them
+        let mut mapping = self.lock_mapping().clone();
+        follow_moves(&mut mapping, &files);
+
+        for file in &files {
+            match mapping.get_entry(file) {
+                Some(entry) => {
+                    status.mapped.push((file.clone(), entry.issue));
+                    if entry.conflict {
+                        status.conflicts.push(file.clone());
+                    }
+                }
+                None => status.unmapped.push(file.clone()),
+            }
+        }
+        for file in mapping.files() {
+            if !files.contains(&file) {
+                let number = mapping.get_issue(&file).unwrap_or_default();
+                status.missing.push((file, number));
+            }
+        }
+        status.missing.sort();
+
+        Ok(status)
+    }
+
+    async fn fetch_remote(&self) -> Result<HashMap<u64, Issue>> {
+        let issues = self
+            .backoff
+            .call(|| self.github.list_issues(&self.owner, &self.repo, Some("all".to_string()), None))
+            .await
+            .context("Failed to fetch issues")?;
+
+        Ok(issues.into_iter().map(|i| (i.number, i)).collect())
+    }
+
+    /// One pass over the issue files; `push` and `pull` select which side may be written
+    /// and `only` restricts the pass to some file names
+    async fn run(
+        &mut self,
+        remote: &HashMap<u64, Issue>,
+        push: bool,
+        pull: bool,
+        only: Option<&BTreeSet<String>>,
+    ) -> Result<SyncReport> {
+        let mut report = SyncReport::default();
+        // Every file on disk, including the ones that fail to parse (never treated as deleted)
+        let files: Vec<(String, PathBuf)> = self
+            .scan_issue_files()?
+            .into_iter()
+            .map(|path| (file_key(&self.issues_dir, &path), path))
+            .collect();
+        let mut present: BTreeSet<String> = files.iter().map(|(key, _)| key.clone()).collect();
+        // Conflicts left unresolved by the `fail` strategy
+        let mut unresolved = Vec::new();
+        let mut jobs = Vec::new();
+        // Files to link to their parent once every issue exists
+        let mut children = Vec::new();
+
+        for (from, to) in follow_moves(&mut self.lock_mapping(), &present) {
+            info!("{} moved to {}, keeping its issue", from, to);
+        }
+
+        for (filename, file_path) in files {
+            if only.is_some_and(|only| !only.contains(&filename)) {
+                continue;
+            }
+
+            let entry = self.lock_mapping().get_entry(&filename).cloned();
+            let existing = entry.as_ref().and_then(|e| remote.get(&e.issue));
+
+            // Closed on GitHub: the file is retired on pull
+            if let Some(issue) = existing.filter(|i| !is_open(i)) {
+                if pull {
+                    self.retire(&filename, &file_path, issue, &mut report).await;
+                } else {
+                    report.skipped += 1;
+                }
+                continue;
+            }
+
+            // Parse file
+            let issue_data = match parse_issue_file(&file_path) {
+                Ok(data) => self.with_directory_values(&filename, data),
+                Err(e) => {
+                    warn!("Failed to parse {}: {:#}", filename, e);
+                    report.errors += 1;
+                    report.invalid.push((filename.clone(), format!("{:#}", e)));
+                    continue;
+                }
+            };
+
+            match (entry, existing) {
+                (Some(entry), Some(issue)) => {
+                    let resolution = self.resolve(&filename, &issue_data, &entry, issue, &mut report);
+                    match resolution {
+                        Some(Resolution::Push) if push => jobs.push(Job::Update {
+                            filename: filename.clone(),
+                            data: issue_data.clone(),
+                            issue,
+                        }),
+                        Some(Resolution::Pull) if pull => self.write_local(&filename, &issue_data, issue, &mut report)?,
+                        Some(Resolution::Conflict) => match self.on_conflict {
+                            ConflictStrategy::Fail => {
+                                warn!("Conflict: {} and #{} both changed since the last sync", filename, issue.number);
+                                report.conflicts += 1;
+                                unresolved.push(format!("{} (#{})", filename, issue.number));
+                            }
+                            _ => self.write_conflict(&filename, &issue_data, issue, &mut report)?,
+                        },
+                        Some(_) => {
+                            info!("Skipping {}: its other side changed", filename);
+                            report.skipped += 1;
+                        }
+                        None => {}
+                    }
+                }
+                // Unmapped, or the mapped issue no longer exists
+                _ if push => jobs.push(Job::Create { filename: filename.clone(), data: issue_data.clone() }),
+                _ => {}
+            }
+
+            if push && issue_data.parent.is_some() {
+                children.push((filename, issue_data));
+            }
+        }
+
+        // Creates and updates, in file order, at most `concurrency` in flight
+        let outcomes: Vec<Result<SyncReport>> = stream::iter(jobs)
+            .map(|job| self.execute(job))
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+        for outcome in outcomes {
+            report.absorb(outcome?);
+        }
+
+        for (filename, data) in &children {
+            self.link_parent(filename, data, &mut report).await;
+        }
+
+        // Open issues without a file
+        if pull {
+            let mut numbers: Vec<u64> = remote.keys().copied().collect();
+            numbers.sort_unstable();
+            for number in numbers {
+                let issue = &remote[&number];
+                if is_open(issue) && !self.lock_mapping().contains_issue(number) && self.matches_labels(issue) {
+                    self.materialize(issue, &mut present, &mut report)?;
+                }
+            }
+        }
+
+        // Mapped files that were deleted locally
+        let mapped = self.lock_mapping().files();
+        for filename in mapped {
+            if !push || present.contains(&filename) || only.is_some_and(|only| !only.contains(&filename)) {
+                continue;
+            }
+            let Some(number) = self.lock_mapping().get_issue(&filename) else { continue };
+
+            if let Some(issue) = remote.get(&number).filter(|i| is_open(i)) {
+                report.changes.push(SyncChange {
+                    kind: ChangeKind::Close,
+                    file: filename.clone(),
+                    issue: Some(number),
+                    title: issue.title.clone(),
+                    diff: Vec::new(),
+                });
+                if self.dry_run {
+                    info!("[DRY RUN] Would close issue #{} ({} was deleted)", number, filename);
+                    report.closed += 1;
+                    continue;
+                }
+                match self.backoff.call(|| self.github.close_issue(&self.owner, &self.repo, number)).await {
+                    Ok(_) => {
+                        info!("Closed issue #{} ({} was deleted)", number, filename);
+                        report.closed += 1;
+                    }
+                    Err(e) => {
+                        warn!("Failed to close issue #{}: {}", number, e);
+                        report.errors += 1;
+                        continue;
+                    }
+                }
+            }
+            if !self.dry_run {
+                self.lock_mapping().remove_by_file(&filename);
+            }
+        }
+
+        // Save mapping if not dry run
+        if !self.dry_run {
+            self.save_mapping()?;
+        }
+
+        if !unresolved.is_empty() {
+            anyhow::bail!(
+                "{} file(s) changed both locally and on GitHub: {} (edit one side or use another conflict strategy)",
+                unresolved.len(),
+                unresolved.join(", ")
+            );
+        }
+
+        Ok(report)
+    }
+
+    /// Decide which side of a mapped file wins; `None` when both already agree
+    fn resolve(
+        &mut self,
+        filename: &str,
+        data: &IssueData,
+        entry: &MappingEntry,
+        issue: &Issue,
+        report: &mut SyncReport,
+    ) -> Option<Resolution> {
+        let local_hash = content_hash(data);
+        let remote_hash = content_hash(&remote_view(issue, data));
+
+        if local_hash == remote_hash {
+            report.unchanged += 1;
+            if !self.dry_run {
+                if entry.conflict {
+                    self.remove_conflict_file(filename);
+                }
+                self.lock_mapping().record(filename.to_string(), issue.number, local_hash, issue.updated_at.clone());
+            }
+            return None;
+        }
+
+        if entry.conflict {
+            if self.issues_dir.join(conflict_file_name(filename)).exists() {
+                info!("Skipping {}: resolve and delete {} first", filename, conflict_file_name(filename));
+                report.skipped += 1;
+                return None;
+            }
+            // The conflict file was deleted: the edited local file is the resolution
+            return Some(Resolution::Push);
+        }
+
+        // Without a recorded hash (first sync, legacy mapping) the local file is authoritative
+        let local_changed = entry.hash.as_deref() != Some(local_hash.as_str());
+        let remote_untouched = entry.updated_at.is_some() && entry.updated_at == issue.updated_at;
+        let remote_changed = !remote_untouched && entry.hash.as_deref().is_some_and(|h| h != remote_hash);
+
+        Some(match (local_changed, remote_changed) {
+            (_, false) => Resolution::Push,
+            (false, true) => Resolution::Pull,
+            (true, true) => match self.on_conflict {
+                ConflictStrategy::LocalWins => Resolution::Push,
+                ConflictStrategy::RemoteWins => Resolution::Pull,
+                ConflictStrategy::Fail | ConflictStrategy::ConflictFile => Resolution::Conflict,
+            },
+        })
+    }
+
+    async fn execute(&self, job: Job<'_>) -> Result<SyncReport> {
+        let mut report = SyncReport::default();
+        match job {
+            Job::Create { filename, data } => self.create(&filename, &data, &mut report).await?,
+            Job::Update { filename, data, issue } => self.update(&filename, &data, issue, &mut report).await,
+        }
+        Ok(report)
+    }
+
+    async fn create(&self, filename: &str, data: &IssueData, report: &mut SyncReport) -> Result<()> {
+        let mut change = SyncChange {
+            kind: ChangeKind::Create,
+            file: filename.to_string(),
+            issue: None,
+            title: data.title.clone(),
+            diff: diff_issue(None, data),
+        };
+
+        if self.dry_run {
+            info!("[DRY RUN] Would create issue from {}", filename);
+            report.created += 1;
+            report.changes.push(change);
+            return Ok(());
+        }
+
+        let created = self
+            .backoff
+            .call(|| self.github.create_issue(&self.owner, &self.repo, &data.title, &data.body, &data.labels))
+            .await;
+        match created {
+            Ok(mut issue) => {
+                info!("Created issue #{} from {}", issue.number, filename);
+                // Fields the create endpoint does not take
+                let patch = IssuePatch {
+                    assignees: Some(data.assignees.clone()).filter(|a| !a.is_empty()),
+                    milestone: data.milestone.clone(),
+                    state: data.state.clone().filter(|s| s == "closed"),
+                    ..Default::default()
+                };
+                if patch != IssuePatch::default() {
+                    let number = issue.number;
+                    match self.backoff.call(|| self.github.update_issue(&self.owner, &self.repo, number, &patch)).await {
+                        Ok(updated) => issue = updated,
+                        Err(e) => {
+                            warn!("Failed to set assignees/milestone/state of issue #{}: {}", issue.number, e);
+                            report.errors += 1;
+                        }
+                    }
+                }
+
+                // Persist right away so a later failure cannot cause duplicates
+                {
+                    let mut mapping = self.lock_mapping();
+                    mapping.record(filename.to_string(), issue.number, content_hash(data), issue.updated_at.clone());
+                    mapping.save(&self.mapping_file).context("Failed to save mapping")?;
+                }
+
+                change.issue = Some(issue.number);
+                report.created += 1;
+                report.changes.push(change);
+            }
+            Err(e) => {
+                warn!("Failed to create issue from {}: {}", filename, e);
+                report.errors += 1;
+            }
+        }
+        Ok(())
+    }
+
+    async fn update(&self, filename: &str, data: &IssueData, issue: &Issue, report: &mut SyncReport) {
+        let change = SyncChange {
+            kind: ChangeKind::Update,
+            file: filename.to_string(),
+            issue: Some(issue.number),
+            title: data.title.clone(),
+            diff: diff_issue(Some(&remote_view(issue, data)), data),
+        };
+
+        if self.dry_run {
+            info!("[DRY RUN] Would update issue #{}", issue.number);
+            report.updated += 1;
+            report.changes.push(change);
+            return;
+        }
+
+        let patch = IssuePatch {
+            title: Some(data.title.clone()),
+            body: Some(data.body.clone()),
+            // Empty lists leave the remote value untouched
+            labels: Some(data.labels.clone()).filter(|l| !l.is_empty()),
+            assignees: Some(data.assignees.clone()).filter(|a| !a.is_empty()),
+            milestone: data.milestone.clone(),
+            state: data.state.clone(),
+        };
+        match self.backoff.call(|| self.github.update_issue(&self.owner, &self.repo, issue.number, &patch)).await {
+            Ok(updated) => {
+                info!("Updated issue #{} from {}", issue.number, filename);
+                self.remove_conflict_file(filename);
+                self.lock_mapping().record(filename.to_string(), issue.number, content_hash(data), updated.updated_at);
+                report.updated += 1;
+                report.changes.push(change);
+            }
+            Err(e) => {
+                warn!("Failed to update issue #{}: {}", issue.number, e);
+                report.errors += 1;
+            }
+        }
+    }
+
+    /// Rewrite a local file from its issue, keeping extra frontmatter keys
+    fn write_local(&mut self, filename: &str, data: &IssueData, issue: &Issue, report: &mut SyncReport) -> Result<()> {
+        let remote = remote_view(issue, data);
+        report.changes.push(SyncChange {
+            kind: ChangeKind::Pull,
+            file: filename.to_string(),
+            issue: Some(issue.number),
+            title: remote.title.clone(),
+            diff: diff_issue(Some(data), &remote),
+        });
+
+        if self.dry_run {
+            info!("[DRY RUN] Would update {} from issue #{}", filename, issue.number);
+            report.pulled += 1;
+            return Ok(());
+        }
+
+        let path = self.issues_dir.join(filename);
+        let existing = std::fs::read_to_string(&path).ok();
+        let content = render_issue_file(existing.as_deref(), &self.without_directory_values(filename, remote.clone()))?;
+        match std::fs::write(&path, content) {
+            Ok(_) => {
+                info!("Updated {} from issue #{}", filename, issue.number);
+                self.lock_mapping().record(filename.to_string(), issue.number, content_hash(&remote), issue.updated_at.clone());
+                report.pulled += 1;
+            }
+            Err(e) => {
+                warn!("Failed to write {}: {}", filename, e);
+                report.errors += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write `<name>.conflict.md` with both versions and flag the mapping entry
+    fn write_conflict(&mut self, filename: &str, data: &IssueData, issue: &Issue, report: &mut SyncReport) -> Result<()> {
+        let conflict_name = conflict_file_name(filename);
+        let remote = remote_view(issue, data);
+        report.conflicts += 1;
+        report.changes.push(SyncChange {
+            kind: ChangeKind::Conflict,
+            file: filename.to_string(),
+            issue: Some(issue.number),
+            title: data.title.clone(),
+            diff: diff_issue(Some(&remote), data),
+        });
+
+        if self.dry_run {
+            info!("[DRY RUN] Would write {} ({} and #{} both changed)", conflict_name, filename, issue.number);
+            return Ok(());
+        }
+
+        let content = format!(
+            "<<<<<<< local ({})\n{}=======\n{}>>>>>>> remote (#{}, updated {})\n",
+            filename,
+            render_issue_file(None, data)?,
+            render_issue_file(None, &remote)?,
+            issue.number,
+            issue.updated_at.as_deref().unwrap_or("unknown"),
+        );
+        std::fs::write(self.issues_dir.join(&conflict_name), content)
+            .with_context(|| format!("Failed to write {}", conflict_name))?;
+        warn!("Conflict: {} and #{} both changed, see {}", filename, issue.number, conflict_name);
+        self.lock_mapping().set_conflict(filename, true);
+        Ok(())
+    }
+
+    /// Write `TYPE_slug.md` for an issue created on GitHub (`TYPE_slug-N.md` if the name is taken)
+    fn materialize(&mut self, issue: &Issue, taken: &mut BTreeSet<String>, report: &mut SyncReport) -> Result<()> {
+        let type_ = type_from_issue(&issue.title, &issue.labels);
+        let mut filename = issue_file_name(&type_, &issue.title);
+        if taken.contains(&filename) || self.lock_mapping().contains_file(&filename) {
+            filename = format!("{}-{}.md", filename.trim_end_matches(".md"), issue.number);
+        }
+        taken.insert(filename.clone());
+
+        let data = IssueData {
+            title: issue.title.clone(),
+            labels: issue.labels.clone(),
+            assignees: issue.assignees.clone(),
+            milestone: issue.milestone.clone(),
+            body: normalize_body(issue.body.as_deref().unwrap_or("")),
+            ..Default::default()
+        };
+        report.pulled += 1;
+        report.changes.push(SyncChange {
+            kind: ChangeKind::Materialize,
+            file: filename.clone(),
+            issue: Some(issue.number),
+            title: issue.title.clone(),
+            diff: diff_issue(None, &data),
+        });
+
+        if self.dry_run {
+            info!("[DRY RUN] Would write {} for issue #{}", filename, issue.number);
+            return Ok(());
+        }
+
+        std::fs::create_dir_all(&self.issues_dir)
+            .with_context(|| format!("Failed to create {}", self.issues_dir.display()))?;
+        std::fs::write(self.issues_dir.join(&filename), render_issue_file(None, &data)?)
+            .with_context(|| format!("Failed to write {}", filename))?;
+        info!("Wrote {} for issue #{}", filename, issue.number);
+        self.lock_mapping().record(filename, issue.number, content_hash(&data), issue.updated_at.clone());
+        Ok(())
+    }
+
+    /// Link the file's issue to its `parent` as a sub-issue, once per parent
+    async fn link_parent(&mut self, filename: &str, data: &IssueData, report: &mut SyncReport) {
+        let Some(parent) = data.parent else { return };
+        let Some(entry) = self.lock_mapping().get_entry(filename).cloned() else {
+            if self.dry_run {
+                info!("[DRY RUN] Would make {} a sub-issue of #{}", filename, parent);
+            }
+            return;
+        };
+        if entry.parent == Some(parent) {
+            return;
+        }
+        let number = entry.issue;
+
+        if self.dry_run {
+            info!("[DRY RUN] Would make #{} a sub-issue of #{}", number, parent);
+            return;
+        }
+        match self.backoff.call(|| self.github.add_sub_issue(&self.owner, &self.repo, parent, number)).await {
+            Ok(_) => {
+                info!("Linked #{} as a sub-issue of #{}", number, parent);
+                self.lock_mapping().set_parent(filename, Some(parent));
+            }
+            Err(e) => {
+                warn!("Failed to link #{} to parent #{}: {}", number, parent, e);
+                report.errors += 1;
+            }
+        }
+    }
+
+    fn matches_labels(&self, issue: &Issue) -> bool {
+        self.labels
+            .iter()
+            .all(|wanted| issue.labels.iter().any(|l| l.eq_ignore_ascii_case(wanted)))
+    }
+
+    /// Apply the [`ClosedPolicy`] to a file whose issue was closed on GitHub
+    async fn retire(&mut self, filename: &str, file_path: &Path, issue: &Issue, report: &mut SyncReport) {
+        let content = std::fs::read_to_string(file_path).unwrap_or_default();
+        if self.on_close == ClosedPolicy::KeepAndMark && frontmatter_field(&content, "state").as_deref() == Some("closed") {
+            report.unchanged += 1;
+            return;
+        }
+
+        let month = issue.closed_at.as_deref().or(issue.updated_at.as_deref()).and_then(|t| t.get(..7)).unwrap_or("undated");
+        let archive_dir = self.issues_dir.join("_archive").join(month);
+        let (kind, action) = match self.on_close {
+            ClosedPolicy::Delete => (ChangeKind::Delete, "delete".to_string()),
+            ClosedPolicy::Archive => (ChangeKind::Archive, format!("archive to _archive/{}/", month)),
+            ClosedPolicy::KeepAndMark => (ChangeKind::Mark, "mark as closed".to_string()),
+        };
+        report.changes.push(SyncChange {
+            kind,
+            file: filename.to_string(),
+            issue: Some(issue.number),
+            title: issue.title.clone(),
+            diff: Vec::new(),
+        });
+
+        if self.dry_run {
+            info!("[DRY RUN] Would {} {} for closed issue #{}", action, filename, issue.number);
+            match self.on_close {
+                ClosedPolicy::Delete => report.deleted += 1,
+                _ => report.archived += 1,
+            }
+            return;
+        }
+
+        let result = match self.on_close {
+            ClosedPolicy::Delete => std::fs::remove_file(file_path).map_err(anyhow::Error::from),
+            ClosedPolicy::Archive => {
+                let marked = self.mark_closed(filename, &content, issue).await;
+                let mut target = archive_dir.join(filename);
+                if target.exists() {
+                    target = archive_dir.join(format!("{}-{}.md", filename.trim_end_matches(".md"), issue.number));
+                }
+                std::fs::create_dir_all(target.parent().unwrap_or(&archive_dir))
+                    .and_then(|_| std::fs::write(&target, marked))
+                    .and_then(|_| std::fs::remove_file(file_path))
+                    .map_err(anyhow::Error::from)
+            }
+            ClosedPolicy::KeepAndMark => {
+                let marked = self.mark_closed(filename, &content, issue).await;
+                std::fs::write(file_path, marked).map_err(anyhow::Error::from).and_then(|_| {
+                    // Record the rewritten file, or a reopen would see `state: closed` as a local edit
+                    let data = self.with_directory_values(filename, parse_issue_file(file_path)?);
+                    self.lock_mapping().record(filename.to_string(), issue.number, content_hash(&data), issue.updated_at.clone());
+                    Ok(())
+                })
+            }
+        };
+
+        match result {
+            Ok(_) => {
+                info!("Applied '{}' to {} for closed issue #{}", self.on_close.as_str(), filename, issue.number);
+                self.remove_conflict_file(filename);
+                match self.on_close {
+                    ClosedPolicy::Delete => report.deleted += 1,
+                    _ => report.archived += 1,
+                }
+                // A marked file stays mapped so it is never pushed as a new issue
+                if self.on_close != ClosedPolicy::KeepAndMark {
+                    self.lock_mapping().remove_by_issue(issue.number);
+                }
+            }
+            Err(e) => {
+                warn!("Failed to {} {}: {:#}", action, filename, e);
+                report.errors += 1;
+            }
+        }
+    }
+
+    /// Add the closing date, closing PR and final state to the frontmatter
+    async fn mark_closed(&self, filename: &str, content: &str, issue: &Issue) -> String {
+        let mut fields = vec![("state", "closed".to_string())];
+        if let Some(reason) = &issue.state_reason {
+            fields.push(("state_reason", reason.clone()));
+        }
+        if let Some(closed_at) = &issue.closed_at {
+            fields.push(("closed_at", closed_at.clone()));
+        }
+        match self.backoff.call(|| self.github.closing_pr(&self.owner, &self.repo, issue.number)).await {
+            Ok(Some(pr)) => fields.push(("closed_by", format!("#{}", pr))),
+            Ok(None) => {}
+            Err(e) => warn!("Failed to find the pull request that closed #{}: {}", issue.number, e),
+        }
+
+        set_frontmatter_fields(content, &fields).unwrap_or_else(|e| {
+            warn!("Keeping {} as is: {:#}", filename, e);
+            content.to_string()
+        })
+    }
+
+    fn remove_conflict_file(&self, filename: &str) {
+        let path = self.issues_dir.join(conflict_file_name(filename));
+        if path.exists() {
+            if let Err(e) = std::fs::remove_file(&path) {
+                warn!("Failed to remove {}: {}", path.display(), e);
+            }
+        }
+    }
+
+    /// Scan the issues directory tree for .md files, skipping hidden files, `_` templates and
+    /// conflict files as well as hidden and `_` directories (such as `_archive`)
+    fn scan_issue_files(&self) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+
+        for entry in WalkDir::new(&self.issues_dir)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|e| e.depth() == 0 || !e.file_type().is_dir() || is_issue_dir_name(&e.file_name().to_string_lossy()))
+            .filter_map(|e| e.ok())
+        {
+            let path = entry.path();
+            if path.is_file() && is_issue_file_name(&entry.file_name().to_string_lossy()) {
+                files.push(path.to_path_buf());
+            }
+        }
+
+        Ok(files)
+    }
+
+    /// Add the labels or milestone implied by the file's directories
+    fn with_directory_values(&self, filename: &str, mut data: IssueData) -> IssueData {
+        let (labels, milestone) = self.directory_values(filename);
+        for label in labels {
+            if !data.labels.iter().any(|l| l.eq_ignore_ascii_case(&label)) {
+                data.labels.push(label);
+            }
+        }
+        if data.milestone.is_none() {
+            data.milestone = milestone;
+        }
+        data
+    }
+
+    /// Drop the values implied by the file's directories before writing it, so they stay implicit
+    fn without_directory_values(&self, filename: &str, mut data: IssueData) -> IssueData {
+        let (labels, milestone) = self.directory_values(filename);
+        data.labels.retain(|l| !labels.iter().any(|dir| dir.eq_ignore_ascii_case(l)));
+        if milestone.is_some() && data.milestone == milestone {
+            data.milestone = None;
+        }
+        data
+    }
+
+    fn directory_values(&self, filename: &str) -> (Vec<String>, Option<String>) {
+        let mut dirs: Vec<&str> = filename.split('/').collect();
+        dirs.pop();
+        match self.directories {
+            DirectoryMapping::None => (Vec::new(), None),
+            DirectoryMapping::Labels => (dirs.iter().map(|d| d.to_string()).collect(), None),
+            DirectoryMapping::Milestone => (Vec::new(), dirs.first().map(|d| d.to_string())),
+        }
+    }
+
+    /// Save mapping to disk
+    fn save_mapping(&self) -> Result<()> {
+        self.lock_mapping().save(&self.mapping_file)
+            .context("Failed to save mapping")
+    }
+
+    /// Snapshot of the current mapping
+    pub fn mapping(&self) -> IssueMapping {
+        self.lock_mapping().clone()
+    }
+
+    fn lock_mapping(&self) -> MutexGuard<'_, IssueMapping> {
+        // A panic while holding the lock cannot leave the mapping half-written
+        self.mapping.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Directory holding the issue files
+    pub fn issues_dir(&self) -> &std::path::Path {
+        &self.issues_dir
+    }
+}
+
+/// `.md` files, except hidden files, `_` templates and conflict files
+fn is_issue_file_name(name: &str) -> bool {
+    name.ends_with(".md") && !name.starts_with('.') && !name.starts_with('_') && !name.ends_with(".conflict.md")
+}
+
+/// Directories that may hold issue files: not hidden, and not `_archive` or other `_` directories
+fn is_issue_dir_name(name: &str) -> bool {
+    !name.starts_with('.') && !name.starts_with('_')
+}
+
+/// Whether a file key (see [`file_key`]) names an issue file
+pub(crate) fn is_issue_file_key(key: &str) -> bool {
+    let mut parts: Vec<&str> = key.split('/').collect();
+    let name = parts.pop().unwrap_or_default();
+    is_issue_file_name(name) && parts.into_iter().all(is_issue_dir_name)
+}
+
+/// Mapping key of a file: its path relative to the issues directory, with `/` separators
+pub(crate) fn file_key(issues_dir: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(issues_dir).unwrap_or(path);
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Re-key mapped files that no longer exist when exactly one unmapped file with the same
+/// name exists elsewhere in the tree. Covers files moved between directories as well as
+/// mappings written before nested directories were supported. Returns `(from, to)` pairs.
+fn follow_moves(mapping: &mut IssueMapping, present: &BTreeSet<String>) -> Vec<(String, String)> {
+    let base_name = |key: &str| key.rsplit('/').next().unwrap_or(key).to_string();
+    let mut moves = Vec::new();
+
+    for file in mapping.files() {
+        if present.contains(&file) {
+            continue;
+        }
+        let name = base_name(&file);
+        let mut candidates = present.iter().filter(|key| base_name(key) == name && !mapping.contains_file(key));
+        if let (Some(target), None) = (candidates.next(), candidates.next()) {
+            let target = target.clone();
+            mapping.rename(&file, target.clone());
+            moves.push((file, target));
+        }
+    }
+    moves
+}
+
+fn is_open(issue: &Issue) -> bool {
+    issue.state.eq_ignore_ascii_case("open")
+}
+
+fn sorted(values: &[String]) -> Vec<String> {
+    let mut values = values.to_vec();
+    values.sort();
+    values
+}
+
+/// `FEAT_a.md` → `FEAT_a.conflict.md`
+fn conflict_file_name(filename: &str) -> String {
+    format!("{}.conflict.md", filename.strip_suffix(".md").unwrap_or(filename))
+}
+
+/// The issue as the local file would describe it. Fields the file leaves out are not managed.
+///
+/// GitHub does not report the parent issue in listings, so the local value is kept.
+fn remote_view(issue: &Issue, local: &IssueData) -> IssueData {
+    IssueData {
+        title: issue.title.clone(),
+        labels: if local.labels.is_empty() { Vec::new() } else { issue.labels.clone() },
+        assignees: if local.assignees.is_empty() { Vec::new() } else { issue.assignees.clone() },
+        milestone: local.milestone.as_ref().and(issue.milestone.clone()),
+        state: local.state.as_ref().map(|_| issue.state.to_lowercase()),
+        parent: local.parent,
+        body: normalize_body(issue.body.as_deref().unwrap_or("")),
+    }
+}
+
+/// Line diff between the old version (if any) and the new one
+fn diff_issue(old: Option<&IssueData>, new: &IssueData) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut field = |name: &str, old: String, new: String| {
+        if old != new {
+            if !old.is_empty() {
+                lines.push(format!("-{}: {}", name, old));
+            }
+            if !new.is_empty() {
+                lines.push(format!("+{}: {}", name, new));
+            }
+        }
+    };
+
+    field("title", old.map(|i| i.title.clone()).unwrap_or_default(), new.title.clone());
+    field("labels", old.map(|i| sorted(&i.labels).join(", ")).unwrap_or_default(), sorted(&new.labels).join(", "));
+    field("assignees", old.map(|i| sorted(&i.assignees).join(", ")).unwrap_or_default(), sorted(&new.assignees).join(", "));
+    field("milestone", old.and_then(|i| i.milestone.clone()).unwrap_or_default(), new.milestone.clone().unwrap_or_default());
+    field("state", old.and_then(|i| i.state.clone()).unwrap_or_default(), new.state.clone().unwrap_or_default());
+    let parent = |data: Option<&IssueData>| data.and_then(|i| i.parent).map(|p| format!("#{}", p)).unwrap_or_default();
+    field("parent", parent(old), parent(Some(new)));
+
+    let old_body = old.map(|i| normalize_body(&i.body)).unwrap_or_default();
+    let new_body = normalize_body(&new.body);
+    for change in TextDiff::from_lines(&old_body, &new_body).iter_all_changes() {
+        let line = change.to_string_lossy();
+        let line = line.trim_end_matches('\n');
+        match change.tag() {
+            ChangeTag::Delete => lines.push(format!("-{}", line)),
+            ChangeTag::Insert => lines.push(format!("+{}", line)),
+            ChangeTag::Equal => {}
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gc_core::ports::MockGitHubPort;
+    use tempfile::TempDir;
+
+    fn issue(number: u64, title: &str, body: &str, state: &str) -> Issue {
+        Issue {
+            number,
+            title: title.into(),
+            body: Some(body.into()),
+            state: state.into(),
+            html_url: String::new(),
+            assignees: vec![],
+            labels: vec![],
+            updated_at: None,
+            closed_at: None,
+            state_reason: None,
+            milestone: None,
+        }
+    }
+
+    fn temp_issues_dir() -> (TempDir, PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let issues_dir = temp_dir.path().join("issues");
+        std::fs::create_dir(&issues_dir).unwrap();
+        (temp_dir, issues_dir)
+    }
+
+    #[tokio::test]
+    async fn test_scan_skips_hidden_files_and_templates() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        for name in ["TEST_issue.md", "_TEMPLATE.md", ".hidden.md", "notes.txt"] {
+            std::fs::write(issues_dir.join(name), "---\ntitle: Test\n---\nBody").unwrap();
+        }
+
+        for dir in ["epics", "_archive/2025-01", ".git"] {
+            std::fs::create_dir_all(issues_dir.join(dir)).unwrap();
+            std::fs::write(issues_dir.join(dir).join("FEAT_x.md"), "---\ntitle: X\n---\n").unwrap();
+        }
+
+        let github = MockGitHubPort::new();
+        let syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), issues_dir.join(".issue-mapping.json")).unwrap();
+
+        let files = syncer.scan_issue_files().unwrap();
+        assert_eq!(files, vec![issues_dir.join("TEST_issue.md"), issues_dir.join("epics/FEAT_x.md")]);
+        assert_eq!(file_key(&issues_dir, &files[1]), "epics/FEAT_x.md");
+    }
+
+    #[tokio::test]
+    async fn test_nested_files_are_keyed_by_path_and_moves_are_followed() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        let mapping_file = synced_mapping(&issues_dir);
+        for (path, title) in [("epics/FEAT_a.md", "A"), ("FEAT_b.md", "B"), ("ops/FEAT_b.md", "Ops B")] {
+            std::fs::create_dir_all(issues_dir.join(path).parent().unwrap()).unwrap();
+            std::fs::write(issues_dir.join(path), format!("---\ntitle: {}\n---\nBase", title)).unwrap();
+        }
+
+        let mut github = MockGitHubPort::new();
+        github.expect_list_issues().returning(|_, _, _, _| Ok(vec![Issue { updated_at: Some("t1".into()), ..issue(7, "A", "Base", "open") }]));
+        github.expect_update_issue()
+            .withf(|_, _, number, patch| *number == 7 && patch.labels == Some(vec!["epics".to_string()]))
+            .times(1)
+            .returning(|_, _, _, _| Ok(Issue { labels: vec!["epics".into()], ..issue(7, "A", "Base", "open") }));
+        github.expect_create_issue()
+            .withf(|_, _, _, _, labels| labels == ["ops".to_string()] || labels.is_empty())
+            .times(2)
+            .returning(|_, _, title, _, _| Ok(issue(if title == "B" { 8 } else { 9 }, title, "Base", "open")));
+
+        let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir, mapping_file.clone())
+            .unwrap()
+            .with_directory_mapping(DirectoryMapping::Labels);
+        let report = syncer.push().await.unwrap();
+
+        assert_eq!((report.created, report.updated, report.closed), (2, 1, 0));
+        let mapping = IssueMapping::load(&mapping_file).unwrap();
+        assert_eq!(mapping.files(), vec!["FEAT_b.md", "epics/FEAT_a.md", "ops/FEAT_b.md"]);
+        assert_eq!(mapping.get_issue("epics/FEAT_a.md"), Some(7));
+        assert_eq!(mapping.get_issue("ops/FEAT_b.md"), Some(9));
+    }
+
+    #[tokio::test]
+    async fn test_directory_milestone_stays_implicit_on_pull() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        let mapping_file = issues_dir.join(".issue-mapping.json");
+        let base = IssueData { title: "A".into(), milestone: Some("v1.2".into()), body: "Base".into(), ..Default::default() };
+        let mut mapping = IssueMapping::default();
+        mapping.record("v1.2/FEAT_a.md".into(), 7, content_hash(&base), Some("t1".into()));
+        mapping.save(&mapping_file).unwrap();
+        std::fs::create_dir(issues_dir.join("v1.2")).unwrap();
+        std::fs::write(issues_dir.join("v1.2/FEAT_a.md"), "---\ntitle: A\n---\nBase").unwrap();
+
+        let mut github = MockGitHubPort::new();
+        github.expect_list_issues().returning(|_, _, _, _| Ok(vec![Issue {
+            milestone: Some("v1.2".into()),
+            ..edited_remote("Edited on GitHub")
+        }]));
+
+        let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), mapping_file)
+            .unwrap()
+            .with_directory_mapping(DirectoryMapping::Milestone);
+        let report = syncer.pull().await.unwrap();
+
+        assert_eq!(report.pulled, 1);
+        assert_eq!(std::fs::read_to_string(issues_dir.join("v1.2/FEAT_a.md")).unwrap(), "---\ntitle: A\n---\n\nEdited on GitHub\n");
+    }
+
+    #[tokio::test]
+    async fn test_push_is_idempotent() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        let mapping_file = issues_dir.join(".issue-mapping.json");
+        std::fs::write(&mapping_file, r#"{"FEAT_a.md": 7, "FEAT_gone.md": 8, "FEAT_edited.md": 9}"#).unwrap();
+        std::fs::write(issues_dir.join("FEAT_a.md"), "---\ntitle: A\n---\nBody\r\n").unwrap();
+        std::fs::write(issues_dir.join("FEAT_edited.md"), "---\ntitle: Edited\n---\nNew body").unwrap();
+        std::fs::write(issues_dir.join("FEAT_new.md"), "---\ntitle: New\n---\nBody").unwrap();
+        std::fs::write(issues_dir.join("BROKEN.md"), "no frontmatter").unwrap();
+
+        let mut github = MockGitHubPort::new();
+        github.expect_list_issues().returning(|_, _, _, _| Ok(vec![
+            issue(7, "A", "Body", "Open"),
+            issue(8, "Gone", "", "Open"),
+            issue(9, "Edited", "Old body", "Open"),
+        ]));
+        github.expect_create_issue()
+            .withf(|_, _, title, _, _| title == "New")
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(issue(10, "New", "Body", "open")));
+        github.expect_update_issue()
+            .withf(|_, _, number, patch| *number == 9 && patch.body.as_deref() == Some("New body"))
+            .times(1)
+            .returning(|_, _, _, _| Ok(issue(9, "Edited", "New body", "open")));
+        github.expect_close_issue()
+            .withf(|_, _, number| *number == 8)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir, mapping_file.clone()).unwrap();
+        let report = syncer.push().await.unwrap();
+
+        assert_eq!((report.created, report.updated, report.closed, report.unchanged, report.errors), (1, 1, 1, 1, 1));
+        let mapping = IssueMapping::load(&mapping_file).unwrap();
+        assert_eq!(mapping.files(), vec!["FEAT_a.md", "FEAT_edited.md", "FEAT_new.md"]);
+        assert_eq!(mapping.get_issue("FEAT_new.md"), Some(10));
+        assert!(mapping.get_entry("FEAT_a.md").unwrap().hash.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_reports_diff_without_writing() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        let mapping_file = issues_dir.join(".issue-mapping.json");
+        std::fs::write(&mapping_file, r#"{"FEAT_a.md": 7}"#).unwrap();
+        std::fs::write(issues_dir.join("FEAT_a.md"), "---\ntitle: A\n---\nline 1\nline 2 changed").unwrap();
+
+        let mut github = MockGitHubPort::new();
+        github.expect_list_issues().returning(|_, _, _, _| Ok(vec![issue(7, "A", "line 1\nline 2", "OPEN")]));
+
+        let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir, mapping_file).unwrap().with_dry_run(true);
+        let report = syncer.push().await.unwrap();
+
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(report.changes[0].kind, ChangeKind::Update);
+        assert_eq!(report.changes[0].diff, vec!["-line 2", "+line 2 changed"]);
+    }
+
+    #[tokio::test]
+    async fn test_pull_deletes_files_of_closed_issues() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        let mapping_file = issues_dir.join(".issue-mapping.json");
+        std::fs::write(&mapping_file, r#"{"FEAT_done.md": 3, "FEAT_open.md": 4}"#).unwrap();
+        std::fs::write(issues_dir.join("FEAT_done.md"), "---\ntitle: Done\n---\n").unwrap();
+        std::fs::write(issues_dir.join("FEAT_open.md"), "---\ntitle: Open\n---\n").unwrap();
+
+        let mut github = MockGitHubPort::new();
+        github.expect_list_issues().returning(|_, _, _, _| Ok(vec![
+            issue(3, "Done", "", "Closed"),
+            issue(4, "Open", "", "Open"),
+        ]));
+
+        let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), mapping_file)
+            .unwrap()
+            .with_closed_policy(ClosedPolicy::Delete);
+        let report = syncer.pull().await.unwrap();
+
+        assert_eq!(report.deleted, 1);
+        assert!(!issues_dir.join("FEAT_done.md").exists());
+        assert!(issues_dir.join("FEAT_open.md").exists());
+        assert_eq!(syncer.mapping().files(), vec!["FEAT_open.md"]);
+    }
+
+    /// Mapping whose last sync saw "A" / "Base" at `t1`
+    fn synced_mapping(issues_dir: &std::path::Path) -> PathBuf {
+        let base = IssueData { title: "A".into(), body: "Base".into(), ..Default::default() };
+        let mut mapping = IssueMapping::default();
+        mapping.record("FEAT_a.md".into(), 7, content_hash(&base), Some("t1".into()));
+        let mapping_file = issues_dir.join(".issue-mapping.json");
+        mapping.save(&mapping_file).unwrap();
+        mapping_file
+    }
+
+    fn edited_remote(body: &str) -> Issue {
+        Issue { updated_at: Some("t2".into()), ..issue(7, "A", body, "open") }
+    }
+
+    #[tokio::test]
+    async fn test_remote_only_change_is_pulled() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        let mapping_file = synced_mapping(&issues_dir);
+        std::fs::write(issues_dir.join("FEAT_a.md"), "---\ntitle: A\npriority: high\n---\n\nBase\n").unwrap();
+
+        let mut github = MockGitHubPort::new();
+        github.expect_list_issues().returning(|_, _, _, _| Ok(vec![edited_remote("Edited on GitHub")]));
+        github.expect_update_issue().never();
+
+        let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), mapping_file).unwrap();
+        let report = syncer.sync_all().await.unwrap();
+
+        assert_eq!((report.pulled, report.updated), (1, 0));
+        let content = std::fs::read_to_string(issues_dir.join("FEAT_a.md")).unwrap();
+        assert!(content.contains("priority: high"));
+        assert_eq!(parse_issue_file(issues_dir.join("FEAT_a.md")).unwrap().body, "Edited on GitHub");
+        assert_eq!(syncer.mapping().get_entry("FEAT_a.md").unwrap().updated_at.as_deref(), Some("t2"));
+    }
+
+    #[tokio::test]
+    async fn test_push_does_not_overwrite_remote_edits() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        let mapping_file = synced_mapping(&issues_dir);
+        std::fs::write(issues_dir.join("FEAT_a.md"), "---\ntitle: A\n---\nBase").unwrap();
+
+        let mut github = MockGitHubPort::new();
+        github.expect_list_issues().returning(|_, _, _, _| Ok(vec![edited_remote("Edited on GitHub")]));
+        github.expect_update_issue().never();
+
+        let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), mapping_file).unwrap();
+        let report = syncer.push().await.unwrap();
+
+        assert_eq!((report.skipped, report.pulled), (1, 0));
+        assert_eq!(parse_issue_file(issues_dir.join("FEAT_a.md")).unwrap().body, "Base");
+    }
+
+    #[tokio::test]
+    async fn test_conflict_file_then_resolution() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        let mapping_file = synced_mapping(&issues_dir);
+        std::fs::write(issues_dir.join("FEAT_a.md"), "---\ntitle: A\n---\nLocal edit").unwrap();
+
+        let mut github = MockGitHubPort::new();
+        github.expect_list_issues().returning(|_, _, _, _| Ok(vec![edited_remote("Remote edit")]));
+        github.expect_update_issue()
+            .withf(|_, _, number, patch| *number == 7 && patch.body.as_deref() == Some("Merged"))
+            .times(1)
+            .returning(|_, _, _, _| Ok(Issue { updated_at: Some("t3".into()), ..issue(7, "A", "Merged", "open") }));
+
+        let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), mapping_file.clone()).unwrap();
+        let report = syncer.sync_all().await.unwrap();
+        assert_eq!(report.conflicts, 1);
+        let conflict = std::fs::read_to_string(issues_dir.join("FEAT_a.conflict.md")).unwrap();
+        assert!(conflict.contains("Local edit") && conflict.contains("Remote edit"));
+        assert!(syncer.mapping().get_entry("FEAT_a.md").unwrap().conflict);
+
+        // Still pending: nothing happens
+        let report = syncer.sync_all().await.unwrap();
+        assert_eq!((report.skipped, report.updated), (1, 0));
+
+        // Resolved by editing the file and deleting the conflict file
+        std::fs::write(issues_dir.join("FEAT_a.md"), "---\ntitle: A\n---\nMerged").unwrap();
+        std::fs::remove_file(issues_dir.join("FEAT_a.conflict.md")).unwrap();
+        let report = syncer.sync_all().await.unwrap();
+        assert_eq!(report.updated, 1);
+        let entry = IssueMapping::load(&mapping_file).unwrap().get_entry("FEAT_a.md").cloned().unwrap();
+        assert!(!entry.conflict);
+        assert_eq!(entry.updated_at.as_deref(), Some("t3"));
+    }
+
+    #[tokio::test]
+    async fn test_conflict_strategies() {
+        for strategy in [ConflictStrategy::LocalWins, ConflictStrategy::RemoteWins, ConflictStrategy::Fail] {
+            let (_temp, issues_dir) = temp_issues_dir();
+            let mapping_file = synced_mapping(&issues_dir);
+            std::fs::write(issues_dir.join("FEAT_a.md"), "---\ntitle: A\n---\nLocal edit").unwrap();
+
+            let mut github = MockGitHubPort::new();
+            github.expect_list_issues().returning(|_, _, _, _| Ok(vec![edited_remote("Remote edit")]));
+            github.expect_update_issue()
+                .times(usize::from(strategy == ConflictStrategy::LocalWins))
+                .returning(|_, _, _, _| Ok(edited_remote("Local edit")));
+
+            let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), mapping_file)
+                .unwrap()
+                .with_conflict_strategy(strategy);
+            let result = syncer.sync_all().await;
+            let body = parse_issue_file(issues_dir.join("FEAT_a.md")).unwrap().body;
+
+            match strategy {
+                ConflictStrategy::LocalWins => assert_eq!((result.unwrap().updated, body.as_str()), (1, "Local edit")),
+                ConflictStrategy::RemoteWins => assert_eq!((result.unwrap().pulled, body.as_str()), (1, "Remote edit")),
+                _ => {
+                    assert!(result.unwrap_err().to_string().contains("FEAT_a.md (#7)"));
+                    assert_eq!(body, "Local edit");
+                }
+            }
+            assert!(!issues_dir.join("FEAT_a.conflict.md").exists());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pull_materializes_new_issues() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        let mapping_file = issues_dir.join(".issue-mapping.json");
+        std::fs::write(issues_dir.join("BUG_fix-login.md"), "---\ntitle: Local\n---\n").unwrap();
+
+        let mut github = MockGitHubPort::new();
+        github.expect_list_issues().returning(|_, _, _, _| Ok(vec![
+            Issue { labels: vec!["bug".into()], assignees: vec!["ana".into()], ..issue(5, "Fix login", "Steps", "open") },
+            Issue { labels: vec!["documentation".into(), "bug".into()], ..issue(6, "Update README", "", "open") },
+            Issue { labels: vec!["wontfix".into()], ..issue(7, "Other", "", "open") },
+            Issue { labels: vec!["bug".into()], ..issue(8, "Old", "", "closed") },
+        ]));
+
+        let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), mapping_file)
+            .unwrap()
+            .with_label_filter(vec!["Bug".into()]);
+        let report = syncer.pull().await.unwrap();
+
+        assert_eq!(report.pulled, 2);
+        assert_eq!(syncer.mapping().get_file(5).as_deref(), Some("BUG_fix-login-5.md"));
+        assert_eq!(syncer.mapping().get_file(6).as_deref(), Some("DOCS_update-readme.md"));
+        assert!(!syncer.mapping().contains_issue(7));
+
+        let data = parse_issue_file(issues_dir.join("BUG_fix-login-5.md")).unwrap();
+        assert_eq!((data.title.as_str(), data.body.as_str()), ("Fix login", "Steps"));
+        assert_eq!(data.assignees, vec!["ana"]);
+
+        // Second pull has nothing left to do
+        let report = syncer.pull().await.unwrap();
+        assert_eq!((report.pulled, report.unchanged), (0, 2));
+    }
+
+    #[tokio::test]
+    async fn test_closed_policies() {
+        for policy in [ClosedPolicy::Archive, ClosedPolicy::KeepAndMark] {
+            let (_temp, issues_dir) = temp_issues_dir();
+            let mapping_file = issues_dir.join(".issue-mapping.json");
+            std::fs::write(&mapping_file, r#"{"FEAT_done.md": 3}"#).unwrap();
+            std::fs::write(issues_dir.join("FEAT_done.md"), "---\ntitle: Done\npriority: high\n---\n\nHistory\n").unwrap();
+
+            let mut github = MockGitHubPort::new();
+            github.expect_list_issues().returning(|_, _, _, _| Ok(vec![Issue {
+                closed_at: Some("2025-03-14T10:00:00+00:00".into()),
+                state_reason: Some("completed".into()),
+                ..issue(3, "Done", "History", "Closed")
+            }]));
+            github.expect_closing_pr()
+                .withf(|_, _, number| *number == 3)
+                .times(1)
+                .returning(|_, _, _| Ok(Some(12)));
+
+            let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), mapping_file)
+                .unwrap()
+                .with_closed_policy(policy);
+            let report = syncer.pull().await.unwrap();
+            assert_eq!(report.archived, 1);
+
+            let path = match policy {
+                ClosedPolicy::Archive => issues_dir.join("_archive/2025-03/FEAT_done.md"),
+                _ => issues_dir.join("FEAT_done.md"),
+            };
+            let content = std::fs::read_to_string(&path).unwrap();
+            for field in ["priority: high", "state: closed", "state_reason: completed", "closed_at: 2025-03-14T10:00:00+00:00", "closed_by: '#12'"] {
+                assert!(content.contains(field), "{} missing in {}", field, content);
+            }
+            assert!(content.ends_with("History\n"));
+
+            // Archived files leave the mapping; marked files stay (and are not marked twice)
+            assert_eq!(syncer.mapping().contains_file("FEAT_done.md"), policy == ClosedPolicy::KeepAndMark);
+            let report = syncer.pull().await.unwrap();
+            assert_eq!(report.archived, 0);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reopened_marked_issue_is_pulled() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        let mapping_file = issues_dir.join(".issue-mapping.json");
+        std::fs::write(&mapping_file, r#"{"FEAT_done.md": 3}"#).unwrap();
+        std::fs::write(issues_dir.join("FEAT_done.md"), "---\ntitle: Done\n---\n\nHistory\n").unwrap();
+
+        let reopened = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let seen = reopened.clone();
+        let mut github = MockGitHubPort::new();
+        github.expect_list_issues().returning(move |_, _, _, _| Ok(vec![if seen.load(std::sync::atomic::Ordering::SeqCst) {
+            Issue { updated_at: Some("t2".into()), ..issue(3, "Done", "History", "open") }
+        } else {
+            Issue { updated_at: Some("t1".into()), ..issue(3, "Done", "History", "closed") }
+        }]));
+        github.expect_closing_pr().returning(|_, _, _| Ok(None));
+
+        let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), mapping_file)
+            .unwrap()
+            .with_closed_policy(ClosedPolicy::KeepAndMark)
+            .with_conflict_strategy(ConflictStrategy::Fail);
+        syncer.pull().await.unwrap();
+        let marked = parse_issue_file(issues_dir.join("FEAT_done.md")).unwrap();
+        assert_eq!(syncer.mapping().get_entry("FEAT_done.md").unwrap().hash, Some(content_hash(&marked)));
+
+        reopened.store(true, std::sync::atomic::Ordering::SeqCst);
+        let report = syncer.pull().await.unwrap();
+        assert_eq!((report.pulled, report.conflicts), (1, 0));
+        let data = parse_issue_file(issues_dir.join("FEAT_done.md")).unwrap();
+        assert_eq!(data.state.as_deref(), Some("open"));
+    }
+
+    #[tokio::test]
+    async fn test_push_syncs_milestone_state_and_parent() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        let mapping_file = issues_dir.join(".issue-mapping.json");
+        std::fs::write(
+            issues_dir.join("TASK_child.md"),
+            "---\ntitle: Child\nmilestone: v1\nparent: 3\npriority: high\n---\nBody",
+        ).unwrap();
+
+        let created = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let seen = created.clone();
+        let mut github = MockGitHubPort::new();
+        github.expect_list_issues().returning(move |_, _, _, _| {
+            Ok(if seen.load(std::sync::atomic::Ordering::SeqCst) {
+                vec![Issue { milestone: Some("v1".into()), ..issue(10, "Child", "Body", "open") }]
+            } else {
+                vec![]
+            })
+        });
+        let flag = created.clone();
+        github.expect_create_issue().times(1).returning(move |_, _, _, _, _| {
+            flag.store(true, std::sync::atomic::Ordering::SeqCst);
+            Ok(issue(10, "Child", "Body", "open"))
+        });
+        github.expect_update_issue()
+            .withf(|_, _, number, patch| *number == 10 && patch.milestone.as_deref() == Some("v1") && patch.state.is_none())
+            .times(1)
+            .returning(|_, _, _, _| Ok(Issue { milestone: Some("v1".into()), ..issue(10, "Child", "Body", "open") }));
+        github.expect_add_sub_issue()
+            .withf(|_, _, parent, child| (*parent, *child) == (3, 10))
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir, mapping_file).unwrap();
+        assert_eq!(syncer.push().await.unwrap().created, 1);
+        assert_eq!(syncer.mapping().get_entry("TASK_child.md").unwrap().parent, Some(3));
+
+        // Nothing left to do: milestone matches and the parent link is recorded
+        let report = syncer.push().await.unwrap();
+        assert_eq!((report.unchanged, report.updated), (1, 0));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_files_are_reported_per_file() {
+        let (_temp, issues_dir) = temp_issues_dir();
+        std::fs::write(issues_dir.join("FEAT_bad.md"), "---\ntitle: Bad\nstate: done\n---\n").unwrap();
+
+        let mut github = MockGitHubPort::new();
+        github.expect_list_issues().returning(|_, _, _, _| Ok(vec![]));
+        github.expect_create_issue().never();
+
+        let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), issues_dir.join(".issue-mapping.json")).unwrap();
+        let report = syncer.push().await.unwrap();
+
+        assert_eq!(report.invalid.len(), 1);
+        assert_eq!(report.invalid[0].0, "FEAT_bad.md");
+        assert!(report.invalid[0].1.contains("state 'done' must be one of: open, closed"));
+        assert_eq!(syncer.status().unwrap().invalid, report.invalid);
+    }
+
+    #[test]
+    fn test_conflict_strategy_from_str() {
+        for strategy in [ConflictStrategy::LocalWins, ConflictStrategy::RemoteWins, ConflictStrategy::Fail, ConflictStrategy::ConflictFile] {
+            assert_eq!(strategy.as_str().parse::<ConflictStrategy>().unwrap(), strategy);
+        }
+        assert!("ours".parse::<ConflictStrategy>().is_err());
+    }
+}
@@ -0,0 +1,144 @@
+//! Watch Mode
+//!
+//! Keeps `.github/issues` and GitHub in sync during a session:
+//!
+//! - File events (notify) anywhere in the tree are debounced, then only the touched files are pushed
+//! - GitHub is polled on an interval and remote changes are pulled; the events of the files
+//!   a pull writes are ignored rather than pushed back
+//! - On shutdown, pending file changes are pushed before returning
+
+use anyhow::{Context, Result};
+use gc_core::ports::GitHubPort;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::{BTreeSet, HashMap};
+use std::future::Future;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::{Instant, MissedTickBehavior};
+use tracing::{info, warn};
+
+use crate::syncer::{file_key, is_issue_file_key, ChangeKind, IssueSyncer, SyncReport};
+
+/// How long file events for a file the syncer wrote itself are ignored
+const ECHO_WINDOW: Duration = Duration::from_secs(2);
+
+/// Watch mode timings
+#[derive(Debug, Clone)]
+pub struct WatchConfig {
+    /// Quiet period after the last file event before pushing
+    pub debounce: Duration,
+    /// Interval between GitHub polls
+    pub poll_interval: Duration,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            debounce: Duration::from_millis(500),
+            poll_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Watch the issues directory until `shutdown` resolves.
+///
+/// Starts with a full sync. `on_report` is called after every cycle that did something;
+/// failed cycles are logged and retried on the next event or poll.
+pub async fn watch<G, F>(
+    syncer: &mut IssueSyncer<'_, G>,
+    config: WatchConfig,
+    shutdown: F,
+    mut on_report: impl FnMut(&SyncReport),
+) -> Result<()>
+where
+    G: GitHubPort,
+    F: Future<Output = ()>,
+{
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    let issues_dir = syncer.issues_dir().to_path_buf();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else { return };
+        if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)) {
+            return;
+        }
+        for path in event.paths {
+            let key = file_key(&issues_dir, &path);
+            if path.starts_with(&issues_dir) && is_issue_file_key(&key) {
+                let _ = tx.send(key);
+            }
+        }
+    })
+    .context("Failed to create file watcher")?;
+    watcher
+        .watch(syncer.issues_dir(), RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", syncer.issues_dir().display()))?;
+    info!("Watching {} (poll every {:?})", syncer.issues_dir().display(), config.poll_interval);
+
+    let mut echoes = HashMap::new();
+    report_cycle(remember_writes(syncer.sync_all().await, &mut echoes), &mut on_report);
+
+    let mut poll = tokio::time::interval_at(Instant::now() + config.poll_interval, config.poll_interval);
+    poll.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut pending = BTreeSet::new();
+    let mut deadline: Option<Instant> = None;
+    tokio::pin!(shutdown);
+
+    loop {
+        let debounce = async {
+            match deadline {
+                Some(deadline) => tokio::time::sleep_until(deadline).await,
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            _ = &mut shutdown => break,
+            Some(name) = rx.recv() => {
+                if echoes.get(&name).is_some_and(|until| Instant::now() < *until) {
+                    continue;
+                }
+                pending.insert(name);
+                deadline = Some(Instant::now() + config.debounce);
+            }
+            _ = debounce => {
+                deadline = None;
+                let files = std::mem::take(&mut pending);
+                report_cycle(syncer.push_files(&files).await, &mut on_report);
+            }
+            _ = poll.tick() => {
+                report_cycle(remember_writes(syncer.pull().await, &mut echoes), &mut on_report);
+            }
+        }
+    }
+
+    if !pending.is_empty() {
+        info!("Shutting down: pushing {} pending file(s)", pending.len());
+        report_cycle(syncer.push_files(&pending).await, &mut on_report);
+    }
+    info!("Watch stopped");
+    Ok(())
+}
+
+/// Records the files a cycle wrote locally, so that their file events are not pushed back.
+/// Events raised during the cycle are still queued and are only read after this.
+fn remember_writes(result: Result<SyncReport>, echoes: &mut HashMap<String, Instant>) -> Result<SyncReport> {
+    let now = Instant::now();
+    echoes.retain(|_, until| now < *until);
+    if let Ok(report) = &result {
+        let written = report.changes.iter().filter(|c| {
+            matches!(c.kind, ChangeKind::Pull | ChangeKind::Materialize | ChangeKind::Mark | ChangeKind::Archive | ChangeKind::Delete)
+        });
+        for change in written {
+            echoes.insert(change.file.clone(), now + ECHO_WINDOW);
+        }
+    }
+    result
+}
+
+fn report_cycle(result: Result<SyncReport>, on_report: &mut impl FnMut(&SyncReport)) {
+    match result {
+        Ok(report) if !report.changes.is_empty() || report.errors > 0 => on_report(&report),
+        Ok(_) => {}
+        Err(e) => warn!("Sync cycle failed: {:#}", e),
+    }
+}
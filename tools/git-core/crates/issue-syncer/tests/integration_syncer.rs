@@ -0,0 +1,418 @@
+//! Integration tests for Issue Syncer
+//!
+//! Tests the complete sync workflow with realistic scenarios.
+
+use gc_adapter_github::OctocrabGitHub;
+use gc_core::ports::MockGitHubPort;
+use gc_core::Issue;
+use issue_syncer::{
+    mapping::IssueMapping,
+    syncer::IssueSyncer,
+    watch::{watch, WatchConfig},
+};
+use mockito::Matcher;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tempfile::TempDir;
+
+/// GitHub with the given open issues (dry-run tests only read)
+fn github_with(issues: Vec<(u64, &'static str)>) -> MockGitHubPort {
+    let mut github = MockGitHubPort::new();
+    github.expect_list_issues().returning(move |_, _, _, _| {
+        Ok(issues.iter().map(|(number, title)| Issue {
+            number: *number,
+            title: title.to_string(),
+            body: None,
+            state: "Open".to_string(),
+            html_url: String::new(),
+            assignees: vec![],
+            labels: vec![],
+            updated_at: None,
+            closed_at: None,
+            state_reason: None,
+            milestone: None,
+        }).collect())
+    });
+    github
+}
+
+fn create_test_issue_file(dir: &std::path::Path, filename: &str, title: &str, labels: &[&str]) {
+    let labels_yaml = labels
+        .iter()
+        .map(|l| format!("  - {}", l))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let content = format!(
+        r#"---
+title: "{}"
+labels:
+{}
+assignees: []
+---
+
+This is the issue body for {}.
+"#,
+        title, labels_yaml, filename
+    );
+
+    fs::write(dir.join(filename), content).unwrap();
+}
+
+fn create_test_syncer<'a>(temp_dir: &TempDir, github: &'a MockGitHubPort) -> IssueSyncer<'a, MockGitHubPort> {
+    let issues_dir: PathBuf = temp_dir.path().join("issues");
+    fs::create_dir(&issues_dir).unwrap();
+
+    let mapping_file = issues_dir.join(".issue-mapping.json");
+
+    IssueSyncer::new(github, "owner", "repo", issues_dir, mapping_file)
+        .unwrap()
+        .with_dry_run(true)
+}
+
+#[tokio::test]
+async fn test_sync_empty_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let github = github_with(vec![]);
+    let mut syncer = create_test_syncer(&temp_dir, &github);
+
+    let report = syncer.sync_all().await.unwrap();
+
+    assert_eq!(report.created, 0);
+    assert_eq!(report.updated, 0);
+    assert_eq!(report.deleted, 0);
+}
+
+#[tokio::test]
+async fn test_push_new_issues() {
+    let temp_dir = TempDir::new().unwrap();
+    let github = github_with(vec![]);
+    let mut syncer = create_test_syncer(&temp_dir, &github);
+
+    // Create test issue files
+    let issues_dir = temp_dir.path().join("issues");
+    create_test_issue_file(&issues_dir, "FEAT_test1.md", "Feature 1", &["enhancement"]);
+    create_test_issue_file(&issues_dir, "BUG_test2.md", "Bug Fix", &["bug", "urgent"]);
+
+    let report = syncer.push().await.unwrap();
+
+    assert_eq!(report.created, 2);
+    assert_eq!(report.updated, 0);
+}
+
+#[tokio::test]
+async fn test_push_with_existing_mapping() {
+    let temp_dir = TempDir::new().unwrap();
+    let issues_dir = temp_dir.path().join("issues");
+    fs::create_dir(&issues_dir).unwrap();
+
+    // Create mapping file
+    let mapping_file = issues_dir.join(".issue-mapping.json");
+    let mut mapping = IssueMapping::default();
+    mapping.add("FEAT_test1.md".to_string(), 42);
+    mapping.save(&mapping_file).unwrap();
+
+    // Create issue file
+    create_test_issue_file(&issues_dir, "FEAT_test1.md", "Updated Feature", &["enhancement"]);
+
+    let github = github_with(vec![(42, "Feature")]);
+
+    let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir, mapping_file)
+        .unwrap()
+        .with_dry_run(true);
+
+    let report = syncer.push().await.unwrap();
+
+    assert_eq!(report.created, 0);
+    assert_eq!(report.updated, 1);
+}
+
+#[tokio::test]
+async fn test_scan_multiple_issue_types() {
+    let temp_dir = TempDir::new().unwrap();
+    let github = github_with(vec![]);
+    let mut syncer = create_test_syncer(&temp_dir, &github);
+
+    let issues_dir = temp_dir.path().join("issues");
+    create_test_issue_file(&issues_dir, "FEAT_feature.md", "Feature", &["enhancement"]);
+    create_test_issue_file(&issues_dir, "BUG_bugfix.md", "Bug", &["bug"]);
+    create_test_issue_file(&issues_dir, "DOCS_documentation.md", "Docs", &["documentation"]);
+    create_test_issue_file(&issues_dir, "REFACTOR_cleanup.md", "Refactor", &["refactor"]);
+
+    let report = syncer.push().await.unwrap();
+
+    assert_eq!(report.created, 4);
+}
+
+#[tokio::test]
+async fn test_mapping_persistence() {
+    let temp_dir = TempDir::new().unwrap();
+    let mapping_file = temp_dir.path().join(".issue-mapping.json");
+
+    // Create and save mapping
+    let mut mapping1 = IssueMapping::default();
+    mapping1.add("FEAT_test.md".to_string(), 42);
+    mapping1.add("BUG_error.md".to_string(), 43);
+    mapping1.save(&mapping_file).unwrap();
+
+    // Load mapping
+    let mapping2 = IssueMapping::load(&mapping_file).unwrap();
+
+    assert_eq!(mapping2.get_issue("FEAT_test.md"), Some(42));
+    assert_eq!(mapping2.get_issue("BUG_error.md"), Some(43));
+    assert_eq!(mapping2.len(), 2);
+}
+
+#[tokio::test]
+async fn test_bidirectional_mapping_lookup() {
+    let mut mapping = IssueMapping::default();
+    mapping.add("FEAT_auth.md".to_string(), 100);
+    mapping.add("BUG_crash.md".to_string(), 101);
+
+    // Forward lookup (file -> issue)
+    assert_eq!(mapping.get_issue("FEAT_auth.md"), Some(100));
+    assert_eq!(mapping.get_issue("BUG_crash.md"), Some(101));
+
+    // Reverse lookup (issue -> file)
+    assert_eq!(mapping.get_file(100), Some("FEAT_auth.md".to_string()));
+    assert_eq!(mapping.get_file(101), Some("BUG_crash.md".to_string()));
+}
+
+#[tokio::test]
+async fn test_mapping_removal() {
+    let mut mapping = IssueMapping::default();
+    mapping.add("FEAT_test.md".to_string(), 42);
+
+    // Remove by file
+    let removed = mapping.remove_by_file("FEAT_test.md");
+    assert_eq!(removed, Some(42));
+    assert!(!mapping.contains_file("FEAT_test.md"));
+
+    // Add back and remove by issue
+    mapping.add("FEAT_test.md".to_string(), 42);
+    let removed = mapping.remove_by_issue(42);
+    assert_eq!(removed, Some("FEAT_test.md".to_string()));
+    assert!(!mapping.contains_issue(42));
+}
+
+#[tokio::test]
+async fn test_issue_file_with_complex_yaml() {
+    let temp_dir = TempDir::new().unwrap();
+    let issues_dir = temp_dir.path().join("issues");
+    fs::create_dir(&issues_dir).unwrap();
+
+    let content = r#"---
+title: "Complex Issue with Special Characters: @mentions, #tags"
+labels:
+  - enhancement
+  - high-priority
+  - "special-label"
+assignees:
+  - user1
+  - user2
+---
+
+# Issue Description
+
+This is a **complex** issue with:
+- Multiple lines
+- Markdown formatting
+- Code blocks
+
+```rust
+fn main() {
+    println!("Hello");
+}
+```
+"#;
+
+    fs::write(issues_dir.join("COMPLEX.md"), content).unwrap();
+
+    let github = github_with(vec![]);
+
+    let mut syncer = IssueSyncer::new(
+        &github,
+        "owner",
+        "repo",
+        issues_dir,
+        temp_dir.path().join(".mapping.json"),
+    )
+    .unwrap()
+    .with_dry_run(true);
+
+    let report = syncer.push().await.unwrap();
+    assert_eq!(report.created, 1);
+    assert_eq!(report.errors, 0);
+}
+
+#[tokio::test]
+async fn test_skip_hidden_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let github = github_with(vec![]);
+    let mut syncer = create_test_syncer(&temp_dir, &github);
+
+    let issues_dir = temp_dir.path().join("issues");
+
+    // Create normal and hidden files
+    create_test_issue_file(&issues_dir, "FEAT_visible.md", "Visible", &["enhancement"]);
+    fs::write(
+        issues_dir.join(".issue-mapping.json"),
+        "{}",
+    )
+    .unwrap();
+    fs::write(issues_dir.join(".hidden.md"), "hidden content").unwrap();
+
+    let report = syncer.push().await.unwrap();
+
+    // Should only process the visible file
+    assert_eq!(report.created, 1);
+}
+
+#[tokio::test]
+async fn test_watch_pushes_debounced_changes_and_flushes_on_shutdown() {
+    let temp_dir = TempDir::new().unwrap();
+    let issues_dir = temp_dir.path().join("issues");
+    fs::create_dir(&issues_dir).unwrap();
+    let mapping_file = issues_dir.join(".issue-mapping.json");
+
+    let mut github = github_with(vec![]);
+    let next_number = AtomicU64::new(1);
+    github.expect_create_issue().times(2).returning(move |_, _, title, _, _| {
+        Ok(Issue {
+            number: next_number.fetch_add(1, Ordering::SeqCst),
+            title: title.to_string(),
+            body: None,
+            state: "open".to_string(),
+            html_url: String::new(),
+            assignees: vec![],
+            labels: vec![],
+            updated_at: None,
+            closed_at: None,
+            state_reason: None,
+            milestone: None,
+        })
+    });
+
+    let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), mapping_file.clone()).unwrap();
+    let config = WatchConfig { debounce: Duration::from_millis(300), poll_interval: Duration::from_secs(3600) };
+
+    let edits = async {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        create_test_issue_file(&issues_dir, "FEAT_first.md", "First", &["enhancement"]);
+        tokio::time::sleep(Duration::from_millis(900)).await;
+        // Still inside the debounce window when shutdown is requested
+        create_test_issue_file(&issues_dir, "FEAT_second.md", "Second", &["enhancement"]);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    };
+
+    let mut reports = 0;
+    watch(&mut syncer, config, edits, |_| reports += 1).await.unwrap();
+
+    assert_eq!(reports, 2);
+    let mapping = IssueMapping::load(&mapping_file).unwrap();
+    assert_eq!(mapping.get_issue("FEAT_first.md"), Some(1));
+    assert_eq!(mapping.get_issue("FEAT_second.md"), Some(2));
+}
+
+#[tokio::test]
+async fn test_watch_does_not_push_back_files_it_pulled() {
+    let temp_dir = TempDir::new().unwrap();
+    let issues_dir = temp_dir.path().join("issues");
+    fs::create_dir(&issues_dir).unwrap();
+    let mapping_file = issues_dir.join(".issue-mapping.json");
+
+    let mut github = MockGitHubPort::new();
+    let listings = std::sync::Arc::new(AtomicU64::new(0));
+    let counter = listings.clone();
+    github.expect_list_issues().returning(move |_, _, _, _| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Ok(vec![Issue {
+            number: 5,
+            title: "Remote only".to_string(),
+            body: Some("Written by the pull".to_string()),
+            state: "Open".to_string(),
+            html_url: String::new(),
+            assignees: vec![],
+            labels: vec![],
+            updated_at: Some("2026-01-01T00:00:00Z".to_string()),
+            closed_at: None,
+            state_reason: None,
+            milestone: None,
+        }])
+    });
+
+    let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir.clone(), mapping_file).unwrap();
+    let config = WatchConfig { debounce: Duration::from_millis(100), poll_interval: Duration::from_secs(3600) };
+
+    let mut reports = 0;
+    watch(&mut syncer, config, tokio::time::sleep(Duration::from_millis(800)), |_| reports += 1).await.unwrap();
+
+    // The initial sync wrote the file; its file events did not start a push cycle
+    assert_eq!(reports, 1);
+    assert_eq!(listings.load(Ordering::SeqCst), 1);
+}
+
+/// Issue as the GitHub API returns it, numbered after the `Issue <n>` title that was posted
+fn created_issue(request: &mockito::Request) -> Vec<u8> {
+    let posted: serde_json::Value = serde_json::from_slice(request.body().unwrap()).unwrap();
+    let title = posted["title"].as_str().unwrap();
+    let mut issue: serde_json::Value = serde_json::from_str(include_str!("fixtures/github_issue.json")).unwrap();
+    issue["number"] = title.trim_start_matches("Issue ").parse::<u64>().unwrap().into();
+    issue["title"] = title.into();
+    serde_json::to_vec(&issue).unwrap()
+}
+
+#[tokio::test]
+async fn test_parallel_push_against_mock_server_backs_off_when_rate_limited() {
+    let mut server = mockito::Server::new_async().await;
+    let list = server
+        .mock("GET", "/repos/owner/repo/issues")
+        .match_query(Matcher::Any)
+        .with_body("[]")
+        .create_async()
+        .await;
+    // The first create hits the secondary rate limit, then every create goes through
+    let limited = server
+        .mock("POST", "/repos/owner/repo/issues")
+        .with_status(403)
+        .with_body(r#"{"message": "You have exceeded a secondary rate limit. Please wait a few minutes before you try again."}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let created = server
+        .mock("POST", "/repos/owner/repo/issues")
+        .with_status(201)
+        .with_body_from_request(created_issue)
+        .expect(12)
+        .create_async()
+        .await;
+
+    let temp_dir = TempDir::new().unwrap();
+    let issues_dir = temp_dir.path().join("issues");
+    fs::create_dir_all(&issues_dir).unwrap();
+    for i in 1..=12 {
+        fs::write(issues_dir.join(format!("FEAT_{:02}.md", i)), format!("---\ntitle: Issue {}\n---\nBody", i)).unwrap();
+    }
+    let mapping_file = issues_dir.join(".issue-mapping.json");
+
+    let github = OctocrabGitHub::with_base_uri("token", &server.url()).unwrap();
+    let mut syncer = IssueSyncer::new(&github, "owner", "repo", issues_dir, mapping_file.clone())
+        .unwrap()
+        .with_concurrency(4)
+        .with_rate_limit_backoff(Duration::from_millis(10), 3);
+    let report = syncer.push().await.unwrap();
+
+    assert_eq!((report.created, report.errors), (12, 0));
+    let files: Vec<&str> = report.changes.iter().map(|c| c.file.as_str()).collect();
+    assert_eq!(files.first(), Some(&"FEAT_01.md"), "changes are reported in file order");
+    assert_eq!(files.last(), Some(&"FEAT_12.md"));
+    let mapping = IssueMapping::load(&mapping_file).unwrap();
+    assert_eq!(mapping.len(), 12);
+    assert_eq!(mapping.get_issue("FEAT_07.md"), Some(7));
+
+    list.assert_async().await;
+    limited.assert_async().await;
+    created.assert_async().await;
+}
@@ -1,8 +0,0 @@
-//! Issue Syncer Library
-//!
-//! Exposes modules for integration testing.
-
-pub mod github;
-pub mod mapping;
-pub mod parser;
-pub mod syncer;